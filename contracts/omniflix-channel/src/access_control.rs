@@ -1,13 +1,17 @@
+use crate::state::MODERATORS;
 use crate::ContractError;
 use channel_manager::channel::ChannelsManager;
-use cosmwasm_std::{Addr, Deps};
+use channel_manager::error::ChannelError;
+use cosmwasm_std::{Addr, Deps, Env, Storage};
 use omniflix_channel_types::channel::{ChannelId, Role};
+use omniflix_channel_types::config::AuthDetails;
 use omniflix_std::types::omniflix::onft::v1beta1::{Onft, OnftQuerier};
 
 /// Validates if the sender has the required permissions to perform an action on a channel
 ///
 /// # Arguments
 /// * `deps` - Dependencies for accessing storage and querier
+/// * `env` - Environment used to check the collaborator expiration
 /// * `channel_id` - Identifier of the channel
 /// * `sender` - Address of the account attempting the action
 /// * `channels_collection_id` - Collection ID containing the channel NFTs
@@ -17,9 +21,11 @@ use omniflix_std::types::omniflix::onft::v1beta1::{Onft, OnftQuerier};
 /// * `Ok(())` if the sender either:
 ///   - Owns the channel NFT (has admin privileges)
 ///   - Is a collaborator with sufficient role permissions
+/// * `Err(ChannelError::CollaboratorExpired)` if the sender is an expired collaborator
 /// * `Err(ContractError::Unauthorized)` if the sender lacks required permissions
 pub fn validate_permissions(
     deps: Deps,
+    env: &Env,
     channel_id: ChannelId,
     sender: Addr,
     channels_collection_id: String,
@@ -42,6 +48,9 @@ pub fn validate_permissions(
 
     // If not the owner, check if user is a collaborator with sufficient privileges
     if let Ok(collaborator) = channels.get_collaborator(deps.storage, channel_id, sender) {
+        if collaborator.is_expired(&env.block) {
+            return Err(ChannelError::CollaboratorExpired {}.into());
+        }
         if has_sufficient_privileges(collaborator.role, required_role) {
             return Ok(());
         }
@@ -51,6 +60,27 @@ pub fn validate_permissions(
     Err(ContractError::Unauthorized {})
 }
 
+/// Validates if the sender is the protocol admin or a protocol moderator
+///
+/// # Arguments
+/// * `storage` - Storage holding the protocol moderators
+/// * `auth_details` - Auth details holding the protocol admin
+/// * `sender` - Address of the account attempting the action
+///
+/// # Returns
+/// * `Ok(())` if the sender is the protocol admin or a protocol moderator
+/// * `Err(ContractError::Unauthorized)` if the sender is neither
+pub fn validate_protocol_moderator(
+    storage: &dyn Storage,
+    auth_details: &AuthDetails,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    if *sender == auth_details.protocol_admin || MODERATORS.has(storage, sender.clone()) {
+        return Ok(());
+    }
+    Err(ContractError::Unauthorized {})
+}
+
 // Helper function to check role hierarchy
 fn has_sufficient_privileges(actual_role: Role, required_role: Role) -> bool {
     match (actual_role, required_role) {
@@ -74,6 +104,37 @@ pub fn get_onft_with_owner(
     onft_id: String,
     owner: String,
 ) -> Result<Onft, ContractError> {
+    let onft = get_onft(deps, collection_id.clone(), onft_id.clone())?;
+
+    if onft.owner != owner {
+        return Err(ContractError::OnftNotOwned {
+            collection_id,
+            onft_id,
+        });
+    }
+
+    Ok(onft)
+}
+
+/// Returns true if the address owns at least one ONFT of the collection
+pub fn holds_collection_onft(
+    deps: Deps,
+    collection_id: String,
+    owner: String,
+) -> Result<bool, ContractError> {
+    let onft_querier = OnftQuerier::new(&deps.querier);
+    let response = onft_querier.owner_onf_ts(collection_id.clone(), owner, None)?;
+
+    let holds_onft = response.owner.is_some_and(|owner| {
+        owner
+            .id_collections
+            .iter()
+            .any(|ids| ids.denom_id == collection_id && !ids.onft_ids.is_empty())
+    });
+    Ok(holds_onft)
+}
+
+pub fn get_onft(deps: Deps, collection_id: String, onft_id: String) -> Result<Onft, ContractError> {
     let onft_querier = OnftQuerier::new(&deps.querier);
     let onft_response = onft_querier
         .onft(collection_id.clone(), onft_id.clone())
@@ -89,12 +150,5 @@ pub fn get_onft_with_owner(
             onft_id: onft_id.clone(),
         })?;
 
-    if onft.owner != owner {
-        return Err(ContractError::OnftNotOwned {
-            collection_id,
-            onft_id,
-        });
-    }
-
     Ok(onft)
 }
@@ -55,57 +55,123 @@ pub fn bank_msg_wrapper(recipient: Addr, amount: Vec<Coin>) -> Vec<CosmosMsg> {
     vec![bank_msg]
 }
 
-/// Distributes funds among collaborators according to their shares
-/// and sends any remaining amount to the channel payment address
-pub fn distribute_funds_with_shares(
+/// Calculates the share of each collaborator, rounded down,
+/// with the remaining amount going to the channel payment address
+pub fn calculate_shares(
     collaborators: Vec<(Addr, Decimal)>,
     amount: Coin,
     channel_payment_address: Addr,
-) -> Result<(Vec<CosmosMsg>, Vec<Attribute>), ContractError> {
+) -> Result<Vec<(Addr, Coin)>, ContractError> {
     if amount.amount == Uint128::from(0u128) {
-        return Ok((vec![], vec![]));
+        return Ok(vec![]);
     }
-    let mut bank_msgs: Vec<CosmosMsg> = vec![];
+    let mut shares: Vec<(Addr, Coin)> = vec![];
     let mut remaining_amount = amount.clone().amount;
-    let mut attributes: Vec<Attribute> = vec![];
 
     for (collaborator, share) in collaborators.clone() {
         // Calculate share based on original amount
         let share_amount = Decimal::from_ratio(amount.amount, Uint128::one()) * share;
         let uint_share_amount = share_amount.to_uint_floor();
-        let share_amount_coin = Coin {
-            denom: amount.denom.clone(),
-            amount: uint_share_amount,
-        };
-        bank_msgs.extend(bank_msg_wrapper(
-            collaborator.clone(),
-            vec![share_amount_coin.clone()],
-        ));
         remaining_amount = remaining_amount.checked_sub(uint_share_amount)?;
-        attributes.push(Attribute::new(
-            collaborator.to_string(),
-            share_amount_coin.to_string(),
+        shares.push((
+            collaborator,
+            Coin {
+                denom: amount.denom.clone(),
+                amount: uint_share_amount,
+            },
         ));
     }
 
     if !remaining_amount.is_zero() {
-        let remaining_amount_coin = Coin {
-            denom: amount.denom.clone(),
-            amount: remaining_amount,
-        };
-        bank_msgs.extend(bank_msg_wrapper(
-            channel_payment_address.clone(),
-            vec![remaining_amount_coin.clone()],
-        ));
-        attributes.push(Attribute::new(
-            channel_payment_address.to_string(),
-            remaining_amount_coin.to_string(),
+        shares.push((
+            channel_payment_address,
+            Coin {
+                denom: amount.denom.clone(),
+                amount: remaining_amount,
+            },
         ));
     }
 
+    Ok(shares)
+}
+
+/// Distributes funds among collaborators according to their shares
+/// and sends any remaining amount to the channel payment address
+pub fn distribute_funds_with_shares(
+    collaborators: Vec<(Addr, Decimal)>,
+    amount: Coin,
+    channel_payment_address: Addr,
+) -> Result<(Vec<CosmosMsg>, Vec<Attribute>), ContractError> {
+    let mut bank_msgs: Vec<CosmosMsg> = vec![];
+    let mut attributes: Vec<Attribute> = vec![];
+
+    for (recipient, share) in calculate_shares(collaborators, amount, channel_payment_address)? {
+        bank_msgs.extend(bank_msg_wrapper(recipient.clone(), vec![share.clone()]));
+        attributes.push(Attribute::new(recipient.to_string(), share.to_string()));
+    }
+
     Ok((bank_msgs, attributes))
 }
 
+/// Splits the protocol fee off an amount
+///
+/// # Returns
+/// * `(Coin, Coin)` - The protocol fee, rounded down, and the remaining amount
+pub fn split_protocol_fee(amount: Coin, fee_rate: Decimal) -> Result<(Coin, Coin), ContractError> {
+    let fee_amount = amount.amount.mul_floor(fee_rate);
+    let remaining_amount = amount.amount.checked_sub(fee_amount)?;
+    Ok((
+        Coin {
+            denom: amount.denom.clone(),
+            amount: fee_amount,
+        },
+        Coin {
+            denom: amount.denom,
+            amount: remaining_amount,
+        },
+    ))
+}
+
+/// Merges bank send messages so that each recipient receives a single message
+///
+/// Recipients keep the order in which they first appear. Messages that are not bank sends are
+/// returned unchanged after the merged sends.
+pub fn merge_bank_msgs(msgs: Vec<CosmosMsg>) -> Vec<CosmosMsg> {
+    let mut recipients: Vec<(String, NativeBalance)> = vec![];
+    let mut other_msgs: Vec<CosmosMsg> = vec![];
+    for msg in msgs {
+        match msg {
+            CosmosMsg::Bank(cosmwasm_std::BankMsg::Send { to_address, amount }) => {
+                match recipients.iter_mut().find(|(addr, _)| addr == &to_address) {
+                    Some((_, balance)) => {
+                        for coin in amount {
+                            *balance += coin;
+                        }
+                    }
+                    None => recipients.push((to_address, NativeBalance(amount))),
+                }
+            }
+            other => other_msgs.push(other),
+        }
+    }
+
+    let mut merged: Vec<CosmosMsg> = vec![];
+    for (to_address, balance) in recipients {
+        merged.extend(bank_msg_wrapper(Addr::unchecked(to_address), balance.0));
+    }
+    merged.extend(other_msgs);
+    merged
+}
+
+/// Formats a list of coins as a comma separated string
+pub fn coins_to_string(coins: &[Coin]) -> String {
+    coins
+        .iter()
+        .map(|coin| coin.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,17 +191,13 @@ mod tests {
 
         // Check messages
         assert_eq!(msgs.len(), 2);
-        if let CosmosMsg::Bank(bank_msg) = &msgs[0] {
-            if let cosmwasm_std::BankMsg::Send { to_address, amount } = bank_msg {
-                assert_eq!(to_address, "addr1");
-                assert_eq!(amount[0], coin(300, "uflix"));
-            }
+        if let CosmosMsg::Bank(cosmwasm_std::BankMsg::Send { to_address, amount }) = &msgs[0] {
+            assert_eq!(to_address, "addr1");
+            assert_eq!(amount[0], coin(300, "uflix"));
         }
-        if let CosmosMsg::Bank(bank_msg) = &msgs[1] {
-            if let cosmwasm_std::BankMsg::Send { to_address, amount } = bank_msg {
-                assert_eq!(to_address, "addr2");
-                assert_eq!(amount[0], coin(700, "uflix"));
-            }
+        if let CosmosMsg::Bank(cosmwasm_std::BankMsg::Send { to_address, amount }) = &msgs[1] {
+            assert_eq!(to_address, "addr2");
+            assert_eq!(amount[0], coin(700, "uflix"));
         }
 
         // Check attributes
@@ -160,23 +222,17 @@ mod tests {
 
         // Check messages
         assert_eq!(msgs.len(), 3);
-        if let CosmosMsg::Bank(bank_msg) = &msgs[0] {
-            if let cosmwasm_std::BankMsg::Send { to_address, amount } = bank_msg {
-                assert_eq!(to_address, "addr1");
-                assert_eq!(amount[0], coin(33, "uflix"));
-            }
+        if let CosmosMsg::Bank(cosmwasm_std::BankMsg::Send { to_address, amount }) = &msgs[0] {
+            assert_eq!(to_address, "addr1");
+            assert_eq!(amount[0], coin(33, "uflix"));
         }
-        if let CosmosMsg::Bank(bank_msg) = &msgs[1] {
-            if let cosmwasm_std::BankMsg::Send { to_address, amount } = bank_msg {
-                assert_eq!(to_address, "addr2");
-                assert_eq!(amount[0], coin(33, "uflix"));
-            }
+        if let CosmosMsg::Bank(cosmwasm_std::BankMsg::Send { to_address, amount }) = &msgs[1] {
+            assert_eq!(to_address, "addr2");
+            assert_eq!(amount[0], coin(33, "uflix"));
         }
-        if let CosmosMsg::Bank(bank_msg) = &msgs[2] {
-            if let cosmwasm_std::BankMsg::Send { to_address, amount } = bank_msg {
-                assert_eq!(to_address, "channel");
-                assert_eq!(amount[0], coin(34, "uflix"));
-            }
+        if let CosmosMsg::Bank(cosmwasm_std::BankMsg::Send { to_address, amount }) = &msgs[2] {
+            assert_eq!(to_address, "channel");
+            assert_eq!(amount[0], coin(34, "uflix"));
         }
 
         // Check attributes
@@ -217,4 +273,62 @@ mod tests {
         assert!(msgs.is_empty());
         assert!(attrs.is_empty());
     }
+
+    #[test]
+    fn test_split_protocol_fee() {
+        let (fee, remaining) =
+            split_protocol_fee(coin(1001, "uflix"), Decimal::percent(10)).unwrap();
+        assert_eq!(fee, coin(100, "uflix"));
+        assert_eq!(remaining, coin(901, "uflix"));
+
+        let (fee, remaining) = split_protocol_fee(coin(1000, "uflix"), Decimal::zero()).unwrap();
+        assert_eq!(fee, coin(0, "uflix"));
+        assert_eq!(remaining, coin(1000, "uflix"));
+    }
+
+    #[test]
+    fn test_merge_bank_msgs() {
+        let msgs = vec![
+            CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: "addr1".to_string(),
+                amount: vec![coin(100, "uflix")],
+            }),
+            CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: "addr2".to_string(),
+                amount: vec![coin(50, "uflix")],
+            }),
+            CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: "addr1".to_string(),
+                amount: vec![coin(10, "uatom")],
+            }),
+            CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: "addr1".to_string(),
+                amount: vec![coin(5, "uflix")],
+            }),
+        ];
+
+        let merged = merge_bank_msgs(msgs);
+        assert_eq!(
+            merged,
+            vec![
+                CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                    to_address: "addr1".to_string(),
+                    amount: vec![coin(10, "uatom"), coin(105, "uflix")],
+                }),
+                CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                    to_address: "addr2".to_string(),
+                    amount: vec![coin(50, "uflix")],
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_coins_to_string() {
+        assert_eq!(
+            coins_to_string(&[coin(10, "uatom"), coin(105, "uflix")]),
+            "10uatom,105uflix"
+        );
+        assert_eq!(coins_to_string(&[]), "");
+    }
 }
@@ -1,10 +1,11 @@
 use cosmwasm_schema::write_api;
-use omniflix_channel_types::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use omniflix_channel_types::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 fn main() {
     write_api! {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
         query: QueryMsg,
+        migrate: MigrateMsg,
     }
 }
@@ -0,0 +1,107 @@
+use crate::ContractError;
+use asset_manager::assets::AssetsManager;
+use asset_manager::playlists::PlaylistsManager;
+use channel_manager::channel::ChannelsManager;
+use channel_manager::tips::TipsManager;
+use cosmwasm_std::Storage;
+use omniflix_channel_types::channel::{ChannelCleanupProgress, ChannelId, CleanupStage};
+
+/// Number of entries removed per transaction if no limit is given
+pub const DEFAULT_CLEANUP_LIMIT: u32 = 100;
+/// Maximum number of entries removed per transaction
+pub const MAX_CLEANUP_LIMIT: u32 = 500;
+
+/// Removes the state of a deleted channel, stage by stage, until `limit` entries are removed
+///
+/// # Returns
+/// * `Some(progress)` if there is state left to be removed in a later transaction
+/// * `None` if the cleanup is finished
+pub fn run_channel_cleanup(
+    storage: &mut dyn Storage,
+    channel_id: ChannelId,
+    mut progress: ChannelCleanupProgress,
+    limit: u32,
+) -> Result<Option<ChannelCleanupProgress>, ContractError> {
+    let channels_manager = ChannelsManager::new();
+    let assets_manager = AssetsManager::new();
+    let playlists_manager = PlaylistsManager::new();
+    let tips_manager = TipsManager::new();
+
+    let mut remaining = limit.min(MAX_CLEANUP_LIMIT) as usize;
+    while remaining > 0 {
+        let removed = match progress.stage {
+            CleanupStage::Collaborators => {
+                channels_manager.delete_collaborators(storage, channel_id.clone(), remaining)?
+            }
+            CleanupStage::CollaboratorInvites => channels_manager.delete_collaborator_invites(
+                storage,
+                channel_id.clone(),
+                remaining,
+            )?,
+            CleanupStage::Followers => {
+                channels_manager.delete_followers(storage, channel_id.clone(), remaining)?
+            }
+            CleanupStage::Subscriptions => {
+                channels_manager.delete_subscriptions(storage, channel_id.clone(), remaining)?
+            }
+            CleanupStage::UsernameHolds => {
+                channels_manager.delete_username_holds(storage, channel_id.clone(), remaining)?
+            }
+            CleanupStage::Assets => {
+                // The tip totals, flaggers, flag reports and purchases of an asset count against
+                // the limit, an asset is only removed once they are gone
+                let publish_ids = assets_manager.get_channel_publish_ids(
+                    storage,
+                    channel_id.clone(),
+                    remaining,
+                )?;
+                let mut removed = 0;
+                for publish_id in publish_ids {
+                    let key = (channel_id.clone(), publish_id.clone());
+                    removed += tips_manager.delete_asset_tip_stats(
+                        storage,
+                        channel_id.clone(),
+                        publish_id,
+                        remaining - removed,
+                    )?;
+                    removed += assets_manager.delete_asset_records(
+                        storage,
+                        key.clone(),
+                        remaining - removed,
+                    )?;
+                    if removed == remaining {
+                        break;
+                    }
+                    assets_manager.delete_channel_asset(storage, key)?;
+                    removed += 1;
+                }
+                removed
+            }
+            CleanupStage::Playlists => playlists_manager.delete_channel_playlists(
+                storage,
+                channel_id.clone(),
+                remaining,
+            )?,
+            CleanupStage::TipStats => {
+                tips_manager.delete_channel_tip_stats(storage, channel_id.clone(), remaining)?
+            }
+        };
+        progress.removed_entries += removed as u64;
+        remaining -= removed;
+
+        // A stage is done once it removes less than it was allowed to
+        if remaining > 0 {
+            progress.stage = match progress.stage {
+                CleanupStage::Collaborators => CleanupStage::CollaboratorInvites,
+                CleanupStage::CollaboratorInvites => CleanupStage::Followers,
+                CleanupStage::Followers => CleanupStage::Subscriptions,
+                CleanupStage::Subscriptions => CleanupStage::UsernameHolds,
+                CleanupStage::UsernameHolds => CleanupStage::Assets,
+                CleanupStage::Assets => CleanupStage::Playlists,
+                CleanupStage::Playlists => CleanupStage::TipStats,
+                CleanupStage::TipStats => return Ok(None),
+            };
+        }
+    }
+    Ok(Some(progress))
+}
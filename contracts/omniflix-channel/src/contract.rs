@@ -1,35 +1,64 @@
-use crate::access_control::validate_permissions;
-use crate::bank_helpers::{bank_msg_wrapper, check_payment, distribute_funds_with_shares};
+use crate::access_control::{
+    get_onft, get_onft_with_owner, holds_collection_onft, validate_permissions,
+    validate_protocol_moderator,
+};
+use crate::bank_helpers::{
+    bank_msg_wrapper, calculate_shares, check_payment, coins_to_string,
+    distribute_funds_with_shares, merge_bank_msgs, split_protocol_fee,
+};
+use crate::cleanup::{run_channel_cleanup, DEFAULT_CLEANUP_LIMIT, MAX_CLEANUP_LIMIT};
 use crate::error::ContractError;
 use crate::helpers::{
     filter_assets_to_remove, generate_create_denom_msg, generate_mint_onft_msg,
-    get_collection_creation_fee, validate_asset_metadata, validate_asset_source,
-    validate_channel_collection_details, validate_channel_details, validate_channel_metadata,
-    validate_channel_token_details, validate_reserved_usernames,
+    get_collection_creation_fee, validate_access_policy, validate_asset_metadata,
+    validate_asset_price, validate_asset_source, validate_channel_collection_details,
+    validate_channel_details, validate_channel_metadata, validate_channel_token_details,
+    validate_flag_report, validate_reserved_usernames,
 };
+use crate::migrations::{parse_version, run_migrations, MIGRATIONS};
 use crate::random::generate_random_id_with_prefix;
-use crate::state::{CHANNEL_TOKEN_DETAILS, CONFIG};
+use crate::state::{
+    CHANNEL_TOKEN_DETAILS, CONFIG, FLAG_THRESHOLDS, MODERATORS, PENDING_PROTOCOL_ADMIN,
+    TIP_DENOM_MIN_AMOUNTS,
+};
 use crate::string_validation::{validate_string, StringValidationType};
 use asset_manager::assets::AssetsManager;
+use asset_manager::error::AssetError;
 use asset_manager::playlists::PlaylistsManager;
-use channel_manager::channel::ChannelsManager;
+use channel_manager::channel::{ChannelsManager, PAGINATION_LIMIT};
+use channel_manager::error::ChannelError;
+use channel_manager::tips::TipsManager;
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response,
-    StdResult,
+    to_json_binary, Addr, Attribute, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env,
+    MessageInfo, Order, Response, StdResult, Storage, Timestamp,
+};
+use cw2::{set_contract_version, CONTRACT};
+use cw_storage_plus::Bound;
+use cw_utils::{Expiration, NativeBalance};
+use omniflix_channel_types::asset::{
+    AccessPolicy, Asset, AssetKey, AssetMetadata, AssetSource, Flag, FlagReport, Playlist,
+    PurchaseReceipt,
 };
-use cw_utils::must_pay;
-use omniflix_channel_types::asset::{Asset, AssetKey, AssetMetadata, AssetSource, Flag, Playlist};
 use omniflix_channel_types::channel::{
-    ChannelCollaborator, ChannelDetails, ChannelMetadata, ChannelOnftData, Role,
+    ChannelCleanupProgress, ChannelCollaborator, ChannelDetails, ChannelMetadata, ChannelOnftData,
+    ChannelSuspension, CleanupStage, CollaboratorInvite, PayoutMode, Role, SubscriptionPlan,
+};
+use omniflix_channel_types::config::{
+    AuthDetails, ChannelConractConfig, PendingProtocolAdmin, DEFAULT_USERNAME_HOLD_PERIOD,
+    MAX_TIP_FEE_RATE,
 };
-use omniflix_channel_types::config::{AuthDetails, ChannelConractConfig};
 use omniflix_channel_types::msg::{
-    AssetResponse, ChannelResponse, CollaboratorInfo, ExecuteMsg, InstantiateMsg, QueryMsg,
-    ReservedUsername,
+    AddressChannelInfo, AssetResponse, ChannelRelation, ChannelResponse, CollaboratorInfo,
+    CollaboratorInviteInfo, ExecuteMsg, FlagThreshold, FlaggerInfo, InstantiateMsg, MigrateMsg,
+    QueryMsg, ReservedUsername, SubscriberInfo, TipDenom, TipStats, TipperInfo,
 };
 use pauser::PauseState;
 
+// Version info for migration
+pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -37,6 +66,9 @@ pub fn instantiate(
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
+    // Record the contract name and version so that future migrations can be validated
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     // Validate the admin address provided in the instantiation message
     let protocol_admin = deps
         .api
@@ -72,6 +104,9 @@ pub fn instantiate(
         accepted_tip_denoms: msg.accepted_tip_denoms.clone(),
         channels_collection_id: msg.channels_collection_details.collection_id.clone(),
         channel_creation_fee: msg.channel_creation_fee.clone(),
+        tip_fee_rate: Decimal::zero(),
+        username_change_fee: vec![],
+        username_hold_period: DEFAULT_USERNAME_HOLD_PERIOD,
     };
     // Save the channel CONFIG to the contract state
     CONFIG.save(deps.storage, &channel_contract_config)?;
@@ -128,12 +163,48 @@ pub fn execute(
             asset_keys,
             refresh_flags,
         } => remove_assets(deps, info, asset_keys, refresh_flags),
+        ExecuteMsg::AdminManageModerators {
+            add_moderators,
+            remove_moderators,
+        } => manage_moderators(deps, info, add_moderators, remove_moderators),
+        ExecuteMsg::AdminHideAssets { asset_keys } => hide_assets(deps, env, info, asset_keys),
+        ExecuteMsg::AdminSuspendChannel {
+            channel_id,
+            reason,
+            until,
+        } => suspend_channel(deps, env, info, channel_id, reason, until),
+        ExecuteMsg::AdminUnsuspendChannel { channel_id } => {
+            unsuspend_channel(deps, info, channel_id)
+        }
+        ExecuteMsg::AdminResetFlags { asset_keys, flags } => {
+            reset_flags(deps, info, asset_keys, flags)
+        }
+        ExecuteMsg::AdminClearAllFlags { limit } => clear_all_flags(deps, info, limit),
+        ExecuteMsg::AdminSetFlagThresholds { thresholds } => {
+            set_flag_thresholds(deps, info, thresholds)
+        }
+        ExecuteMsg::AdminRestoreAssets { asset_keys } => {
+            restore_assets(deps, env, info, asset_keys)
+        }
         ExecuteMsg::AssetFlag {
             channel_id,
             publish_id,
             flag,
-            interactive_video_id: _,
-        } => flag_asset(deps, info, channel_id, publish_id, flag),
+            interactive_video_id,
+        } => flag_asset(
+            deps,
+            env,
+            info,
+            channel_id,
+            publish_id,
+            flag,
+            interactive_video_id,
+        ),
+        ExecuteMsg::AssetRetractFlag {
+            channel_id,
+            publish_id,
+            flag,
+        } => retract_flag(deps, info, channel_id, publish_id, flag),
         ExecuteMsg::Pause {} => pause(deps, info),
         ExecuteMsg::Unpause {} => unpause(deps, info),
         ExecuteMsg::SetPausers { pausers } => set_pausers(deps, info, pausers),
@@ -144,6 +215,9 @@ pub fn execute(
             playlist_id,
             is_visible,
             metadata,
+            access_policy,
+            price,
+            publish_at,
         } => publish(
             deps,
             env,
@@ -154,15 +228,18 @@ pub fn execute(
             playlist_id,
             is_visible,
             metadata,
+            access_policy,
+            price,
+            publish_at,
         ),
         ExecuteMsg::AssetUnpublish {
             publish_id,
             channel_id,
-        } => unpublish(deps, info, publish_id, channel_id),
+        } => unpublish(deps, env, info, publish_id, channel_id),
         ExecuteMsg::PlaylistRefresh {
             channel_id,
             playlist_id,
-        } => refresh_playlist(deps, info, channel_id, playlist_id),
+        } => refresh_playlist(deps, env, info, channel_id, playlist_id),
         ExecuteMsg::PlaylistCreate {
             playlist_name,
             channel_id,
@@ -197,6 +274,7 @@ pub fn execute(
             payment_address,
         } => update_channel_details(
             deps,
+            env,
             info,
             channel_id,
             description,
@@ -209,23 +287,32 @@ pub fn execute(
         ExecuteMsg::PlaylistDelete {
             playlist_id,
             channel_id,
-        } => delete_playlist(deps, info, channel_id, playlist_id),
+        } => delete_playlist(deps, env, info, channel_id, playlist_id),
         ExecuteMsg::PlaylistRemoveAsset {
             publish_id,
             channel_id,
             playlist_id,
-        } => remove_asset_from_playlist(deps, info, publish_id, channel_id, playlist_id),
+        } => remove_asset_from_playlist(deps, env, info, publish_id, channel_id, playlist_id),
         ExecuteMsg::AdminSetConfig {
             channel_creation_fee,
-            protocol_admin,
             fee_collector,
+            tip_fee_rate,
+            username_change_fee,
+            username_hold_period,
         } => set_config(
             deps,
             info,
             channel_creation_fee,
-            protocol_admin,
             fee_collector,
+            tip_fee_rate,
+            username_change_fee,
+            username_hold_period,
         ),
+        ExecuteMsg::AdminProposeProtocolAdmin { new_admin, expiry } => {
+            propose_protocol_admin(deps, env, info, new_admin, expiry)
+        }
+        ExecuteMsg::AdminAcceptProtocolAdmin {} => accept_protocol_admin(deps, env, info),
+        ExecuteMsg::AdminCancelProtocolAdmin {} => cancel_protocol_admin(deps, info),
         ExecuteMsg::PlaylistAddAsset {
             publish_id,
             asset_channel_id,
@@ -233,6 +320,7 @@ pub fn execute(
             playlist_id,
         } => add_asset_to_playlist(
             deps,
+            env,
             info,
             asset_channel_id,
             publish_id,
@@ -247,8 +335,12 @@ pub fn execute(
             description,
             media_uri,
             thumbnail_uri,
+            access_policy,
+            publish_at,
+            clear_publish_at,
         } => update_asset_details(
             deps,
+            env,
             info,
             publish_id,
             channel_id,
@@ -257,32 +349,99 @@ pub fn execute(
             description,
             media_uri,
             thumbnail_uri,
+            access_policy,
+            publish_at,
+            clear_publish_at.unwrap_or(false),
         ),
-        ExecuteMsg::ChannelDelete { channel_id } => delete_channel(deps, info, channel_id),
+        ExecuteMsg::AssetSetPrice {
+            channel_id,
+            publish_id,
+            price,
+        } => set_asset_price(deps, env, info, channel_id, publish_id, price),
+        ExecuteMsg::AssetPurchase {
+            channel_id,
+            publish_id,
+        } => purchase_asset(deps, env, info, channel_id, publish_id),
+        ExecuteMsg::ChannelDelete { channel_id } => delete_channel(deps, env, info, channel_id),
+        ExecuteMsg::ChannelChangeUsername {
+            channel_id,
+            new_user_name,
+        } => change_username(deps, env, info, channel_id, new_user_name),
+        ExecuteMsg::ChannelCleanup { channel_id, limit } => {
+            cleanup_channel(deps, channel_id, limit)
+        }
         ExecuteMsg::AdminManageReservedUsernames {
             add_usernames,
             remove_usernames,
         } => manage_reserved_usernames(deps, info, add_usernames, remove_usernames),
+        ExecuteMsg::AdminManageTipDenoms {
+            add_tip_denoms,
+            remove_tip_denoms,
+        } => manage_tip_denoms(deps, info, add_tip_denoms, remove_tip_denoms),
+        ExecuteMsg::ChannelSetPayoutMode {
+            channel_id,
+            payout_mode,
+        } => set_payout_mode(deps, env, info, channel_id, payout_mode),
+        ExecuteMsg::WithdrawEarnings {} => withdraw_earnings(deps, info),
         ExecuteMsg::ChannelTip {
-            channel_id, amount, ..
-        } => tip_channel(deps, info, channel_id, amount),
-        ExecuteMsg::ChannelAddCollaborator {
+            channel_id,
+            amount,
+            asset_id,
+        } => tip_channel(deps, env, info, channel_id, amount, asset_id),
+        ExecuteMsg::ChannelInviteCollaborator {
             channel_id,
             collaborator_address,
             collaborator_details,
-        } => add_collaborator(
+            expiry,
+        } => invite_collaborator(
             deps,
+            env,
             info,
             channel_id,
             collaborator_address,
             collaborator_details,
+            expiry,
+        ),
+        ExecuteMsg::ChannelAcceptCollaboratorInvite { channel_id } => {
+            accept_collaborator_invite(deps, env, info, channel_id)
+        }
+        ExecuteMsg::ChannelDeclineCollaboratorInvite { channel_id } => {
+            decline_collaborator_invite(deps, info, channel_id)
+        }
+        ExecuteMsg::ChannelLeaveCollaboration { channel_id } => {
+            leave_collaboration(deps, info, channel_id)
+        }
+        ExecuteMsg::ChannelPruneCollaborators { channel_id } => {
+            prune_collaborators(deps, env, channel_id)
+        }
+        ExecuteMsg::ChannelSyncOwner { channel_id } => sync_channel_owner(deps, channel_id),
+        ExecuteMsg::ChannelUpdateCollaborator {
+            channel_id,
+            collaborator_address,
+            role,
+            share,
+        } => update_collaborator(
+            deps,
+            env,
+            info,
+            channel_id,
+            collaborator_address,
+            role,
+            share,
         ),
         ExecuteMsg::ChannelRemoveCollaborator {
             channel_id,
             collaborator_address,
-        } => remove_collaborator(deps, info, channel_id, collaborator_address),
+        } => remove_collaborator(deps, env, info, channel_id, collaborator_address),
         ExecuteMsg::ChannelFollow { channel_id } => follow_channel(deps, info, channel_id),
         ExecuteMsg::ChannelUnfollow { channel_id } => unfollow_channel(deps, info, channel_id),
+        ExecuteMsg::ChannelSetSubscriptionPlan { channel_id, plan } => {
+            set_subscription_plan(deps, env, info, channel_id, plan)
+        }
+        ExecuteMsg::ChannelSubscribe {
+            channel_id,
+            periods,
+        } => subscribe(deps, env, info, channel_id, periods),
     }
 }
 
@@ -348,6 +507,13 @@ fn create_channel(
             channels_manager.remove_reserved_usernames(deps.storage, vec![user_name.clone()])?;
         }
     }
+    // Usernames released by a username change are held for their previous channel
+    channels_manager.check_username_hold(
+        deps.storage,
+        user_name.clone(),
+        channel_id.clone(),
+        &env.block,
+    )?;
     // Add the new channel to the collection
     // Checks for uniqueness of the channel ID and username
     channels_manager.add_channel(
@@ -356,6 +522,8 @@ fn create_channel(
         channel_details.clone(),
         channel_metadata.clone(),
     )?;
+    // The channel ONFT is minted to the sender
+    channels_manager.set_channel_owner(deps.storage, channel_id.clone(), info.sender.clone())?;
 
     // Create the onft data for the channel. This data will be stored in the onft's data field
     let onft_data = ChannelOnftData {
@@ -394,6 +562,76 @@ fn create_channel(
     Ok(response)
 }
 
+fn change_username(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: String,
+    new_user_name: String,
+) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.storage)?;
+
+    let config = CONFIG.load(deps.storage)?;
+
+    validate_permissions(
+        deps.as_ref(),
+        &env,
+        channel_id.clone(),
+        info.sender.clone(),
+        config.channels_collection_id.clone(),
+        Role::Admin,
+    )?;
+
+    // Check if the payment provided in the message matches the username change fee
+    check_payment(config.username_change_fee.clone(), info.funds.clone())?;
+
+    validate_string(&new_user_name, StringValidationType::Username)?;
+
+    let channels_manager = ChannelsManager::new();
+    // Check if the username is reserved
+    if let Some(reserved_addr) =
+        channels_manager.get_reserved_status(deps.storage, new_user_name.clone())?
+    {
+        if reserved_addr != Some(info.sender.clone()) {
+            return Err(ContractError::UserNameReserved {});
+        }
+        // Sender matches the reserved address, remove the reservation
+        channels_manager.remove_reserved_usernames(deps.storage, vec![new_user_name.clone()])?;
+    }
+
+    // Checks for uniqueness and holds of the new username
+    let old_user_name = channels_manager.change_username(
+        deps.storage,
+        channel_id.clone(),
+        new_user_name.clone(),
+        &env.block,
+        config.username_hold_period,
+    )?;
+
+    // Pay the username change fee to the fee collector
+    let bank_fee_msg = bank_msg_wrapper(
+        config.auth_details.fee_collector,
+        config.username_change_fee,
+    );
+
+    let response = Response::new()
+        .add_messages(bank_fee_msg)
+        .add_attribute("action", "change_username")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("old_user_name", old_user_name)
+        .add_attribute("new_user_name", new_user_name)
+        .add_attribute(
+            "hold_until",
+            env.block
+                .time
+                .plus_seconds(config.username_hold_period)
+                .to_string(),
+        );
+
+    Ok(response)
+}
+
 fn follow_channel(
     deps: DepsMut,
     info: MessageInfo,
@@ -422,8 +660,124 @@ fn unfollow_channel(
     Ok(response)
 }
 
+fn set_subscription_plan(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: String,
+    plan: Option<SubscriptionPlan>,
+) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    validate_permissions(
+        deps.as_ref(),
+        &env,
+        channel_id.clone(),
+        info.sender.clone(),
+        config.channels_collection_id,
+        Role::Admin,
+    )?;
+
+    if let Some(plan) = plan.as_ref() {
+        if plan.price.amount.is_zero() || plan.period == 0 {
+            return Err(ContractError::InvalidSubscriptionPlan {});
+        }
+        if !config.accepted_tip_denoms.contains(&plan.price.denom) {
+            return Err(ContractError::InvalidTipDenom {});
+        }
+    }
+
+    let channels_manager = ChannelsManager::new();
+    channels_manager.set_subscription_plan(deps.storage, channel_id.clone(), plan.clone())?;
+
+    let response = Response::new()
+        .add_attribute("action", "set_subscription_plan")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute(
+            "price",
+            plan.as_ref()
+                .map(|plan| plan.price.to_string())
+                .unwrap_or_default(),
+        )
+        .add_attribute(
+            "period",
+            plan.map(|plan| plan.period.to_string()).unwrap_or_default(),
+        );
+
+    Ok(response)
+}
+
+fn subscribe(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: String,
+    periods: u32,
+) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    if periods == 0 {
+        return Err(ContractError::InvalidSubscriptionPeriods {});
+    }
+
+    let channels_manager = ChannelsManager::new();
+    channels_manager.ensure_not_suspended(deps.storage, channel_id.clone(), env.block.time)?;
+    let plan = channels_manager
+        .get_subscription_plan(deps.storage, channel_id.clone())?
+        .ok_or(ChannelError::SubscriptionPlanNotFound {})?;
+
+    // The subscriber prepays every period at once
+    let price = Coin {
+        denom: plan.price.denom.clone(),
+        amount: plan.price.amount.checked_mul(periods.into())?,
+    };
+    check_payment(vec![price.clone()], info.funds.clone())?;
+
+    let expires_at = channels_manager.add_subscription(
+        deps.storage,
+        channel_id.clone(),
+        info.sender.clone(),
+        periods,
+        &env.block,
+    )?;
+
+    // Subscription revenue is split like tips
+    let channel_details = channels_manager.get_channel_details(deps.storage, channel_id.clone())?;
+    let collaborator_shares =
+        channels_manager.get_collaborator_shares(deps.storage, channel_id.clone(), &env.block)?;
+    let payout_mode = channels_manager.get_payout_mode(deps.storage, channel_id.clone())?;
+    let (bank_msgs, attributes, protocol_fee, creator_amount) = distribute_channel_revenue(
+        deps.storage,
+        &config,
+        collaborator_shares,
+        channel_details.payment_address,
+        &payout_mode,
+        price.clone(),
+    )?;
+
+    let response = Response::new()
+        .add_messages(merge_bank_msgs(bank_msgs))
+        .add_attributes(attributes)
+        .add_attribute("action", "subscribe")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("subscriber", info.sender)
+        .add_attribute("periods", periods.to_string())
+        .add_attribute("amount", price.to_string())
+        .add_attribute("protocol_fee", protocol_fee.to_string())
+        .add_attribute("creator_amount", creator_amount.to_string())
+        .add_attribute("payout_mode", payout_mode.to_string())
+        .add_attribute("expires_at", expires_at.to_string());
+
+    Ok(response)
+}
+
 fn delete_channel(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     channel_id: String,
 ) -> Result<Response, ContractError> {
@@ -433,11 +787,10 @@ fn delete_channel(
     let config = CONFIG.load(deps.storage)?;
 
     let channels_manager = ChannelsManager::new();
-    let assets_manager = AssetsManager::new();
-    let playlist_manager = PlaylistsManager::new();
     // Check if the sender has admin permissions
     validate_permissions(
         deps.as_ref(),
+        &env,
         channel_id.clone(),
         info.sender.clone(),
         config.channels_collection_id.clone(),
@@ -445,12 +798,52 @@ fn delete_channel(
     )?;
 
     channels_manager.delete_channel(deps.storage, channel_id.clone())?;
-    assets_manager.delete_assets_by_channel_id(deps.storage, channel_id.clone())?;
-    playlist_manager.delete_playlists_by_channel_id(deps.storage, channel_id.clone());
+
+    // Removes the rest of the channel state, as much as fits in this transaction
+    let progress = run_channel_cleanup(
+        deps.storage,
+        channel_id.clone(),
+        ChannelCleanupProgress {
+            stage: CleanupStage::Collaborators,
+            removed_entries: 0,
+        },
+        DEFAULT_CLEANUP_LIMIT,
+    )?;
+    channels_manager.set_channel_cleanup(deps.storage, channel_id.clone(), progress.clone())?;
 
     let response = Response::new()
         .add_attribute("action", "delete_channel")
-        .add_attribute("channel_id", channel_id);
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("cleanup_complete", progress.is_none().to_string());
+
+    Ok(response)
+}
+
+fn cleanup_channel(
+    deps: DepsMut,
+    channel_id: String,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.storage)?;
+
+    let channels_manager = ChannelsManager::new();
+    let progress = channels_manager
+        .get_channel_cleanup(deps.storage, channel_id.clone())?
+        .ok_or(ContractError::NoPendingChannelCleanup {})?;
+
+    let progress = run_channel_cleanup(
+        deps.storage,
+        channel_id.clone(),
+        progress,
+        limit.unwrap_or(DEFAULT_CLEANUP_LIMIT),
+    )?;
+    channels_manager.set_channel_cleanup(deps.storage, channel_id.clone(), progress.clone())?;
+
+    let response = Response::new()
+        .add_attribute("action", "cleanup_channel")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("cleanup_complete", progress.is_none().to_string());
 
     Ok(response)
 }
@@ -466,6 +859,9 @@ fn publish(
     playlist_id: Option<String>,
     is_visible: bool,
     metadata: AssetMetadata,
+    access_policy: Option<AccessPolicy>,
+    price: Option<Coin>,
+    publish_at: Option<Timestamp>,
 ) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.storage)?;
@@ -474,11 +870,14 @@ fn publish(
 
     validate_permissions(
         deps.as_ref(),
+        &env,
         channel_id.clone(),
         info.sender.clone(),
         config.channels_collection_id.clone(),
         Role::Publisher,
     )?;
+    let channels_manager = ChannelsManager::new();
+    channels_manager.ensure_not_suspended(deps.storage, channel_id.clone(), env.block.time)?;
 
     let publish_id = generate_random_id_with_prefix(&salt, &env, "publish");
 
@@ -486,12 +885,23 @@ fn publish(
 
     validate_asset_metadata(metadata.clone())?;
 
+    let access_policy = access_policy.unwrap_or_default();
+    validate_access_policy(&access_policy)?;
+
+    if let Some(price) = price.as_ref() {
+        validate_asset_price(price, &config.accepted_tip_denoms)?;
+    }
+
     // Define the asset to be published
     let asset = Asset {
         channel_id: channel_id.clone(),
         publish_id: publish_id.clone(),
         asset_source: asset_source.clone(),
         is_visible,
+        access_policy: access_policy.clone(),
+        price: price.clone(),
+        publish_at,
+        under_review: false,
     };
 
     // Add asset to the channel's asset list
@@ -499,102 +909,220 @@ fn publish(
     let asset_key = (channel_id.clone(), publish_id.clone());
     assets_manager.add_asset(deps.storage, asset_key.clone(), asset.clone(), metadata)?;
 
+    // Hidden and scheduled assets are not added to the playlist
+    let playlist_id = playlist_id.filter(|_| asset.is_live(env.block.time));
     if let Some(playlist_id) = playlist_id.clone() {
-        if is_visible {
-            let playlists_manager = PlaylistsManager::new();
-            playlists_manager.add_asset_to_playlist(
-                deps.storage,
-                channel_id.clone(),
-                playlist_id.clone(),
-                asset_key,
-            )?;
-        }
+        let playlists_manager = PlaylistsManager::new();
+        playlists_manager.add_asset_to_playlist(
+            deps.storage,
+            channel_id.clone(),
+            playlist_id,
+            asset_key,
+        )?;
     }
 
     let mut response = Response::new()
         .add_attribute("action", "publish")
         .add_attribute("publish_id", publish_id)
         .add_attribute("channel_id", channel_id)
-        .add_attribute("asset_source", asset_source.to_string());
+        .add_attribute("asset_source", asset_source.to_string())
+        .add_attribute("access_policy", access_policy.to_string());
 
     if let Some(playlist_id) = playlist_id {
         response = response.add_attribute("playlist_id", playlist_id);
     }
+    if let Some(price) = price {
+        response = response.add_attribute("price", price.to_string());
+    }
+    if let Some(publish_at) = publish_at {
+        response = response.add_attribute("publish_at", publish_at.to_string());
+    }
     Ok(response)
 }
 
-fn unpublish(
+fn set_asset_price(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    publish_id: String,
     channel_id: String,
+    publish_id: String,
+    price: Option<Coin>,
 ) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.storage)?;
-
     let config = CONFIG.load(deps.storage)?;
 
     validate_permissions(
         deps.as_ref(),
+        &env,
         channel_id.clone(),
         info.sender.clone(),
-        config.channels_collection_id.clone(),
+        config.channels_collection_id,
         Role::Publisher,
     )?;
+    let channels_manager = ChannelsManager::new();
+    channels_manager.ensure_not_suspended(deps.storage, channel_id.clone(), env.block.time)?;
+
+    if let Some(price) = price.as_ref() {
+        validate_asset_price(price, &config.accepted_tip_denoms)?;
+    }
 
     let assets_manager = AssetsManager::new();
     let asset_key = (channel_id.clone(), publish_id.clone());
-    assets_manager.delete_assets(deps.storage, vec![asset_key.clone()])?;
+    let mut asset = assets_manager.get_asset(deps.storage, asset_key.clone())?;
+    asset.price = price.clone();
+    assets_manager.update_asset(deps.storage, asset_key, asset)?;
 
     let response = Response::new()
-        .add_attribute("action", "unpublish")
+        .add_attribute("action", "set_asset_price")
+        .add_attribute("channel_id", channel_id)
         .add_attribute("publish_id", publish_id)
-        .add_attribute("channel_id", channel_id);
+        .add_attribute(
+            "price",
+            price.map(|price| price.to_string()).unwrap_or_default(),
+        );
 
     Ok(response)
 }
 
-fn refresh_playlist(
+fn purchase_asset(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     channel_id: String,
-    playlist_id: String,
+    publish_id: String,
 ) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.storage)?;
-
     let config = CONFIG.load(deps.storage)?;
 
-    validate_permissions(
-        deps.as_ref(),
-        channel_id.clone(),
-        info.sender.clone(),
-        config.channels_collection_id.clone(),
-        Role::Publisher,
-    )?;
+    let channels_manager = ChannelsManager::new();
+    channels_manager.ensure_not_suspended(deps.storage, channel_id.clone(), env.block.time)?;
 
-    let playlists_manager = PlaylistsManager::new();
-    let playlist_asset_keys = playlists_manager
-        .get_playlist(deps.storage, channel_id.clone(), playlist_id.clone())?
-        .assets;
-    let asset_keys_to_remove = filter_assets_to_remove(deps.storage, playlist_asset_keys.clone());
+    let assets_manager = AssetsManager::new();
+    let asset_key = (channel_id.clone(), publish_id.clone());
+    let asset = assets_manager.get_asset(deps.storage, asset_key.clone())?;
+    if !asset.is_live(env.block.time) {
+        return Err(ContractError::AssetNotVisible {});
+    }
+    let price = asset.price.ok_or(AssetError::AssetNotForSale {})?;
+    check_payment(vec![price.clone()], info.funds.clone())?;
 
-    playlists_manager.remove_assets_from_playlist(
+    assets_manager.add_purchase(
         deps.storage,
-        channel_id.clone(),
-        playlist_id.clone(),
-        asset_keys_to_remove.clone(),
+        asset_key,
+        PurchaseReceipt {
+            buyer: info.sender.clone(),
+            price: price.clone(),
+            purchased_at: env.block.time,
+        },
+    )?;
+
+    // Purchase revenue is split like tips
+    let channel_details = channels_manager.get_channel_details(deps.storage, channel_id.clone())?;
+    let collaborator_shares =
+        channels_manager.get_collaborator_shares(deps.storage, channel_id.clone(), &env.block)?;
+    let payout_mode = channels_manager.get_payout_mode(deps.storage, channel_id.clone())?;
+    let (bank_msgs, attributes, protocol_fee, creator_amount) = distribute_channel_revenue(
+        deps.storage,
+        &config,
+        collaborator_shares,
+        channel_details.payment_address,
+        &payout_mode,
+        price.clone(),
     )?;
-    let removed_publish_ids: Vec<String> = asset_keys_to_remove
-        .iter()
-        .map(|asset_key| asset_key.1.clone())
-        .collect();
 
     let response = Response::new()
-        .add_attribute("action", "refresh_playlist")
+        .add_messages(merge_bank_msgs(bank_msgs))
+        .add_attributes(attributes)
+        .add_attribute("action", "purchase_asset")
         .add_attribute("channel_id", channel_id)
-        .add_attribute("playlist_id", playlist_id)
-        .add_attribute("removed_publish_ids", removed_publish_ids.join(", "));
+        .add_attribute("publish_id", publish_id)
+        .add_attribute("buyer", info.sender)
+        .add_attribute("amount", price.to_string())
+        .add_attribute("protocol_fee", protocol_fee.to_string())
+        .add_attribute("creator_amount", creator_amount.to_string())
+        .add_attribute("payout_mode", payout_mode.to_string());
+
+    Ok(response)
+}
+
+fn unpublish(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    publish_id: String,
+    channel_id: String,
+) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.storage)?;
+
+    let config = CONFIG.load(deps.storage)?;
+
+    validate_permissions(
+        deps.as_ref(),
+        &env,
+        channel_id.clone(),
+        info.sender.clone(),
+        config.channels_collection_id.clone(),
+        Role::Publisher,
+    )?;
+
+    let assets_manager = AssetsManager::new();
+    let asset_key = (channel_id.clone(), publish_id.clone());
+    assets_manager.delete_assets(deps.storage, vec![asset_key.clone()])?;
+
+    let response = Response::new()
+        .add_attribute("action", "unpublish")
+        .add_attribute("publish_id", publish_id)
+        .add_attribute("channel_id", channel_id);
+
+    Ok(response)
+}
+
+fn refresh_playlist(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: String,
+    playlist_id: String,
+) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.storage)?;
+
+    let config = CONFIG.load(deps.storage)?;
+
+    validate_permissions(
+        deps.as_ref(),
+        &env,
+        channel_id.clone(),
+        info.sender.clone(),
+        config.channels_collection_id.clone(),
+        Role::Publisher,
+    )?;
+
+    let playlists_manager = PlaylistsManager::new();
+    let playlist_asset_keys = playlists_manager
+        .get_playlist(deps.storage, channel_id.clone(), playlist_id.clone())?
+        .assets;
+    let asset_keys_to_remove = filter_assets_to_remove(deps.storage, playlist_asset_keys.clone());
+
+    playlists_manager.remove_assets_from_playlist(
+        deps.storage,
+        channel_id.clone(),
+        playlist_id.clone(),
+        asset_keys_to_remove.clone(),
+    )?;
+    let removed_publish_ids: Vec<String> = asset_keys_to_remove
+        .iter()
+        .map(|asset_key| asset_key.1.clone())
+        .collect();
+
+    let response = Response::new()
+        .add_attribute("action", "refresh_playlist")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("playlist_id", playlist_id)
+        .add_attribute("removed_publish_ids", removed_publish_ids.join(", "));
 
     Ok(response)
 }
@@ -614,11 +1142,14 @@ fn create_playlist(
 
     validate_permissions(
         deps.as_ref(),
+        &env,
         channel_id.clone(),
         info.sender.clone(),
         config.channels_collection_id.clone(),
         Role::Publisher,
     )?;
+    let channels_manager = ChannelsManager::new();
+    channels_manager.ensure_not_suspended(deps.storage, channel_id.clone(), env.block.time)?;
     // Validate the playlist name
     validate_string(&playlist_name, StringValidationType::PlaylistName)?;
     let playlist_id = generate_random_id_with_prefix(&salt, &env, "playlist");
@@ -683,54 +1214,202 @@ fn set_pausers(
     Ok(response)
 }
 
-fn add_collaborator(
+fn invite_collaborator(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     channel_id: String,
     collaborator_address: String,
     collaborator_details: ChannelCollaborator,
+    expiry: Option<Expiration>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
     validate_permissions(
         deps.as_ref(),
+        &env,
         channel_id.clone(),
         info.sender.clone(),
         config.channels_collection_id.clone(),
         Role::Admin,
     )?;
 
+    // An invite that is already expired could never be accepted
+    if let Some(expiry) = expiry {
+        if expiry.is_expired(&env.block) {
+            return Err(ContractError::InvalidExpiration {});
+        }
+    }
+    if collaborator_details.is_expired(&env.block) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+
     // Validate the collaborator address
     let collaborator_address = deps.api.addr_validate(&collaborator_address)?;
     let channels_manager = ChannelsManager::new();
 
-    // Add the collaborator to the channel
-    channels_manager.add_collaborator(
+    channels_manager.add_collaborator_invite(
         deps.storage,
         channel_id.clone(),
         collaborator_address.clone(),
-        collaborator_details.clone(),
+        CollaboratorInvite {
+            collaborator: collaborator_details.clone(),
+            expiry,
+        },
     )?;
 
     let response = Response::new()
-        .add_attribute("action", "add_collaborator")
+        .add_attribute("action", "invite_collaborator")
         .add_attribute("channel_id", channel_id)
         .add_attribute("collaborator_address", collaborator_address)
+        .add_attribute("role", collaborator_details.role.to_string())
         .add_attribute("share", collaborator_details.share.to_string());
 
     Ok(response)
 }
 
-fn remove_collaborator(
+fn accept_collaborator_invite(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: String,
+) -> Result<Response, ContractError> {
+    let channels_manager = ChannelsManager::new();
+    let invite = channels_manager.get_collaborator_invite(
+        deps.storage,
+        channel_id.clone(),
+        info.sender.clone(),
+    )?;
+
+    if let Some(expiry) = invite.expiry {
+        if expiry.is_expired(&env.block) {
+            return Err(ChannelError::CollaboratorInviteExpired {}.into());
+        }
+    }
+    if invite.collaborator.is_expired(&env.block) {
+        return Err(ChannelError::CollaboratorExpired {}.into());
+    }
+
+    channels_manager.remove_collaborator_invite(
+        deps.storage,
+        channel_id.clone(),
+        info.sender.clone(),
+    )?;
+    // Shares are validated again as the channel may have changed since the invite
+    channels_manager.add_collaborator(
+        deps.storage,
+        channel_id.clone(),
+        info.sender.clone(),
+        invite.collaborator.clone(),
+    )?;
+
+    let response = Response::new()
+        .add_attribute("action", "accept_collaborator_invite")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("collaborator_address", info.sender)
+        .add_attribute("share", invite.collaborator.share.to_string());
+
+    Ok(response)
+}
+
+fn decline_collaborator_invite(
+    deps: DepsMut,
+    info: MessageInfo,
+    channel_id: String,
+) -> Result<Response, ContractError> {
+    let channels_manager = ChannelsManager::new();
+    channels_manager.remove_collaborator_invite(
+        deps.storage,
+        channel_id.clone(),
+        info.sender.clone(),
+    )?;
+
+    let response = Response::new()
+        .add_attribute("action", "decline_collaborator_invite")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("collaborator_address", info.sender);
+
+    Ok(response)
+}
+
+fn leave_collaboration(
+    deps: DepsMut,
+    info: MessageInfo,
+    channel_id: String,
+) -> Result<Response, ContractError> {
+    let channels_manager = ChannelsManager::new();
+    channels_manager.remove_collaborator(deps.storage, channel_id.clone(), info.sender.clone())?;
+
+    let response = Response::new()
+        .add_attribute("action", "leave_collaboration")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("collaborator_address", info.sender);
+
+    Ok(response)
+}
+
+fn sync_channel_owner(deps: DepsMut, channel_id: String) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let channels_manager = ChannelsManager::new();
+    let channel_details = channels_manager.get_channel_details(deps.storage, channel_id.clone())?;
+
+    let onft = get_onft(
+        deps.as_ref(),
+        config.channels_collection_id,
+        channel_details.onft_id,
+    )?;
+    let owner = deps.api.addr_validate(&onft.owner)?;
+    channels_manager.set_channel_owner(deps.storage, channel_id.clone(), owner.clone())?;
+
+    let response = Response::new()
+        .add_attribute("action", "sync_channel_owner")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("owner", owner);
+
+    Ok(response)
+}
+
+fn prune_collaborators(
+    deps: DepsMut,
+    env: Env,
+    channel_id: String,
+) -> Result<Response, ContractError> {
+    let channels_manager = ChannelsManager::new();
+    let pruned = channels_manager.prune_expired_collaborators(
+        deps.storage,
+        channel_id.clone(),
+        &env.block,
+    )?;
+
+    let response = Response::new()
+        .add_attribute("action", "prune_collaborators")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute(
+            "pruned_collaborators",
+            pruned
+                .iter()
+                .map(|address| address.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+
+    Ok(response)
+}
+
+fn update_collaborator(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     channel_id: String,
     collaborator_address: String,
+    role: Option<Role>,
+    share: Option<Decimal>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
     validate_permissions(
         deps.as_ref(),
+        &env,
         channel_id.clone(),
         info.sender.clone(),
         config.channels_collection_id.clone(),
@@ -740,14 +1419,73 @@ fn remove_collaborator(
     // Validate the collaborator address
     let collaborator_address = deps.api.addr_validate(&collaborator_address)?;
 
-    // Remove the collaborator from the channel
     let channels_manager = ChannelsManager::new();
-    channels_manager.remove_collaborator(
+    let (old_collaborator, new_collaborator) = channels_manager.update_collaborator(
         deps.storage,
         channel_id.clone(),
         collaborator_address.clone(),
+        role,
+        share,
+        &env.block,
+    )?;
+
+    let response = Response::new()
+        .add_attribute("action", "update_collaborator")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("collaborator_address", collaborator_address)
+        .add_attribute("old_role", old_collaborator.role.to_string())
+        .add_attribute("new_role", new_collaborator.role.to_string())
+        .add_attribute("old_share", old_collaborator.share.to_string())
+        .add_attribute("new_share", new_collaborator.share.to_string());
+
+    Ok(response)
+}
+
+fn remove_collaborator(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: String,
+    collaborator_address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    validate_permissions(
+        deps.as_ref(),
+        &env,
+        channel_id.clone(),
+        info.sender.clone(),
+        config.channels_collection_id.clone(),
+        Role::Admin,
     )?;
 
+    // Validate the collaborator address
+    let collaborator_address = deps.api.addr_validate(&collaborator_address)?;
+
+    let channels_manager = ChannelsManager::new();
+    if channels_manager
+        .get_collaborator_invite(
+            deps.storage,
+            channel_id.clone(),
+            collaborator_address.clone(),
+        )
+        .is_ok()
+    {
+        // Cancel the pending invite
+        channels_manager.remove_collaborator_invite(
+            deps.storage,
+            channel_id.clone(),
+            collaborator_address.clone(),
+        )?;
+    } else {
+        // Remove the collaborator from the channel
+        channels_manager.remove_collaborator(
+            deps.storage,
+            channel_id.clone(),
+            collaborator_address.clone(),
+        )?;
+    }
+
     let response = Response::new()
         .add_attribute("action", "remove_collaborator")
         .add_attribute("channel_id", channel_id)
@@ -759,6 +1497,7 @@ fn remove_collaborator(
 #[allow(clippy::too_many_arguments)]
 fn update_channel_details(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     channel_id: String,
     description: Option<String>,
@@ -776,11 +1515,13 @@ fn update_channel_details(
 
     validate_permissions(
         deps.as_ref(),
+        &env,
         channel_id.clone(),
         info.sender.clone(),
         channels_collection_id.clone(),
         Role::Admin,
     )?;
+    channel_manager.ensure_not_suspended(deps.storage, channel_id.clone(), env.block.time)?;
 
     let mut channel_metadata =
         channel_manager.get_channel_metadata(deps.storage, channel_id.clone())?;
@@ -826,6 +1567,7 @@ fn update_channel_details(
 
 fn delete_playlist(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     channel_id: String,
     playlist_id: String,
@@ -837,6 +1579,7 @@ fn delete_playlist(
 
     validate_permissions(
         deps.as_ref(),
+        &env,
         channel_id.clone(),
         info.sender.clone(),
         config.channels_collection_id.clone(),
@@ -855,6 +1598,7 @@ fn delete_playlist(
 }
 fn add_asset_to_playlist(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     asset_channel_id: String,
     publish_id: String,
@@ -868,11 +1612,14 @@ fn add_asset_to_playlist(
 
     validate_permissions(
         deps.as_ref(),
+        &env,
         channel_id.clone(),
         info.sender.clone(),
         config.channels_collection_id.clone(),
         Role::Publisher,
     )?;
+    let channels_manager = ChannelsManager::new();
+    channels_manager.ensure_not_suspended(deps.storage, channel_id.clone(), env.block.time)?;
 
     let playlist_manager = PlaylistsManager::new();
 
@@ -881,8 +1628,8 @@ fn add_asset_to_playlist(
     let asset_key = (asset_channel_id.clone(), publish_id.clone());
     let asset = assets_manager.get_asset(deps.storage, asset_key.clone())?;
 
-    // Verify that the asset is visible
-    if !asset.is_visible {
+    // Verify that the asset is visible and live
+    if !asset.is_live(env.block.time) {
         return Err(ContractError::AssetNotVisible {});
     }
 
@@ -904,6 +1651,7 @@ fn add_asset_to_playlist(
 }
 fn remove_asset_from_playlist(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     publish_id: String,
     channel_id: String,
@@ -916,6 +1664,7 @@ fn remove_asset_from_playlist(
 
     validate_permissions(
         deps.as_ref(),
+        &env,
         channel_id.clone(),
         info.sender.clone(),
         config.channels_collection_id.clone(),
@@ -943,6 +1692,7 @@ fn remove_asset_from_playlist(
 #[allow(clippy::too_many_arguments)]
 fn update_asset_details(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     publish_id: String,
     channel_id: String,
@@ -951,6 +1701,9 @@ fn update_asset_details(
     description: Option<String>,
     media_uri: Option<String>,
     thumbnail_uri: Option<String>,
+    access_policy: Option<AccessPolicy>,
+    publish_at: Option<Timestamp>,
+    clear_publish_at: bool,
 ) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.storage)?;
@@ -959,11 +1712,14 @@ fn update_asset_details(
 
     validate_permissions(
         deps.as_ref(),
+        &env,
         channel_id.clone(),
         info.sender.clone(),
         config.channels_collection_id.clone(),
         Role::Publisher,
     )?;
+    let channels_manager = ChannelsManager::new();
+    channels_manager.ensure_not_suspended(deps.storage, channel_id.clone(), env.block.time)?;
 
     let assets_manager = AssetsManager::new();
     let asset_key = (channel_id.clone(), publish_id.clone());
@@ -991,6 +1747,19 @@ fn update_asset_details(
         validate_string(&thumbnail_uri, StringValidationType::Link)?;
         metadata.thumbnail_uri = Some(thumbnail_uri);
     }
+    if let Some(access_policy) = access_policy {
+        validate_access_policy(&access_policy)?;
+        asset.access_policy = access_policy;
+    }
+    if clear_publish_at {
+        if publish_at.is_some() {
+            return Err(ContractError::InvalidPublishAt {});
+        }
+        asset.publish_at = None;
+    }
+    if let Some(publish_at) = publish_at {
+        asset.publish_at = Some(publish_at);
+    }
 
     assets_manager.update_asset(deps.storage, asset_key.clone(), asset.clone())?;
     assets_manager.update_asset_metadata(deps.storage, asset_key.clone(), metadata.clone())?;
@@ -1000,6 +1769,14 @@ fn update_asset_details(
         .add_attribute("channel_id", channel_id)
         .add_attribute("publish_id", publish_id)
         .add_attribute("is_visible", asset.is_visible.to_string())
+        .add_attribute("access_policy", asset.access_policy.to_string())
+        .add_attribute(
+            "publish_at",
+            asset
+                .publish_at
+                .map(|publish_at| publish_at.to_string())
+                .unwrap_or_default(),
+        )
         .add_attribute("name", metadata.name.clone())
         .add_attribute("description", metadata.description.clone())
         .add_attribute("media_uri", metadata.media_uri.clone());
@@ -1011,8 +1788,10 @@ fn set_config(
     deps: DepsMut,
     info: MessageInfo,
     channel_creation_fee: Option<Vec<Coin>>,
-    protocol_admin: Option<String>,
     fee_collector: Option<String>,
+    tip_fee_rate: Option<Decimal>,
+    username_change_fee: Option<Vec<Coin>>,
+    username_hold_period: Option<u64>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
@@ -1020,11 +1799,6 @@ fn set_config(
         return Err(ContractError::Unauthorized {});
     }
 
-    if let Some(protocol_admin) = protocol_admin {
-        let protocol_admin = deps.api.addr_validate(&protocol_admin)?;
-        config.auth_details.protocol_admin = protocol_admin;
-    }
-
     if let Some(fee_collector) = fee_collector {
         let fee_collector = deps.api.addr_validate(&fee_collector)?;
         config.auth_details.fee_collector = fee_collector;
@@ -1034,6 +1808,23 @@ fn set_config(
         config.channel_creation_fee = channel_creation_fee;
     }
 
+    if let Some(tip_fee_rate) = tip_fee_rate {
+        if tip_fee_rate > MAX_TIP_FEE_RATE {
+            return Err(ContractError::TipFeeRateTooHigh {
+                max: MAX_TIP_FEE_RATE,
+            });
+        }
+        config.tip_fee_rate = tip_fee_rate;
+    }
+
+    if let Some(username_change_fee) = username_change_fee {
+        config.username_change_fee = username_change_fee;
+    }
+
+    if let Some(username_hold_period) = username_hold_period {
+        config.username_hold_period = username_hold_period;
+    }
+
     CONFIG.save(deps.storage, &config)?;
 
     let response = Response::new()
@@ -1045,36 +1836,136 @@ fn set_config(
         .add_attribute(
             "fee_collector",
             config.auth_details.fee_collector.to_string(),
+        )
+        .add_attribute("tip_fee_rate", config.tip_fee_rate.to_string())
+        .add_attribute(
+            "username_hold_period",
+            config.username_hold_period.to_string(),
         );
 
     Ok(response)
 }
 
-fn manage_reserved_usernames(
+fn propose_protocol_admin(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    add_usernames: Option<Vec<ReservedUsername>>,
-    remove_usernames: Option<Vec<String>>,
+    new_admin: String,
+    expiry: Option<Expiration>,
 ) -> Result<Response, ContractError> {
-    let pause_state = PauseState::new()?;
-    pause_state.error_if_paused(deps.storage)?;
-
     let config = CONFIG.load(deps.storage)?;
 
     if info.sender != config.auth_details.protocol_admin {
         return Err(ContractError::Unauthorized {});
     }
 
-    let channels_manager = ChannelsManager::new();
+    let new_admin = deps.api.addr_validate(&new_admin)?;
 
-    let mut attrs = vec![(
-        "action".to_string(),
-        "manage_reserved_usernames".to_string(),
-    )];
-    if let Some(add_usernames) = add_usernames {
-        validate_reserved_usernames(add_usernames.clone(), deps.api)?;
-        channels_manager.add_reserved_usernames(deps.storage, add_usernames.clone())?;
-        for username in add_usernames {
+    // The proposal must be acceptable at the time it is made
+    if let Some(expiry) = expiry {
+        if expiry.is_expired(&env.block) {
+            return Err(ContractError::InvalidExpiration {});
+        }
+    }
+
+    // Any previous proposal is replaced
+    PENDING_PROTOCOL_ADMIN.save(
+        deps.storage,
+        &PendingProtocolAdmin {
+            address: new_admin.clone(),
+            expiry,
+        },
+    )?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "propose_protocol_admin")
+        .add_attribute("protocol_admin", config.auth_details.protocol_admin)
+        .add_attribute("pending_protocol_admin", new_admin);
+
+    if let Some(expiry) = expiry {
+        response = response.add_attribute("expiry", expiry.to_string());
+    }
+
+    Ok(response)
+}
+
+fn accept_protocol_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending_admin = PENDING_PROTOCOL_ADMIN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingProtocolAdmin {})?;
+
+    if info.sender != pending_admin.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(expiry) = pending_admin.expiry {
+        if expiry.is_expired(&env.block) {
+            return Err(ContractError::PendingProtocolAdminExpired {});
+        }
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let previous_admin = config.auth_details.protocol_admin.clone();
+    config.auth_details.protocol_admin = pending_admin.address.clone();
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_PROTOCOL_ADMIN.remove(deps.storage);
+
+    let response = Response::new()
+        .add_attribute("action", "accept_protocol_admin")
+        .add_attribute("previous_protocol_admin", previous_admin)
+        .add_attribute("protocol_admin", pending_admin.address);
+
+    Ok(response)
+}
+
+fn cancel_protocol_admin(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.auth_details.protocol_admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let pending_admin = PENDING_PROTOCOL_ADMIN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingProtocolAdmin {})?;
+    PENDING_PROTOCOL_ADMIN.remove(deps.storage);
+
+    let response = Response::new()
+        .add_attribute("action", "cancel_protocol_admin")
+        .add_attribute("pending_protocol_admin", pending_admin.address);
+
+    Ok(response)
+}
+
+fn manage_reserved_usernames(
+    deps: DepsMut,
+    info: MessageInfo,
+    add_usernames: Option<Vec<ReservedUsername>>,
+    remove_usernames: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.storage)?;
+
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.auth_details.protocol_admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let channels_manager = ChannelsManager::new();
+
+    let mut attrs = vec![(
+        "action".to_string(),
+        "manage_reserved_usernames".to_string(),
+    )];
+    if let Some(add_usernames) = add_usernames {
+        validate_reserved_usernames(add_usernames.clone(), deps.api)?;
+        channels_manager.add_reserved_usernames(deps.storage, add_usernames.clone())?;
+        for username in add_usernames {
             attrs.push(("add_username".to_string(), username.username)); // Directly push owned username
         }
     }
@@ -1090,42 +1981,252 @@ fn manage_reserved_usernames(
     Ok(response)
 }
 
+fn manage_tip_denoms(
+    deps: DepsMut,
+    info: MessageInfo,
+    add_tip_denoms: Option<Vec<TipDenom>>,
+    remove_tip_denoms: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.auth_details.protocol_admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut attrs = vec![("action".to_string(), "manage_tip_denoms".to_string())];
+    if let Some(add_tip_denoms) = add_tip_denoms {
+        for tip_denom in add_tip_denoms {
+            if tip_denom.denom.is_empty() {
+                return Err(ContractError::InvalidTipDenom {});
+            }
+            if !config.accepted_tip_denoms.contains(&tip_denom.denom) {
+                config.accepted_tip_denoms.push(tip_denom.denom.clone());
+            }
+            // Adding an accepted denom again overrides its minimum amount
+            match tip_denom.min_amount {
+                Some(min_amount) => TIP_DENOM_MIN_AMOUNTS.save(
+                    deps.storage,
+                    tip_denom.denom.clone(),
+                    &min_amount,
+                )?,
+                None => TIP_DENOM_MIN_AMOUNTS.remove(deps.storage, tip_denom.denom.clone()),
+            }
+            attrs.push(("add_tip_denom".to_string(), tip_denom.denom));
+        }
+    }
+    if let Some(remove_tip_denoms) = remove_tip_denoms {
+        for denom in remove_tip_denoms {
+            if !config.accepted_tip_denoms.contains(&denom) {
+                return Err(ContractError::TipDenomNotFound { denom });
+            }
+            config.accepted_tip_denoms.retain(|d| d != &denom);
+            TIP_DENOM_MIN_AMOUNTS.remove(deps.storage, denom.clone());
+            attrs.push(("remove_tip_denom".to_string(), denom));
+        }
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    let response = Response::new().add_attributes(attrs);
+
+    Ok(response)
+}
+
 fn tip_channel(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     channel_id: String,
-    amount: Coin,
+    amount: Vec<Coin>,
+    asset_id: Option<String>,
 ) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.storage)?;
     let config = CONFIG.load(deps.storage)?;
-    let accepted_tip_denoms = config.accepted_tip_denoms.clone();
-    if !accepted_tip_denoms.contains(&amount.denom) {
-        return Err(ContractError::InvalidTipDenom {});
-    }
 
-    let sent_amount = must_pay(&info, &amount.denom)?;
+    check_payment(amount.clone(), info.funds.clone())?;
 
-    if sent_amount != amount.amount {
+    // Merge duplicate denoms and drop zero amounts
+    let mut tip_balance = NativeBalance(amount);
+    tip_balance.normalize();
+    if tip_balance.is_empty() {
         return Err(ContractError::InvalidTipAmount {});
     }
 
+    for coin in tip_balance.0.iter() {
+        if !config.accepted_tip_denoms.contains(&coin.denom) {
+            return Err(ContractError::InvalidTipDenom {});
+        }
+        if let Some(min_amount) =
+            TIP_DENOM_MIN_AMOUNTS.may_load(deps.storage, coin.denom.clone())?
+        {
+            if coin.amount < min_amount {
+                return Err(ContractError::TipAmountBelowMinimum {
+                    min_amount: Coin {
+                        denom: coin.denom.clone(),
+                        amount: min_amount,
+                    },
+                });
+            }
+        }
+    }
+
     let channels_manager = ChannelsManager::new();
     let channel_details = channels_manager.get_channel_details(deps.storage, channel_id.clone())?;
+    channels_manager.ensure_not_suspended(deps.storage, channel_id.clone(), env.block.time)?;
     let channel_payment_address = channel_details.payment_address.clone();
-    // Calculates the shares of the collaborators
+    // Calculates the shares of the active collaborators, expired shares are left to the payment address
     let collaborator_shares =
-        channels_manager.get_collaborator_shares(deps.storage, channel_id.clone())?;
-    // Distributes the funds to the collaborators and remaining to the channel payment address
-    let (bank_msgs, attributes) =
-        distribute_funds_with_shares(collaborator_shares, amount.clone(), channel_payment_address)?;
+        channels_manager.get_collaborator_shares(deps.storage, channel_id.clone(), &env.block)?;
+
+    // The tipped asset must belong to the channel
+    if let Some(asset_id) = asset_id.clone() {
+        let assets_manager = AssetsManager::new();
+        assets_manager.get_asset(deps.storage, (channel_id.clone(), asset_id))?;
+    }
+
+    let payout_mode = channels_manager.get_payout_mode(deps.storage, channel_id.clone())?;
+
+    let tips_manager = TipsManager::new();
+    tips_manager.record_tip(
+        deps.storage,
+        channel_id.clone(),
+        asset_id.clone(),
+        info.sender.clone(),
+        tip_balance.0.clone(),
+    )?;
+
+    let mut bank_msgs: Vec<CosmosMsg> = vec![];
+    let mut attributes = vec![];
+    let mut protocol_fees: Vec<Coin> = vec![];
+    let mut creator_amounts: Vec<Coin> = vec![];
+    for coin in tip_balance.0.iter() {
+        let (revenue_msgs, revenue_attributes, protocol_fee, creator_amount) =
+            distribute_channel_revenue(
+                deps.storage,
+                &config,
+                collaborator_shares.clone(),
+                channel_payment_address.clone(),
+                &payout_mode,
+                coin.clone(),
+            )?;
+        bank_msgs.extend(revenue_msgs);
+        attributes.extend(revenue_attributes);
+        protocol_fees.push(protocol_fee);
+        creator_amounts.push(creator_amount);
+    }
 
     let response = Response::new()
-        .add_messages(bank_msgs)
+        .add_messages(merge_bank_msgs(bank_msgs))
         .add_attributes(attributes)
         .add_attribute("action", "tip_creator")
         .add_attribute("channel_id", channel_id)
-        .add_attribute("amount", amount.to_string());
+        .add_attribute("amount", coins_to_string(&tip_balance.0))
+        .add_attribute("protocol_fee", coins_to_string(&protocol_fees))
+        .add_attribute(
+            "fee_collector",
+            config.auth_details.fee_collector.to_string(),
+        )
+        .add_attribute("creator_amount", coins_to_string(&creator_amounts))
+        .add_attribute("payout_mode", payout_mode.to_string())
+        .add_attribute("asset_id", asset_id.unwrap_or_default());
+
+    Ok(response)
+}
+
+/// Deducts the protocol fee from channel revenue and splits the rest between the collaborators
+/// and the channel payment address, according to the payout mode of the channel
+///
+/// # Returns
+/// * The bank messages, the share attributes, the protocol fee and the amount left to the channel
+fn distribute_channel_revenue(
+    storage: &mut dyn Storage,
+    config: &ChannelConractConfig,
+    collaborator_shares: Vec<(Addr, Decimal)>,
+    payment_address: Addr,
+    payout_mode: &PayoutMode,
+    amount: Coin,
+) -> Result<(Vec<CosmosMsg>, Vec<Attribute>, Coin, Coin), ContractError> {
+    let mut bank_msgs: Vec<CosmosMsg> = vec![];
+    let mut attributes = vec![];
+
+    // Deducts the protocol fee before the collaborator split
+    let (protocol_fee, creator_amount) = split_protocol_fee(amount, config.tip_fee_rate)?;
+    bank_msgs.extend(bank_msg_wrapper(
+        config.auth_details.fee_collector.clone(),
+        vec![protocol_fee.clone()],
+    ));
+    match payout_mode {
+        PayoutMode::Push => {
+            // Distributes the funds to the collaborators and remaining to the channel payment address
+            let (share_msgs, share_attributes) = distribute_funds_with_shares(
+                collaborator_shares,
+                creator_amount.clone(),
+                payment_address,
+            )?;
+            bank_msgs.extend(share_msgs);
+            attributes.extend(share_attributes);
+        }
+        PayoutMode::Pull => {
+            // Credits the shares to be withdrawn later
+            let tips_manager = TipsManager::new();
+            let shares =
+                calculate_shares(collaborator_shares, creator_amount.clone(), payment_address)?;
+            for (recipient, share) in shares {
+                tips_manager.credit_earnings(storage, recipient.clone(), share.clone())?;
+                attributes.push(Attribute::new(recipient.to_string(), share.to_string()));
+            }
+        }
+    }
+    Ok((bank_msgs, attributes, protocol_fee, creator_amount))
+}
+
+fn set_payout_mode(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: String,
+    payout_mode: PayoutMode,
+) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    validate_permissions(
+        deps.as_ref(),
+        &env,
+        channel_id.clone(),
+        info.sender.clone(),
+        config.channels_collection_id,
+        Role::Admin,
+    )?;
+
+    let channels_manager = ChannelsManager::new();
+    channels_manager.set_payout_mode(deps.storage, channel_id.clone(), payout_mode.clone())?;
+
+    let response = Response::new()
+        .add_attribute("action", "set_payout_mode")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("payout_mode", payout_mode.to_string());
+
+    Ok(response)
+}
+
+fn withdraw_earnings(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.storage)?;
+
+    let tips_manager = TipsManager::new();
+    let earnings = tips_manager.take_pending_earnings(deps.storage, info.sender.clone())?;
+    if earnings.is_empty() {
+        return Err(ContractError::NoPendingEarnings {});
+    }
+
+    let response = Response::new()
+        .add_messages(bank_msg_wrapper(info.sender.clone(), earnings.clone()))
+        .add_attribute("action", "withdraw_earnings")
+        .add_attribute("recipient", info.sender.to_string())
+        .add_attribute("amount", coins_to_string(&earnings));
 
     Ok(response)
 }
@@ -1137,7 +2238,9 @@ fn remove_assets(
     refresh_flags: Option<bool>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    if info.sender != config.auth_details.protocol_admin {
+    validate_protocol_moderator(deps.storage, &config.auth_details, &info.sender)?;
+    // Only the protocol admin can remove the flags of all assets
+    if refresh_flags == Some(true) && info.sender != config.auth_details.protocol_admin {
         return Err(ContractError::Unauthorized {});
     }
 
@@ -1148,47 +2251,389 @@ fn remove_assets(
     let assets_manager = AssetsManager::new();
     assets_manager.delete_assets(deps.storage, asset_keys)?;
 
+    let mut response = Response::new()
+        .add_attribute("action", "remove_assets")
+        .add_attribute("admin", info.sender.to_string());
+
     // Refresh the flags if set
-    // If the flag is set to true, all flags of all assets will be removed
-    if let Some(refresh_flags) = refresh_flags {
-        if refresh_flags {
-            assets_manager.remove_all_flags(deps.storage)?;
+    // If the flag is set to true, the first batch of the flags of all assets is removed
+    if refresh_flags == Some(true) {
+        let flags_cleared =
+            assets_manager.remove_flags_with_limit(deps.storage, DEFAULT_CLEANUP_LIMIT as usize)?;
+        response = response.add_attribute("flags_cleared", flags_cleared.to_string());
+    }
+
+    Ok(response)
+}
+
+fn manage_moderators(
+    deps: DepsMut,
+    info: MessageInfo,
+    add_moderators: Option<Vec<String>>,
+    remove_moderators: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.auth_details.protocol_admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut attrs = vec![("action".to_string(), "manage_moderators".to_string())];
+    if let Some(add_moderators) = add_moderators {
+        for moderator in add_moderators {
+            let moderator = deps.api.addr_validate(&moderator)?;
+            MODERATORS.save(deps.storage, moderator.clone(), &true)?;
+            attrs.push(("add_moderator".to_string(), moderator.to_string()));
+        }
+    }
+    if let Some(remove_moderators) = remove_moderators {
+        for moderator in remove_moderators {
+            let moderator = deps.api.addr_validate(&moderator)?;
+            if !MODERATORS.has(deps.storage, moderator.clone()) {
+                return Err(ContractError::ModeratorNotFound {
+                    address: moderator.to_string(),
+                });
+            }
+            MODERATORS.remove(deps.storage, moderator.clone());
+            attrs.push(("remove_moderator".to_string(), moderator.to_string()));
         }
     }
 
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn hide_assets(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_keys: Vec<AssetKey>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    validate_protocol_moderator(deps.storage, &config.auth_details, &info.sender)?;
+
+    let assets_manager = AssetsManager::new();
+    for asset_key in asset_keys {
+        assets_manager.set_under_review(deps.storage, asset_key, true, env.block.time)?;
+    }
+
     Ok(Response::new()
-        .add_attribute("action", "remove_assets")
-        .add_attribute("admin", config.auth_details.protocol_admin.to_string()))
+        .add_attribute("action", "hide_assets")
+        .add_attribute("admin", info.sender.to_string()))
+}
+
+fn suspend_channel(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: String,
+    reason: String,
+    until: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    validate_protocol_moderator(deps.storage, &config.auth_details, &info.sender)?;
+
+    if reason.trim().is_empty() || until.is_some_and(|until| until <= env.block.time) {
+        return Err(ContractError::InvalidChannelSuspension {});
+    }
+    validate_string(&reason, StringValidationType::Description)?;
+
+    let channels_manager = ChannelsManager::new();
+    channels_manager.suspend_channel(
+        deps.storage,
+        channel_id.clone(),
+        ChannelSuspension {
+            reason: reason.clone(),
+            suspended_at: env.block.time,
+            until,
+        },
+    )?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "suspend_channel")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("reason", reason)
+        .add_attribute("admin", info.sender.to_string());
+    if let Some(until) = until {
+        response = response.add_attribute("until", until.to_string());
+    }
+
+    Ok(response)
+}
+
+fn unsuspend_channel(
+    deps: DepsMut,
+    info: MessageInfo,
+    channel_id: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    validate_protocol_moderator(deps.storage, &config.auth_details, &info.sender)?;
+
+    let channels_manager = ChannelsManager::new();
+    channels_manager.unsuspend_channel(deps.storage, channel_id.clone())?;
+
+    Ok(Response::new()
+        .add_attribute("action", "unsuspend_channel")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("admin", info.sender.to_string()))
+}
+
+fn reset_flags(
+    deps: DepsMut,
+    info: MessageInfo,
+    asset_keys: Vec<AssetKey>,
+    flags: Option<Vec<Flag>>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    validate_protocol_moderator(deps.storage, &config.auth_details, &info.sender)?;
+
+    let assets_manager = AssetsManager::new();
+    for asset_key in asset_keys {
+        assets_manager.reset_asset_flags(deps.storage, asset_key, flags.clone())?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "reset_flags")
+        .add_attribute("admin", info.sender.to_string()))
+}
+
+fn clear_all_flags(
+    deps: DepsMut,
+    info: MessageInfo,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.auth_details.protocol_admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let limit = limit
+        .unwrap_or(DEFAULT_CLEANUP_LIMIT)
+        .min(MAX_CLEANUP_LIMIT) as usize;
+    let flags_cleared = AssetsManager::new().remove_flags_with_limit(deps.storage, limit)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "clear_all_flags")
+        .add_attribute("flags_cleared", flags_cleared.to_string()))
+}
+
+fn set_flag_thresholds(
+    deps: DepsMut,
+    info: MessageInfo,
+    thresholds: Vec<FlagThreshold>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.auth_details.protocol_admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Existing counts are not re-checked here. An asset already at or above a lowered
+    // threshold is put under review when the flag is raised on it again.
+    let mut attrs = vec![("action".to_string(), "set_flag_thresholds".to_string())];
+    for flag_threshold in thresholds {
+        let key = flag_threshold.flag.to_key();
+        if flag_threshold.threshold == 0 {
+            FLAG_THRESHOLDS.remove(deps.storage, key);
+        } else {
+            FLAG_THRESHOLDS.save(deps.storage, key, &flag_threshold)?;
+        }
+        attrs.push((
+            flag_threshold.flag.to_string(),
+            flag_threshold.threshold.to_string(),
+        ));
+    }
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn restore_assets(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_keys: Vec<AssetKey>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    validate_protocol_moderator(deps.storage, &config.auth_details, &info.sender)?;
+
+    let thresholds = FLAG_THRESHOLDS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, flag_threshold)| flag_threshold))
+        .collect::<StdResult<Vec<FlagThreshold>>>()?;
+
+    let assets_manager = AssetsManager::new();
+    for asset_key in asset_keys {
+        assets_manager.set_under_review(deps.storage, asset_key.clone(), false, env.block.time)?;
+        // Flags already at their threshold do not put the asset under review again
+        for flag_threshold in thresholds.iter() {
+            let flag_count = assets_manager.get_flag_count(
+                deps.storage,
+                asset_key.0.clone(),
+                asset_key.1.clone(),
+                flag_threshold.flag.clone(),
+            )?;
+            if flag_count >= flag_threshold.threshold {
+                assets_manager.set_flag_restored(
+                    deps.storage,
+                    asset_key.clone(),
+                    flag_threshold.flag.clone(),
+                    true,
+                )?;
+            }
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "restore_assets")
+        .add_attribute("admin", info.sender.to_string()))
 }
 
 fn flag_asset(
     deps: DepsMut,
-    _info: MessageInfo,
+    env: Env,
+    info: MessageInfo,
     channel_id: String,
     publish_id: String,
     flag: Flag,
+    interactive_video_id: Option<String>,
 ) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.storage)?;
 
+    validate_flag_report(&flag, interactive_video_id.as_ref())?;
+
     let assets_manager = AssetsManager::new();
     assets_manager.add_flag(
+        deps.storage,
+        channel_id.clone(),
+        publish_id.clone(),
+        FlagReport {
+            reporter: info.sender.clone(),
+            flag: flag.clone(),
+            interactive_video_id: interactive_video_id.clone(),
+            reported_at: env.block.time,
+        },
+    )?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "asset_flag")
+        .add_attribute("channel_id", channel_id.clone())
+        .add_attribute("publish_id", publish_id.clone())
+        .add_attribute("flag", flag.to_string())
+        .add_attribute("flagger", info.sender);
+    if let Some(interactive_video_id) = interactive_video_id {
+        response = response.add_attribute("interactive_video_id", interactive_video_id);
+    }
+
+    // Put the asset under review when the flag is at or above its threshold, which also covers
+    // counts that were already above a lowered threshold. Restored assets are only put under
+    // review again if the count drops below the threshold and reaches it again.
+    if let Some(flag_threshold) = FLAG_THRESHOLDS.may_load(deps.storage, flag.to_key())? {
+        let asset_key = (channel_id.clone(), publish_id.clone());
+        let flag_count =
+            assets_manager.get_flag_count(deps.storage, channel_id, publish_id, flag.clone())?;
+        let asset = assets_manager.get_asset(deps.storage, asset_key.clone())?;
+        let restored =
+            assets_manager.is_flag_restored(deps.storage, asset_key.clone(), flag.clone());
+        if flag_count < flag_threshold.threshold {
+            if restored {
+                assets_manager.set_flag_restored(deps.storage, asset_key, flag, false)?;
+            }
+        } else if !asset.under_review && !restored {
+            assets_manager.set_under_review(deps.storage, asset_key, true, env.block.time)?;
+            response = response.add_attribute("under_review", "true");
+        }
+    }
+
+    Ok(response)
+}
+
+fn retract_flag(
+    deps: DepsMut,
+    info: MessageInfo,
+    channel_id: String,
+    publish_id: String,
+    flag: Flag,
+) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.storage)?;
+
+    let assets_manager = AssetsManager::new();
+    assets_manager.remove_flag(
         deps.storage,
         channel_id.clone(),
         publish_id.clone(),
         flag.clone(),
+        info.sender.clone(),
     )?;
 
+    // A restored flag that drops below its threshold can put the asset under review again
+    let asset_key = (channel_id.clone(), publish_id.clone());
+    if assets_manager.is_flag_restored(deps.storage, asset_key.clone(), flag.clone()) {
+        let flag_count = assets_manager.get_flag_count(
+            deps.storage,
+            channel_id.clone(),
+            publish_id.clone(),
+            flag.clone(),
+        )?;
+        let below_threshold = FLAG_THRESHOLDS
+            .may_load(deps.storage, flag.to_key())?
+            .is_none_or(|flag_threshold| flag_count < flag_threshold.threshold);
+        if below_threshold {
+            assets_manager.set_flag_restored(deps.storage, asset_key, flag.clone(), false)?;
+        }
+    }
+
     Ok(Response::new()
-        .add_attribute("action", "asset_flag")
+        .add_attribute("action", "asset_retract_flag")
         .add_attribute("channel_id", channel_id)
         .add_attribute("publish_id", publish_id)
-        .add_attribute("flag", flag.to_string()))
+        .add_attribute("flag", flag.to_string())
+        .add_attribute("flagger", info.sender))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let new_version = parse_version(CONTRACT_VERSION)?;
+
+    // Deployments made before version tracking have no cw2 record.
+    // Those are treated as the oldest version so that every migration is applied.
+    let stored_version = match CONTRACT.may_load(deps.storage)? {
+        Some(stored) => {
+            if stored.contract != CONTRACT_NAME {
+                return Err(ContractError::InvalidContractName {
+                    expected: CONTRACT_NAME.to_string(),
+                    found: stored.contract,
+                });
+            }
+            parse_version(&stored.version)?
+        }
+        None => parse_version("0.0.0")?,
+    };
+
+    // Refuse to downgrade
+    if stored_version > new_version {
+        return Err(ContractError::CannotMigrateToOlderVersion {
+            stored: stored_version.to_string(),
+            new: new_version.to_string(),
+        });
+    }
+
+    let applied_migrations =
+        run_migrations(deps.storage, &stored_version, &new_version, MIGRATIONS)?;
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let response = Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", stored_version.to_string())
+        .add_attribute("to_version", new_version.to_string())
+        .add_attribute("applied_migrations", applied_migrations.join(", "));
+
+    Ok(response)
 }
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::Channel { channel_id } => to_json_binary(&query_channel(deps, channel_id)?),
+        QueryMsg::Channel { channel_id } => to_json_binary(&query_channel(deps, env, channel_id)?),
         QueryMsg::IsPaused {} => to_json_binary(&query_is_paused(deps)?),
         QueryMsg::Pausers {} => to_json_binary(&query_pausers(deps)?),
         QueryMsg::ChannelDetails { channel_id } => {
@@ -1202,24 +2647,94 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             playlist_id,
         } => to_json_binary(&query_playlist(deps, channel_id, playlist_id)?),
         QueryMsg::Channels { start_after, limit } => {
-            to_json_binary(&query_channels(deps, start_after, limit)?)
+            to_json_binary(&query_channels(deps, env, start_after, limit)?)
         }
         QueryMsg::ChannelId { user_name } => to_json_binary(&query_channel_id(deps, user_name)?),
         QueryMsg::Playlists {
             channel_id,
             start_after,
             limit,
-        } => to_json_binary(&query_playlists(deps, channel_id, start_after, limit)?),
-        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
-        QueryMsg::Assets {
+        } => to_json_binary(&query_playlists(deps, channel_id, start_after, limit)?),
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::AcceptedTipDenoms {} => to_json_binary(&query_accepted_tip_denoms(deps)?),
+        QueryMsg::PendingProtocolAdmin {} => {
+            to_json_binary(&PENDING_PROTOCOL_ADMIN.may_load(deps.storage)?)
+        }
+        QueryMsg::Assets {
+            channel_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_assets(deps, env, channel_id, start_after, limit)?),
+        QueryMsg::Asset {
+            channel_id,
+            publish_id,
+        } => to_json_binary(&query_asset(deps, env, channel_id, publish_id)?),
+        QueryMsg::ScheduledAssets {
+            channel_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_scheduled_assets(
+            deps,
+            env,
+            channel_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::CanAccessAsset {
+            address,
+            channel_id,
+            publish_id,
+        } => to_json_binary(&query_can_access_asset(
+            deps, env, address, channel_id, publish_id,
+        )?),
+        QueryMsg::FlagThresholds {} => to_json_binary(&query_flag_thresholds(deps)?),
+        QueryMsg::ReviewQueue { start_after, limit } => {
+            to_json_binary(&query_review_queue(deps, env, start_after, limit)?)
+        }
+        QueryMsg::AssetFlaggers {
+            channel_id,
+            publish_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_asset_flaggers(
+            deps,
+            channel_id,
+            publish_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::AssetFlagReports {
+            channel_id,
+            publish_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_asset_flag_reports(
+            deps,
+            channel_id,
+            publish_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::HasPurchased {
+            channel_id,
+            publish_id,
+            address,
+        } => to_json_binary(&query_has_purchased(deps, channel_id, publish_id, address)?),
+        QueryMsg::AssetBuyers {
             channel_id,
+            publish_id,
             start_after,
             limit,
-        } => to_json_binary(&query_assets(deps, channel_id, start_after, limit)?),
-        QueryMsg::Asset {
+        } => to_json_binary(&query_asset_buyers(
+            deps,
             channel_id,
             publish_id,
-        } => to_json_binary(&query_asset(deps, channel_id, publish_id)?),
+            start_after,
+            limit,
+        )?),
+        QueryMsg::Moderators { start_after, limit } => {
+            to_json_binary(&query_moderators(deps, start_after, limit)?)
+        }
         QueryMsg::ReservedUsernames { start_after, limit } => {
             to_json_binary(&query_reserved_usernames(deps, start_after, limit)?)
         }
@@ -1241,6 +2756,28 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         )?),
+        QueryMsg::ChannelsByOwner {
+            address,
+            start_after,
+            limit,
+        } => to_json_binary(&query_channels_by_owner(
+            deps,
+            env,
+            address,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::CollaboratorInvites {
+            address,
+            start_after,
+            limit,
+        } => to_json_binary(&query_collaborator_invites(
+            deps,
+            env,
+            address,
+            start_after,
+            limit,
+        )?),
         QueryMsg::FollowersCount { channel_id } => {
             to_json_binary(&query_followers_count(deps, channel_id)?)
         }
@@ -1249,9 +2786,77 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         } => to_json_binary(&query_followers(deps, channel_id, start_after, limit)?),
+        QueryMsg::SubscriptionPlan { channel_id } => {
+            to_json_binary(&query_subscription_plan(deps, channel_id)?)
+        }
+        QueryMsg::IsSubscribed {
+            channel_id,
+            address,
+        } => to_json_binary(&query_is_subscribed(deps, env, channel_id, address)?),
+        QueryMsg::Subscribers {
+            channel_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_subscribers(
+            deps,
+            env,
+            channel_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::Following {
+            address,
+            start_after,
+            limit,
+        } => to_json_binary(&query_following(deps, address, start_after, limit)?),
+        QueryMsg::IsFollowing {
+            channel_id,
+            address,
+        } => to_json_binary(&query_is_following(deps, channel_id, address)?),
+        QueryMsg::ChannelCleanupProgress { channel_id } => {
+            to_json_binary(&ChannelsManager::new().get_channel_cleanup(deps.storage, channel_id)?)
+        }
+        QueryMsg::PendingEarnings { address } => {
+            to_json_binary(&query_pending_earnings(deps, address)?)
+        }
+        QueryMsg::ChannelTipStats { channel_id } => {
+            to_json_binary(&query_channel_tip_stats(deps, channel_id)?)
+        }
+        QueryMsg::AssetTipStats {
+            channel_id,
+            publish_id,
+        } => to_json_binary(&query_asset_tip_stats(deps, channel_id, publish_id)?),
+        QueryMsg::TipperTotals { channel_id, tipper } => {
+            to_json_binary(&query_tipper_totals(deps, channel_id, tipper)?)
+        }
+        QueryMsg::TopTippers {
+            channel_id,
+            denom,
+            start_after,
+            limit,
+        } => to_json_binary(&query_top_tippers(
+            deps,
+            channel_id,
+            denom,
+            start_after,
+            limit,
+        )?),
     }
 }
 
+fn query_accepted_tip_denoms(deps: Deps) -> Result<Vec<TipDenom>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let tip_denoms = config
+        .accepted_tip_denoms
+        .into_iter()
+        .map(|denom| {
+            let min_amount = TIP_DENOM_MIN_AMOUNTS.may_load(deps.storage, denom.clone())?;
+            Ok(TipDenom { denom, min_amount })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(tip_denoms)
+}
+
 fn query_channel_details(deps: Deps, channel_id: String) -> Result<ChannelDetails, ContractError> {
     let channels_manager = ChannelsManager::new();
     let channel_details = channels_manager.get_channel_details(deps.storage, channel_id.clone())?;
@@ -1268,7 +2873,11 @@ fn query_channel_metadata(
     Ok(channel_metadata)
 }
 
-fn query_channel(deps: Deps, channel_id: String) -> Result<ChannelResponse, ContractError> {
+fn query_channel(
+    deps: Deps,
+    env: Env,
+    channel_id: String,
+) -> Result<ChannelResponse, ContractError> {
     let channels_manager = ChannelsManager::new();
     let channel_details = channels_manager.get_channel_details(deps.storage, channel_id.clone())?;
     let channel_metadata =
@@ -1276,6 +2885,9 @@ fn query_channel(deps: Deps, channel_id: String) -> Result<ChannelResponse, Cont
     let channel_collaborators =
         channels_manager.get_channel_collaborators(deps.storage, channel_id.clone(), None, None)?;
     let follower_count = channels_manager.get_followers_count(deps.storage, channel_id.clone())?;
+    let payout_mode = channels_manager.get_payout_mode(deps.storage, channel_id.clone())?;
+    let suspension =
+        channels_manager.get_suspension(deps.storage, channel_id.clone(), env.block.time)?;
 
     Ok(ChannelResponse {
         channel_id: channel_details.channel_id,
@@ -1288,11 +2900,14 @@ fn query_channel(deps: Deps, channel_id: String) -> Result<ChannelResponse, Cont
         banner_picture: channel_metadata.banner_picture,
         collaborators: channel_collaborators,
         follower_count,
+        payout_mode,
+        suspension,
     })
 }
 
 fn query_channels(
     deps: Deps,
+    env: Env,
     start_after: Option<String>,
     limit: Option<u32>,
 ) -> Result<Vec<ChannelResponse>, ContractError> {
@@ -1300,7 +2915,7 @@ fn query_channels(
     let channels_list = channels_manager.get_channels_list(deps.storage, start_after, limit)?;
     let channels = channels_list
         .iter()
-        .map(|channel| query_channel(deps, channel.channel_id.clone()))
+        .map(|channel| query_channel(deps, env.clone(), channel.channel_id.clone()))
         .collect::<Result<Vec<_>, _>>()?;
     Ok(channels)
 }
@@ -1353,24 +2968,51 @@ fn query_pausers(deps: Deps) -> Result<Vec<String>, ContractError> {
 
 fn query_assets(
     deps: Deps,
+    env: Env,
     channel_id: String,
     start_after: Option<String>,
     limit: Option<u32>,
 ) -> Result<Vec<AssetResponse>, ContractError> {
     let assets_manager = AssetsManager::new();
-    let assets_list =
-        assets_manager.get_all_assets(deps.storage, channel_id.clone(), start_after, limit)?;
+    let assets_list = assets_manager.get_all_assets(
+        deps.storage,
+        channel_id.clone(),
+        start_after,
+        limit,
+        env.block.time,
+    )?;
+    Ok(assets_list)
+}
+
+fn query_scheduled_assets(
+    deps: Deps,
+    env: Env,
+    channel_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<AssetResponse>, ContractError> {
+    let assets_manager = AssetsManager::new();
+    let assets_list = assets_manager.get_scheduled_assets(
+        deps.storage,
+        channel_id,
+        start_after,
+        limit,
+        env.block.time,
+    )?;
     Ok(assets_list)
 }
 
 fn query_asset(
     deps: Deps,
+    env: Env,
     channel_id: String,
     publish_id: String,
 ) -> Result<AssetResponse, ContractError> {
     let assets_manager = AssetsManager::new();
     let asset_key = (channel_id.clone(), publish_id.clone());
-    let asset = assets_manager.get_asset(deps.storage, asset_key.clone())?;
+    let mut asset = assets_manager.get_asset(deps.storage, asset_key.clone())?;
+    // Scheduled assets are not visible until their publish time
+    asset.is_visible = asset.is_live(env.block.time);
     let flags = assets_manager.get_all_flags_for_asset(deps.storage, channel_id, publish_id)?;
     let metadata = assets_manager.get_asset_metadata(deps.storage, asset_key.clone())?;
     Ok(AssetResponse {
@@ -1379,6 +3021,47 @@ fn query_asset(
         metadata,
     })
 }
+fn query_flag_thresholds(deps: Deps) -> Result<Vec<FlagThreshold>, ContractError> {
+    let flag_thresholds = FLAG_THRESHOLDS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, flag_threshold)| flag_threshold))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(flag_thresholds)
+}
+
+fn query_review_queue(
+    deps: Deps,
+    env: Env,
+    start_after: Option<AssetKey>,
+    limit: Option<u32>,
+) -> Result<Vec<AssetResponse>, ContractError> {
+    let assets_manager = AssetsManager::new();
+    let assets_list =
+        assets_manager.get_review_queue(deps.storage, start_after, limit, env.block.time)?;
+    Ok(assets_list)
+}
+
+fn query_moderators(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<Addr>, ContractError> {
+    let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    let moderators = MODERATORS
+        .keys(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(moderators)
+}
+
 fn query_reserved_usernames(
     deps: Deps,
     start_after: Option<String>,
@@ -1401,6 +3084,7 @@ fn query_channel_collaborator(
         address: collaborator_address.to_string(),
         role: collaborator.role.to_string(),
         share: collaborator.share,
+        expires_at: collaborator.expires_at,
     })
 }
 
@@ -1416,6 +3100,114 @@ fn query_channel_collaborators(
     Ok(collaborators)
 }
 
+fn query_collaborator_invites(
+    deps: Deps,
+    env: Env,
+    address: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<CollaboratorInviteInfo>, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let channels_manager = ChannelsManager::new();
+    let invites = channels_manager
+        .get_collaborator_invites_by_address(deps.storage, address, start_after, limit, &env.block)?
+        .into_iter()
+        .map(|(channel_id, invite)| CollaboratorInviteInfo {
+            channel_id,
+            role: invite.collaborator.role.to_string(),
+            share: invite.collaborator.share,
+            expiry: invite.expiry,
+        })
+        .collect();
+    Ok(invites)
+}
+
+fn query_channels_by_owner(
+    deps: Deps,
+    env: Env,
+    address: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<AddressChannelInfo>, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT);
+    let config = CONFIG.load(deps.storage)?;
+    let channels_manager = ChannelsManager::new();
+
+    let owned = channels_manager.get_channels_by_owner(
+        deps.storage,
+        address.clone(),
+        start_after.clone(),
+        Some(limit),
+    )?;
+    let collaborations = channels_manager.get_channels_by_collaborator(
+        deps.storage,
+        address.clone(),
+        start_after,
+        Some(limit),
+    )?;
+
+    // An index that filled the page may have more entries after its last one,
+    // so nothing past the smallest such entry is returned on this page
+    let page_end = [
+        owned.last().filter(|_| owned.len() == limit as usize),
+        collaborations
+            .last()
+            .map(|(channel_id, _)| channel_id)
+            .filter(|_| collaborations.len() == limit as usize),
+    ]
+    .into_iter()
+    .flatten()
+    .min()
+    .cloned();
+
+    let mut channels: Vec<(String, ChannelRelation)> = vec![];
+    for channel_id in owned {
+        // The recorded owner may have transferred the channel ONFT
+        let channel_details = channels_manager.get_channel_details(deps.storage, channel_id)?;
+        if get_onft_with_owner(
+            deps,
+            config.channels_collection_id.clone(),
+            channel_details.onft_id,
+            address.to_string(),
+        )
+        .is_ok()
+        {
+            channels.push((channel_details.channel_id, ChannelRelation::Owner));
+        }
+    }
+    for (channel_id, collaborator) in collaborations {
+        if collaborator.is_expired(&env.block)
+            || channels.iter().any(|(owned_id, _)| owned_id == &channel_id)
+        {
+            continue;
+        }
+        channels.push((
+            channel_id,
+            ChannelRelation::Collaborator {
+                role: collaborator.role.to_string(),
+                share: collaborator.share,
+                expires_at: collaborator.expires_at,
+            },
+        ));
+    }
+    channels.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut response = vec![];
+    for (channel_id, relation) in channels {
+        if page_end.as_ref().is_some_and(|end| &channel_id > end) {
+            break;
+        }
+        let channel_details = channels_manager.get_channel_details(deps.storage, channel_id)?;
+        response.push(AddressChannelInfo {
+            channel_id: channel_details.channel_id,
+            user_name: channel_details.user_name,
+            relation,
+        });
+    }
+    Ok(response)
+}
+
 fn query_followers_count(deps: Deps, channel_id: String) -> Result<u64, ContractError> {
     let channels = ChannelsManager::new();
     let count = channels.get_followers_count(deps.storage, channel_id)?;
@@ -1433,5 +3225,272 @@ fn query_followers(
     Ok(followers)
 }
 
+fn query_subscription_plan(
+    deps: Deps,
+    channel_id: String,
+) -> Result<Option<SubscriptionPlan>, ContractError> {
+    let channels = ChannelsManager::new();
+    let plan = channels.get_subscription_plan(deps.storage, channel_id)?;
+    Ok(plan)
+}
+
+fn query_is_subscribed(
+    deps: Deps,
+    env: Env,
+    channel_id: String,
+    address: String,
+) -> Result<bool, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let channels = ChannelsManager::new();
+    let subscription =
+        channels.get_active_subscription(deps.storage, channel_id, address, &env.block)?;
+    Ok(subscription.is_some())
+}
+
+fn query_can_access_asset(
+    deps: Deps,
+    env: Env,
+    address: String,
+    channel_id: String,
+    publish_id: String,
+) -> Result<bool, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+    let assets_manager = AssetsManager::new();
+    let asset = assets_manager.get_asset(deps.storage, (channel_id.clone(), publish_id))?;
+
+    // Channel owner and collaborators can access every asset of the channel
+    if validate_permissions(
+        deps,
+        &env,
+        channel_id.clone(),
+        address.clone(),
+        config.channels_collection_id,
+        Role::Publisher,
+    )
+    .is_ok()
+    {
+        return Ok(true);
+    }
+
+    if !asset.is_live(env.block.time) {
+        return Ok(false);
+    }
+
+    // Buyers of a pay-per-view asset can access it regardless of its access policy
+    if assets_manager
+        .get_purchase(
+            deps.storage,
+            (channel_id.clone(), asset.publish_id),
+            address.clone(),
+        )?
+        .is_some()
+    {
+        return Ok(true);
+    }
+
+    // Priced assets are only accessible with a purchase receipt
+    if asset.price.is_some() {
+        return Ok(false);
+    }
+
+    let channels = ChannelsManager::new();
+    let is_subscribed = |channel_id: String, address: Addr| -> Result<bool, ContractError> {
+        let subscription =
+            channels.get_active_subscription(deps.storage, channel_id, address, &env.block)?;
+        Ok(subscription.is_some())
+    };
+
+    let can_access = match asset.access_policy {
+        AccessPolicy::Public => true,
+        AccessPolicy::Followers => {
+            channels.is_follower(deps.storage, channel_id.clone(), address.clone())?
+                || is_subscribed(channel_id, address)?
+        }
+        AccessPolicy::Subscribers => is_subscribed(channel_id, address)?,
+        AccessPolicy::CollectionHolders { collection_id } => {
+            holds_collection_onft(deps, collection_id, address.to_string())?
+        }
+    };
+    Ok(can_access)
+}
+
+fn query_asset_flaggers(
+    deps: Deps,
+    channel_id: String,
+    publish_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<FlaggerInfo>, ContractError> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    let assets_manager = AssetsManager::new();
+    let flaggers =
+        assets_manager.get_flaggers(deps.storage, channel_id, publish_id, start_after, limit)?;
+    Ok(flaggers)
+}
+
+fn query_asset_flag_reports(
+    deps: Deps,
+    channel_id: String,
+    publish_id: String,
+    start_after: Option<(String, Flag)>,
+    limit: Option<u32>,
+) -> Result<Vec<FlagReport>, ContractError> {
+    let start_after = start_after
+        .map(|(reporter, flag)| {
+            deps.api
+                .addr_validate(&reporter)
+                .map(|reporter| (reporter, flag.to_key()))
+        })
+        .transpose()?;
+    let assets_manager = AssetsManager::new();
+    let reports = assets_manager.get_flag_reports(
+        deps.storage,
+        (channel_id, publish_id),
+        start_after,
+        limit,
+    )?;
+    Ok(reports)
+}
+
+fn query_has_purchased(
+    deps: Deps,
+    channel_id: String,
+    publish_id: String,
+    address: String,
+) -> Result<bool, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let assets_manager = AssetsManager::new();
+    let receipt = assets_manager.get_purchase(deps.storage, (channel_id, publish_id), address)?;
+    Ok(receipt.is_some())
+}
+
+fn query_asset_buyers(
+    deps: Deps,
+    channel_id: String,
+    publish_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<PurchaseReceipt>, ContractError> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    let assets_manager = AssetsManager::new();
+    let receipts =
+        assets_manager.get_buyers(deps.storage, (channel_id, publish_id), start_after, limit)?;
+    Ok(receipts)
+}
+
+fn query_subscribers(
+    deps: Deps,
+    env: Env,
+    channel_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<SubscriberInfo>, ContractError> {
+    let channels = ChannelsManager::new();
+    let subscribers = channels
+        .get_subscribers(deps.storage, channel_id, start_after, limit, &env.block)?
+        .into_iter()
+        .map(|(address, expires_at)| SubscriberInfo {
+            address: address.to_string(),
+            expires_at,
+        })
+        .collect();
+    Ok(subscribers)
+}
+
+fn query_following(
+    deps: Deps,
+    address: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<String>, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let channels = ChannelsManager::new();
+    // Deleted channels are left in the index until their cleanup reaches the followers
+    let following = channels
+        .get_following(deps.storage, address, start_after, limit)?
+        .into_iter()
+        .filter(|channel_id| {
+            channels
+                .channel_details
+                .has(deps.storage, channel_id.clone())
+        })
+        .collect();
+    Ok(following)
+}
+
+fn query_is_following(
+    deps: Deps,
+    channel_id: String,
+    address: String,
+) -> Result<bool, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let channels = ChannelsManager::new();
+    let is_following = channels.is_follower(deps.storage, channel_id, address)?;
+    Ok(is_following)
+}
+
+fn query_pending_earnings(deps: Deps, address: String) -> Result<Vec<Coin>, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let tips_manager = TipsManager::new();
+    let earnings = tips_manager.get_pending_earnings(deps.storage, address)?;
+    Ok(earnings)
+}
+
+fn query_channel_tip_stats(deps: Deps, channel_id: String) -> Result<TipStats, ContractError> {
+    let channels_manager = ChannelsManager::new();
+    channels_manager.get_channel_details(deps.storage, channel_id.clone())?;
+    let tips_manager = TipsManager::new();
+    let stats = tips_manager.get_channel_tip_stats(deps.storage, channel_id)?;
+    Ok(stats)
+}
+
+fn query_asset_tip_stats(
+    deps: Deps,
+    channel_id: String,
+    publish_id: String,
+) -> Result<TipStats, ContractError> {
+    let assets_manager = AssetsManager::new();
+    assets_manager.get_asset(deps.storage, (channel_id.clone(), publish_id.clone()))?;
+    let tips_manager = TipsManager::new();
+    let stats = tips_manager.get_asset_tip_stats(deps.storage, channel_id, publish_id)?;
+    Ok(stats)
+}
+
+fn query_tipper_totals(
+    deps: Deps,
+    channel_id: String,
+    tipper: String,
+) -> Result<Vec<Coin>, ContractError> {
+    let channels_manager = ChannelsManager::new();
+    channels_manager.get_channel_details(deps.storage, channel_id.clone())?;
+    let tipper = deps.api.addr_validate(&tipper)?;
+    let tips_manager = TipsManager::new();
+    let totals = tips_manager.get_tipper_totals(deps.storage, channel_id, tipper)?;
+    Ok(totals)
+}
+
+fn query_top_tippers(
+    deps: Deps,
+    channel_id: String,
+    denom: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<TipperInfo>, ContractError> {
+    let channels_manager = ChannelsManager::new();
+    channels_manager.get_channel_details(deps.storage, channel_id.clone())?;
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let tips_manager = TipsManager::new();
+    let tippers =
+        tips_manager.get_top_tippers(deps.storage, channel_id, denom, start_after, limit)?;
+    Ok(tippers)
+}
+
 #[cfg(test)]
 mod tests {}
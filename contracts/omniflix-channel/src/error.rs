@@ -1,7 +1,7 @@
 use asset_manager::error::AssetError;
 use asset_manager::error::PlaylistError;
 use channel_manager::error::ChannelError;
-use cosmwasm_std::{Coin, OverflowError, StdError};
+use cosmwasm_std::{Coin, Decimal, OverflowError, StdError};
 use cw_utils::PaymentError;
 use pauser::PauseError;
 use thiserror::Error;
@@ -37,6 +37,15 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("No pending cleanup for channel")]
+    NoPendingChannelCleanup {},
+
+    #[error("No pending earnings")]
+    NoPendingEarnings {},
+
+    #[error("Tip fee rate can not exceed {max}")]
+    TipFeeRateTooHigh { max: Decimal },
+
     #[error("Invalid minter code id")]
     InvalidMinterCodeId {},
 
@@ -111,6 +120,54 @@ pub enum ContractError {
 
     #[error("Invalid tip amount")]
     InvalidTipAmount {},
+
+    #[error("Tip amount is below the minimum of {min_amount}")]
+    TipAmountBelowMinimum { min_amount: Coin },
+
+    #[error("Tip denom not found: {denom}")]
+    TipDenomNotFound { denom: String },
+
+    #[error("Invalid subscription plan")]
+    InvalidSubscriptionPlan {},
+
+    #[error("Invalid number of subscription periods")]
+    InvalidSubscriptionPeriods {},
+
+    #[error("Invalid access policy")]
+    InvalidAccessPolicy {},
+
+    #[error("Invalid asset price")]
+    InvalidAssetPrice {},
+
+    #[error("Invalid publish time")]
+    InvalidPublishAt {},
+
+    #[error("Invalid channel suspension")]
+    InvalidChannelSuspension {},
+
+    #[error("Invalid interactive video ID")]
+    InvalidInteractiveVideoId {},
+
+    #[error("Moderator not found: {address}")]
+    ModeratorNotFound { address: String },
+
+    #[error("No pending protocol admin")]
+    NoPendingProtocolAdmin {},
+
+    #[error("Pending protocol admin proposal expired")]
+    PendingProtocolAdminExpired {},
+
+    #[error("Expiration is already reached")]
+    InvalidExpiration {},
+
+    #[error("Cannot migrate from contract {found} to {expected}")]
+    InvalidContractName { expected: String, found: String },
+
+    #[error("Cannot migrate from version {stored} to older version {new}")]
+    CannotMigrateToOlderVersion { stored: String, new: String },
+
+    #[error("Invalid version: {version}")]
+    InvalidVersion { version: String },
 }
 
 impl From<ContractError> for StdError {
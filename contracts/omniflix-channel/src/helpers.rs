@@ -4,7 +4,7 @@ use crate::ContractError;
 use asset_manager::assets::AssetsManager;
 use cosmwasm_std::{Addr, Api, Coin, Decimal, Deps, Uint128};
 use cosmwasm_std::{CosmosMsg, Storage};
-use omniflix_channel_types::asset::{AssetKey, AssetMetadata, AssetSource};
+use omniflix_channel_types::asset::{AccessPolicy, AssetKey, AssetMetadata, AssetSource, Flag};
 use omniflix_channel_types::channel::{ChannelDetails, ChannelMetadata};
 use omniflix_channel_types::msg::{
     ChannelTokenDetails, ChannelsCollectionDetails, ReservedUsername,
@@ -103,6 +103,45 @@ pub fn validate_asset_source(
     }
 }
 
+pub fn validate_access_policy(access_policy: &AccessPolicy) -> Result<(), ContractError> {
+    if let AccessPolicy::CollectionHolders { collection_id } = access_policy {
+        if collection_id.trim().is_empty() {
+            return Err(ContractError::InvalidAccessPolicy {});
+        }
+    }
+    Ok(())
+}
+
+/// The longest interactive video ID stored with a flag report
+const MAX_INTERACTIVE_VIDEO_ID_LENGTH: usize = 64;
+
+pub fn validate_flag_report(
+    flag: &Flag,
+    interactive_video_id: Option<&String>,
+) -> Result<(), ContractError> {
+    if let Flag::Other(reason) = flag {
+        validate_string(reason, StringValidationType::Description)?;
+    }
+    if let Some(interactive_video_id) = interactive_video_id {
+        if interactive_video_id.trim().is_empty()
+            || interactive_video_id.len() > MAX_INTERACTIVE_VIDEO_ID_LENGTH
+        {
+            return Err(ContractError::InvalidInteractiveVideoId {});
+        }
+    }
+    Ok(())
+}
+
+pub fn validate_asset_price(price: &Coin, accepted_denoms: &[String]) -> Result<(), ContractError> {
+    if price.amount.is_zero() {
+        return Err(ContractError::InvalidAssetPrice {});
+    }
+    if !accepted_denoms.contains(&price.denom) {
+        return Err(ContractError::InvalidTipDenom {});
+    }
+    Ok(())
+}
+
 pub fn validate_asset_metadata(metadata: AssetMetadata) -> Result<(), ContractError> {
     validate_string(&metadata.name, StringValidationType::AssetName)?;
     validate_string(&metadata.description, StringValidationType::Description)?;
@@ -2,8 +2,10 @@ pub mod access_control;
 pub mod contract;
 mod error;
 pub mod helpers;
+pub mod migrations;
 pub mod random;
 pub mod state;
 pub mod string_validation;
 pub use crate::error::ContractError;
 pub mod bank_helpers;
+pub mod cleanup;
@@ -0,0 +1,403 @@
+use crate::cleanup::DEFAULT_CLEANUP_LIMIT;
+use asset_manager::assets::AssetsManager;
+use channel_manager::channel::ChannelsManager;
+use channel_manager::username::normalize_username;
+use cosmwasm_std::{Order, StdResult, Storage};
+use semver::Version;
+
+use crate::ContractError;
+
+/// A single versioned state migration.
+///
+/// `version` is the contract version that introduced the storage layout this step upgrades to.
+/// `migrate` receives the raw contract storage so that a step can rewrite any of the
+/// `ChannelsManager`, `AssetsManager` or `PlaylistsManager` maps as well as contract level items.
+pub struct Migration {
+    pub version: &'static str,
+    pub migrate: fn(&mut dyn Storage) -> Result<(), ContractError>,
+}
+
+/// Ordered list of state migrations. Append new steps at the end, sorted by version.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: "0.2.0",
+        migrate: normalize_usernames,
+    },
+    Migration {
+        version: "0.2.0",
+        migrate: index_collaborator_channels,
+    },
+    Migration {
+        version: "0.2.0",
+        migrate: index_following,
+    },
+    Migration {
+        version: "0.2.0",
+        migrate: reset_flag_counts,
+    },
+];
+
+/// Rekeys the username mappings, reservations and holds by the normalized username.
+/// When several channels normalize to the same username, the channel with the lowest
+/// channel ID keeps the mapping. The others keep their display username but can not
+/// be looked up by it until they change it.
+///
+/// The maps are rewritten in a single unbounded pass, as username lookups and uniqueness
+/// checks would be inconsistent while only part of the usernames are rekeyed. The pass reads
+/// and writes one entry per channel, reserved username and held username. A migration that
+/// runs out of gas is reverted as a whole and keeps the previous code, so it is retried with
+/// a higher gas limit.
+fn normalize_usernames(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let channels_manager = ChannelsManager::new();
+
+    let usernames = channels_manager
+        .username_to_channel_id
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for user_name in usernames {
+        channels_manager
+            .username_to_channel_id
+            .remove(storage, user_name);
+    }
+    let channels = channels_manager
+        .channel_id_to_username
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (channel_id, user_name) in channels {
+        let normalized = normalize_username(&user_name);
+        if !channels_manager
+            .username_to_channel_id
+            .has(storage, normalized.clone())
+        {
+            channels_manager
+                .username_to_channel_id
+                .save(storage, normalized, &channel_id)?;
+        }
+    }
+
+    let reserved = channels_manager
+        .reserved_usernames
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (user_name, address) in reserved {
+        channels_manager
+            .reserved_usernames
+            .remove(storage, user_name.clone());
+        let normalized = normalize_username(&user_name);
+        if !channels_manager
+            .reserved_usernames
+            .has(storage, normalized.clone())
+        {
+            channels_manager
+                .reserved_usernames
+                .save(storage, normalized, &address)?;
+        }
+    }
+
+    let holds = channels_manager
+        .username_holds
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (user_name, hold) in holds {
+        channels_manager
+            .username_holds
+            .remove(storage, user_name.clone());
+        let normalized = normalize_username(&user_name);
+        if !channels_manager
+            .username_holds
+            .has(storage, normalized.clone())
+        {
+            channels_manager
+                .username_holds
+                .save(storage, normalized, &hold)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses a semver version string
+pub fn parse_version(version: &str) -> Result<Version, ContractError> {
+    version
+        .parse::<Version>()
+        .map_err(|_| ContractError::InvalidVersion {
+            version: version.to_string(),
+        })
+}
+
+/// Runs every migration whose version is newer than `from` and not newer than `to`
+///
+/// # Returns
+/// * The versions of the migrations that were applied, in order
+pub fn run_migrations(
+    storage: &mut dyn Storage,
+    from: &Version,
+    to: &Version,
+    migrations: &[Migration],
+) -> Result<Vec<String>, ContractError> {
+    let mut applied = vec![];
+    for migration in migrations {
+        let version = parse_version(migration.version)?;
+        if &version > from && &version <= to {
+            (migration.migrate)(storage)?;
+            applied.push(migration.version.to_string());
+        }
+    }
+    Ok(applied)
+}
+
+/// Indexes the existing collaborators by address.
+/// Channel owners can not be read from storage, they are recorded with `ChannelSyncOwner`.
+///
+/// The index is built in a single unbounded pass with one write per collaborator, so that
+/// `ChannelsByOwner` never serves a partially built index. A migration that runs out of gas
+/// is reverted as a whole and keeps the previous code, so it is retried with a higher gas limit.
+fn index_collaborator_channels(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let channels_manager = ChannelsManager::new();
+    let collaborators = channels_manager
+        .channel_collaborators
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (channel_id, address) in collaborators {
+        channels_manager
+            .channels_by_collaborator
+            .save(storage, (address, channel_id), &true)?;
+    }
+    Ok(())
+}
+
+/// Builds the reverse index of the existing followers
+///
+/// The index is built in a single unbounded pass with one write per follower, so that
+/// `Following` and `IsFollowing` never serve a partially built index. A migration that runs
+/// out of gas is reverted as a whole and keeps the previous code, so it is retried with a
+/// higher gas limit.
+fn index_following(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let channels_manager = ChannelsManager::new();
+    let followers = channels_manager
+        .followers
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (channel_id, follower) in followers {
+        channels_manager
+            .following
+            .save(storage, (follower, channel_id), &true)?;
+    }
+    Ok(())
+}
+
+/// Flag counts recorded before flags were deduplicated per account can not be attributed to
+/// flaggers, so counting starts over under a new storage key and the legacy counts are never read.
+/// This step removes the first batch of legacy counts, the protocol admin removes the rest with
+/// `AdminClearAllFlags`.
+fn reset_flag_counts(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    AssetsManager::new().remove_legacy_flags_with_limit(storage, DEFAULT_CLEANUP_LIMIT as usize)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::{Addr, Timestamp};
+    use omniflix_channel_types::asset::{AccessPolicy, Asset, AssetSource, Flag, FlagReport};
+
+    fn write_v1(storage: &mut dyn Storage) -> Result<(), ContractError> {
+        storage.set(b"v1", b"1");
+        Ok(())
+    }
+
+    fn write_v2(storage: &mut dyn Storage) -> Result<(), ContractError> {
+        storage.set(b"v2", b"1");
+        Ok(())
+    }
+
+    fn write_v3(storage: &mut dyn Storage) -> Result<(), ContractError> {
+        storage.set(b"v3", b"1");
+        Ok(())
+    }
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            version: "1.0.0",
+            migrate: write_v1,
+        },
+        Migration {
+            version: "1.1.0",
+            migrate: write_v2,
+        },
+        Migration {
+            version: "2.0.0",
+            migrate: write_v3,
+        },
+    ];
+
+    #[test]
+    fn test_run_migrations_in_range() {
+        let mut storage = MockStorage::new();
+        let from = parse_version("1.0.0").unwrap();
+        let to = parse_version("1.1.1").unwrap();
+
+        let applied = run_migrations(&mut storage, &from, &to, TEST_MIGRATIONS).unwrap();
+        assert_eq!(applied, vec!["1.1.0".to_string()]);
+        assert!(storage.get(b"v1").is_none());
+        assert!(storage.get(b"v2").is_some());
+        assert!(storage.get(b"v3").is_none());
+    }
+
+    #[test]
+    fn test_run_migrations_same_version() {
+        let mut storage = MockStorage::new();
+        let version = parse_version("2.0.0").unwrap();
+
+        let applied = run_migrations(&mut storage, &version, &version, TEST_MIGRATIONS).unwrap();
+        assert!(applied.is_empty());
+    }
+
+    #[test]
+    fn test_normalize_usernames() {
+        let mut storage = MockStorage::new();
+        let channels_manager = ChannelsManager::new();
+        // Mappings saved before normalization are keyed by the display username
+        for (channel_id, user_name) in [("1", "Alice"), ("2", "alice_"), ("3", "Bob")] {
+            channels_manager
+                .username_to_channel_id
+                .save(&mut storage, user_name.to_string(), &channel_id.to_string())
+                .unwrap();
+            channels_manager
+                .channel_id_to_username
+                .save(&mut storage, channel_id.to_string(), &user_name.to_string())
+                .unwrap();
+        }
+        channels_manager
+            .reserved_usernames
+            .save(&mut storage, "Admin".to_string(), &None)
+            .unwrap();
+
+        normalize_usernames(&mut storage).unwrap();
+
+        assert_eq!(
+            channels_manager
+                .get_channel_id(&storage, "ALICE".to_string())
+                .unwrap(),
+            "1"
+        );
+        assert_eq!(
+            channels_manager
+                .get_channel_id(&storage, "bob".to_string())
+                .unwrap(),
+            "3"
+        );
+        assert_eq!(
+            channels_manager
+                .username_to_channel_id
+                .keys(&storage, None, None, Order::Ascending)
+                .count(),
+            2
+        );
+        assert_eq!(
+            channels_manager
+                .get_reserved_status(&storage, "admin".to_string())
+                .unwrap(),
+            Some(None)
+        );
+    }
+
+    #[test]
+    fn test_reset_flag_counts_in_batches() {
+        let mut storage = MockStorage::new();
+        let assets_manager = AssetsManager::new();
+        let channel_id = "channel".to_string();
+        let legacy_count = DEFAULT_CLEANUP_LIMIT as usize + 10;
+        for i in 0..legacy_count {
+            let publish_id = format!("publish{}", i);
+            assets_manager
+                .assets
+                .save(
+                    &mut storage,
+                    (channel_id.clone(), publish_id.clone()),
+                    &Asset {
+                        channel_id: channel_id.clone(),
+                        publish_id: publish_id.clone(),
+                        asset_source: AssetSource::OffChain {},
+                        is_visible: true,
+                        access_policy: AccessPolicy::Public,
+                        price: None,
+                        publish_at: None,
+                        under_review: false,
+                    },
+                )
+                .unwrap();
+            assets_manager
+                .legacy_flags
+                .save(
+                    &mut storage,
+                    (
+                        Flag::Spam.to_key(),
+                        (channel_id.clone(), publish_id.clone()),
+                    ),
+                    &3,
+                )
+                .unwrap();
+        }
+
+        // A single batch of the legacy counts is removed by the migration
+        reset_flag_counts(&mut storage).unwrap();
+        assert_eq!(
+            assets_manager
+                .legacy_flags
+                .keys(&storage, None, None, Order::Ascending)
+                .count(),
+            10
+        );
+
+        // The legacy counts left are not read, counting starts over for every asset
+        let (_, (_, publish_id)) = assets_manager
+            .legacy_flags
+            .keys(&storage, None, None, Order::Ascending)
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            assets_manager
+                .get_flag_count(&storage, channel_id.clone(), publish_id.clone(), Flag::Spam)
+                .unwrap(),
+            0
+        );
+        assets_manager
+            .add_flag(
+                &mut storage,
+                channel_id.clone(),
+                publish_id.clone(),
+                FlagReport {
+                    reporter: Addr::unchecked("flagger"),
+                    flag: Flag::Spam,
+                    interactive_video_id: None,
+                    reported_at: Timestamp::from_seconds(1),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            assets_manager
+                .get_flag_count(&storage, channel_id, publish_id, Flag::Spam)
+                .unwrap(),
+            1
+        );
+
+        // The rest is removed in a later transaction
+        assert!(assets_manager
+            .remove_flags_with_limit(&mut storage, DEFAULT_CLEANUP_LIMIT as usize)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_invalid_version() {
+        let err = parse_version("not-a-version").unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidVersion {
+                version: "not-a-version".to_string()
+            }
+        );
+    }
+}
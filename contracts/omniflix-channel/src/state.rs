@@ -1,12 +1,20 @@
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
 use omniflix_channel_types::{
-    config::{AuthDetails, ChannelConractConfig},
-    msg::ChannelTokenDetails,
+    config::{AuthDetails, ChannelConractConfig, PendingProtocolAdmin},
+    msg::{ChannelTokenDetails, FlagThreshold},
 };
 
 pub type ChannelsCollectionId = String;
 
 // Define storage items
 pub const CONFIG: Item<ChannelConractConfig> = Item::new("chcfg");
+pub const PENDING_PROTOCOL_ADMIN: Item<PendingProtocolAdmin> = Item::new("pnd_admin");
 pub const AUTH_DETAILS: Item<AuthDetails> = Item::new("auth");
+// Minimum tip amounts keyed by denom. Denoms without a minimum have no entry.
+pub const TIP_DENOM_MIN_AMOUNTS: Map<String, Uint128> = Map::new("tip_min");
+// Flag thresholds keyed by flag key. Flags without a threshold have no entry.
+pub const FLAG_THRESHOLDS: Map<String, FlagThreshold> = Map::new("flag_thresholds");
+// Protocol moderators, managed by the protocol admin
+pub const MODERATORS: Map<Addr, bool> = Map::new("moderators");
 pub const CHANNEL_TOKEN_DETAILS: Item<ChannelTokenDetails> = Item::new("ch_tkn_details");
@@ -11,10 +11,11 @@ pub struct StringValidationConfig {
     allow_uppercase: bool,
     allow_spaces: bool,
     allow_special_chars: bool,
-    required_prefixes: Vec<String>, // Required prefixes for the string
-    required_suffixes: Vec<String>, // Required suffixes for the string
-    must_contain: Vec<String>,      // Substrings that must be present
-    allow_empty: bool,              // Whether empty strings are allowed
+    allowed_special_chars: Vec<char>, // Restricts special characters to this set if not empty
+    required_prefixes: Vec<String>,   // Required prefixes for the string
+    required_suffixes: Vec<String>,   // Required suffixes for the string
+    must_contain: Vec<String>,        // Substrings that must be present
+    allow_empty: bool,                // Whether empty strings are allowed
 }
 
 // Define string validation errors
@@ -52,6 +53,7 @@ impl Default for StringValidationConfig {
             allow_uppercase: false,
             allow_spaces: false,
             allow_special_chars: false,
+            allowed_special_chars: vec![],
             required_prefixes: vec![],
             required_suffixes: vec![],
             must_contain: vec![],
@@ -82,6 +84,7 @@ impl StringValidationType {
                 allow_uppercase: true,
                 allow_spaces: false,
                 allow_special_chars: true,
+                allowed_special_chars: vec!['_', '-', '.'],
                 ..Default::default()
             },
             StringValidationType::ChannelName => StringValidationConfig {
@@ -222,41 +225,38 @@ pub fn validate_string(
     for c in input.chars() {
         match c {
             c if c.is_ascii_lowercase() => continue,
-            c if c.is_ascii_uppercase() => {
-                if !config.allow_uppercase {
-                    return Err(ContractError::StringValidationError(
-                        StringValidationError::UppercaseNotAllowed {
-                            sent: input.to_string(),
-                        },
-                    ));
-                }
+            c if c.is_ascii_uppercase() && !config.allow_uppercase => {
+                return Err(ContractError::StringValidationError(
+                    StringValidationError::UppercaseNotAllowed {
+                        sent: input.to_string(),
+                    },
+                ));
             }
-            c if c.is_ascii_digit() => {
-                if !config.allow_numbers {
-                    return Err(ContractError::StringValidationError(
-                        StringValidationError::NumbersNotAllowed {
-                            sent: input.to_string(),
-                        },
-                    ));
-                }
+            c if c.is_ascii_digit() && !config.allow_numbers => {
+                return Err(ContractError::StringValidationError(
+                    StringValidationError::NumbersNotAllowed {
+                        sent: input.to_string(),
+                    },
+                ));
             }
-            ' ' => {
-                if !config.allow_spaces {
-                    return Err(ContractError::StringValidationError(
-                        StringValidationError::SpacesNotAllowed {
-                            sent: input.to_string(),
-                        },
-                    ));
-                }
+            ' ' if !config.allow_spaces => {
+                return Err(ContractError::StringValidationError(
+                    StringValidationError::SpacesNotAllowed {
+                        sent: input.to_string(),
+                    },
+                ));
             }
-            c if !c.is_ascii_alphanumeric() && c != ' ' => {
-                if !config.allow_special_chars {
-                    return Err(ContractError::StringValidationError(
-                        StringValidationError::SpecialCharsNotAllowed {
-                            sent: input.to_string(),
-                        },
-                    ));
-                }
+            c if !c.is_ascii_alphanumeric()
+                && c != ' '
+                && (!config.allow_special_chars
+                    || (!config.allowed_special_chars.is_empty()
+                        && !config.allowed_special_chars.contains(&c))) =>
+            {
+                return Err(ContractError::StringValidationError(
+                    StringValidationError::SpecialCharsNotAllowed {
+                        sent: input.to_string(),
+                    },
+                ));
             }
             _ => {}
         }
@@ -275,12 +275,16 @@ mod tests {
         assert!(validate_string("validname", StringValidationType::Username).is_ok());
         assert!(validate_string("Valid123", StringValidationType::Username).is_ok());
         assert!(validate_string("valid-name", StringValidationType::Username).is_ok());
+        assert!(validate_string("valid_name.1", StringValidationType::Username).is_ok());
 
         // Test invalid cases
         assert!(validate_string("ab", StringValidationType::Username).is_err()); // too short
         assert!(validate_string("a".repeat(33).as_str(), StringValidationType::Username).is_err()); // too long
         assert!(validate_string("invalid name", StringValidationType::Username).is_err());
         // spaces not allowed
+        assert!(validate_string("invalid@name", StringValidationType::Username).is_err());
+        assert!(validate_string("аlice", StringValidationType::Username).is_err());
+        // cyrillic a
     }
 
     #[test]
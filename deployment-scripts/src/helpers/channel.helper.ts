@@ -6,7 +6,7 @@ import _, { random } from 'lodash'
 import assert from 'assert'
 import { CONTRACT_MAP } from './context.ts'
 import { logger } from '../utils/logger.ts'
-import { InstantiateMsg, Coin, ReservedUsername, AssetSource, Flag } from '../types/OmniFlixChannel.types.ts'
+import { InstantiateMsg, Coin, ReservedUsername, AssetSource, Flag, ChannelCollaborator, Expiration } from '../types/OmniFlixChannel.types.ts'
 import { OmniFlixChannelClient } from '../types/OmniFlixChannel.client.ts'
 
 
@@ -92,43 +92,57 @@ export default class ChannelHelper {
         return instantiateResult;
     }
 
-    SetConfig = async (context: Context, account_name: string, admin?: string, channel_creation_fee?: string, fee_collector?: string) => {
+    // The protocol admin is changed with ProposeProtocolAdmin and AcceptProtocolAdmin
+    SetConfig = async (context: Context, account_name: string, channel_creation_fee?: string, fee_collector?: string) => {
         let { client, address: senderAddress } = context.getTestUser(account_name);
         let channel_client: OmniFlixChannelClient = new OmniFlixChannelClient(client, senderAddress, context.getContractAddress(CONTRACT_MAP.OMNIFLIX_CHANNEL));
 
-        let executeMsg: {
-            set_config: {
-                admin?: string;
-                channelCreationFee?: Coin[];
-                fee_collector?: string;
-            }
-        } = {
-            set_config: {}
-        };
-
-        if (admin) {
-            executeMsg.set_config.admin = admin
-        }
+        let config: {
+            channelCreationFee?: Coin[];
+            feeCollector?: string;
+        } = {};
 
         if (channel_creation_fee) {
-            executeMsg.set_config.channelCreationFee = [{
+            config.channelCreationFee = [{
                 amount: channel_creation_fee,
                 denom: chainConfig.denom,
             }]
         }
 
         if (fee_collector) {
-            let fee_collector_address = context.getTestUser(fee_collector).address
-            executeMsg.set_config.fee_collector = fee_collector_address
+            config.feeCollector = context.getTestUser(fee_collector).address
         }
 
-        let res = await channel_client.adminSetConfig(executeMsg.set_config)
+        let res = await channel_client.adminSetConfig(config)
         logger.log(1, `Channel config updated`)
         logger.log(1, `Tx_Hash: ${res.transactionHash}`)
         logger.log(1, `Gas used: ${res.gasUsed}`)
         return res;
     }
 
+    ProposeProtocolAdmin = async (context: Context, account_name: string, new_admin: string, expiry?: Expiration) => {
+        let { client, address: senderAddress } = context.getTestUser(account_name);
+        let channel_client: OmniFlixChannelClient = new OmniFlixChannelClient(client, senderAddress, context.getContractAddress(CONTRACT_MAP.OMNIFLIX_CHANNEL));
+        let res = await channel_client.adminProposeProtocolAdmin({
+            newAdmin: context.getTestUser(new_admin).address,
+            expiry: expiry,
+        });
+        logger.log(1, `Protocol admin proposed: ${new_admin}`)
+        logger.log(1, `Tx_Hash: ${res.transactionHash}`)
+        logger.log(1, `Gas used: ${res.gasUsed}`)
+        return res;
+    }
+
+    AcceptProtocolAdmin = async (context: Context, account_name: string) => {
+        let { client, address: senderAddress } = context.getTestUser(account_name);
+        let channel_client: OmniFlixChannelClient = new OmniFlixChannelClient(client, senderAddress, context.getContractAddress(CONTRACT_MAP.OMNIFLIX_CHANNEL));
+        let res = await channel_client.adminAcceptProtocolAdmin();
+        logger.log(1, `Protocol admin accepted by: ${account_name}`)
+        logger.log(1, `Tx_Hash: ${res.transactionHash}`)
+        logger.log(1, `Gas used: ${res.gasUsed}`)
+        return res;
+    }
+
     CreateChannel = async (context: Context, account_name: string, user_name: string, collaborators?: []) => {
         let { client, address: senderAddress } = context.getTestUser(account_name);
         let channel_client: OmniFlixChannelClient = new OmniFlixChannelClient(client, senderAddress, context.getContractAddress(CONTRACT_MAP.OMNIFLIX_CHANNEL));
@@ -276,6 +290,49 @@ export default class ChannelHelper {
         logger.log(1, `Tx_Hash: ${res.transactionHash}`)
         logger.log(1, `Gas used: ${res.gasUsed}`)
     }
+    TipChannel = async (context: Context, account_name: string, channel_id: string, amount: string, asset_id?: string) => {
+        let { client, address: senderAddress } = context.getTestUser(account_name);
+        let channel_client: OmniFlixChannelClient = new OmniFlixChannelClient(client, senderAddress, context.getContractAddress(CONTRACT_MAP.OMNIFLIX_CHANNEL));
+        let tip: Coin[] = [{
+            amount: amount,
+            denom: chainConfig.denom,
+        }];
+        let res = await channel_client.channelTip({
+            channelId: channel_id,
+            amount: tip,
+            assetId: asset_id,
+        }, "auto", "", tip);
+        logger.log(1, `Channel tipped with id: ${channel_id}`)
+        logger.log(1, `Tx_Hash: ${res.transactionHash}`)
+        logger.log(1, `Gas used: ${res.gasUsed}`)
+    }
+
+    // Collaborators join a channel by accepting an invite
+    InviteCollaborator = async (context: Context, account_name: string, channel_id: string, collaborator: string, collaborator_details: ChannelCollaborator, expiry?: Expiration) => {
+        let { client, address: senderAddress } = context.getTestUser(account_name);
+        let channel_client: OmniFlixChannelClient = new OmniFlixChannelClient(client, senderAddress, context.getContractAddress(CONTRACT_MAP.OMNIFLIX_CHANNEL));
+        let res = await channel_client.channelInviteCollaborator({
+            channelId: channel_id,
+            collaboratorAddress: context.getTestUser(collaborator).address,
+            collaboratorDetails: collaborator_details,
+            expiry: expiry,
+        });
+        logger.log(1, `Collaborator ${collaborator} invited to channel id: ${channel_id}`)
+        logger.log(1, `Tx_Hash: ${res.transactionHash}`)
+        logger.log(1, `Gas used: ${res.gasUsed}`)
+    }
+
+    AcceptCollaboratorInvite = async (context: Context, account_name: string, channel_id: string) => {
+        let { client, address: senderAddress } = context.getTestUser(account_name);
+        let channel_client: OmniFlixChannelClient = new OmniFlixChannelClient(client, senderAddress, context.getContractAddress(CONTRACT_MAP.OMNIFLIX_CHANNEL));
+        let res = await channel_client.channelAcceptCollaboratorInvite({
+            channelId: channel_id,
+        });
+        logger.log(1, `Collaborator invite accepted for channel id: ${channel_id}`)
+        logger.log(1, `Tx_Hash: ${res.transactionHash}`)
+        logger.log(1, `Gas used: ${res.gasUsed}`)
+    }
+
     // Reserved usernames type: [string, string][]
     AddReservedUsernames = async (context: Context, reserved_usernames: ReservedUsername[]) => {
         let { client, address: senderAddress } = context.getTestUser('admin');
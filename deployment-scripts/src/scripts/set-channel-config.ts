@@ -14,7 +14,7 @@ const setChannelConfig = async () => {
     await channel_helper.QueryChannelConfig(context, "creator");
 
     // Update channel config
-    await channel_helper.SetConfig(context, "admin", undefined, "400", "creator");
+    await channel_helper.SetConfig(context, "admin", "400", "creator");
 
     // Get channel contract config
     await channel_helper.QueryChannelConfig(context, "creator");
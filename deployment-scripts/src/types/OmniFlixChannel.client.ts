@@ -6,7 +6,7 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { StdFee } from "@cosmjs/amino";
-import { Uint128, Addr, InstantiateMsg, Coin, ChannelTokenDetails, ChannelsCollectionDetails, ReservedUsername, ExecuteMsg, AssetSource, Binary, Flag, Role, Decimal, AssetMetadata, ChannelCollaborator, QueryMsg, AssetResponse, Asset, FlagInfo, ArrayOfAssetResponse, ChannelResponse, CollaboratorInfo, ChannelDetails, String, ChannelMetadata, ArrayOfChannelResponse, ChannelConractConfig, AuthDetails, ArrayOfString, Uint64, ArrayOfCollaboratorInfo, Boolean, Playlist, ArrayOfPlaylist, ArrayOfReservedUsername } from "./OmniFlixChannel.types";
+import { Uint128, Addr, InstantiateMsg, Coin, ChannelTokenDetails, ChannelsCollectionDetails, ReservedUsername, ExecuteMsg, Decimal, Expiration, Timestamp, Uint64, Flag, AccessPolicy, AssetSource, Binary, PayoutMode, Role, FlagThreshold, TipDenom, AssetMetadata, ChannelCollaborator, SubscriptionPlan, QueryMsg, MigrateMsg, ArrayOfTipDenom, AssetResponse, Asset, FlagInfo, ArrayOfPurchaseReceipt, PurchaseReceipt, ArrayOfFlagReport, FlagReport, ArrayOfFlaggerInfo, FlaggerInfo, TipStats, ArrayOfAssetResponse, Boolean, ChannelResponse, CollaboratorInfo, ChannelSuspension, NullableChannelCleanupProgress, CleanupStage, ChannelCleanupProgress, ChannelDetails, String, ChannelMetadata, ArrayOfChannelResponse, ArrayOfAddressChannelInfo, ChannelRelation, AddressChannelInfo, ArrayOfCollaboratorInviteInfo, CollaboratorInviteInfo, ChannelConractConfig, AuthDetails, ArrayOfFlagThreshold, ArrayOfString, ArrayOfCollaboratorInfo, ArrayOfAddr, ArrayOfCoin, NullablePendingProtocolAdmin, PendingProtocolAdmin, Playlist, ArrayOfPlaylist, ArrayOfReservedUsername, ArrayOfSubscriberInfo, SubscriberInfo, NullableSubscriptionPlan, ArrayOfTipperInfo, TipperInfo } from "./OmniFlixChannel.types";
 export interface OmniFlixChannelReadOnlyInterface {
   contractAddress: string;
   isPaused: () => Promise<Boolean>;
@@ -55,6 +55,8 @@ export interface OmniFlixChannelReadOnlyInterface {
     startAfter?: string;
   }) => Promise<ArrayOfPlaylist>;
   config: () => Promise<ChannelConractConfig>;
+  acceptedTipDenoms: () => Promise<ArrayOfTipDenom>;
+  pendingProtocolAdmin: () => Promise<NullablePendingProtocolAdmin>;
   assets: ({
     channelId,
     limit,
@@ -71,6 +73,81 @@ export interface OmniFlixChannelReadOnlyInterface {
     channelId: string;
     publishId: string;
   }) => Promise<AssetResponse>;
+  canAccessAsset: ({
+    address,
+    channelId,
+    publishId
+  }: {
+    address: string;
+    channelId: string;
+    publishId: string;
+  }) => Promise<Boolean>;
+  scheduledAssets: ({
+    channelId,
+    limit,
+    startAfter
+  }: {
+    channelId: string;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ArrayOfAssetResponse>;
+  flagThresholds: () => Promise<ArrayOfFlagThreshold>;
+  reviewQueue: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string[];
+  }) => Promise<ArrayOfAssetResponse>;
+  assetFlaggers: ({
+    channelId,
+    limit,
+    publishId,
+    startAfter
+  }: {
+    channelId: string;
+    limit?: number;
+    publishId: string;
+    startAfter?: string;
+  }) => Promise<ArrayOfFlaggerInfo>;
+  assetFlagReports: ({
+    channelId,
+    limit,
+    publishId,
+    startAfter
+  }: {
+    channelId: string;
+    limit?: number;
+    publishId: string;
+    startAfter?: string[];
+  }) => Promise<ArrayOfFlagReport>;
+  hasPurchased: ({
+    address,
+    channelId,
+    publishId
+  }: {
+    address: string;
+    channelId: string;
+    publishId: string;
+  }) => Promise<Boolean>;
+  assetBuyers: ({
+    channelId,
+    limit,
+    publishId,
+    startAfter
+  }: {
+    channelId: string;
+    limit?: number;
+    publishId: string;
+    startAfter?: string;
+  }) => Promise<ArrayOfPurchaseReceipt>;
+  moderators: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ArrayOfAddr>;
   reservedUsernames: ({
     limit,
     startAfter
@@ -94,6 +171,15 @@ export interface OmniFlixChannelReadOnlyInterface {
     limit?: number;
     startAfter?: string;
   }) => Promise<ArrayOfCollaboratorInfo>;
+  collaboratorInvites: ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ArrayOfCollaboratorInviteInfo>;
   followersCount: ({
     channelId
   }: {
@@ -108,6 +194,92 @@ export interface OmniFlixChannelReadOnlyInterface {
     limit?: number;
     startAfter?: string;
   }) => Promise<ArrayOfString>;
+  subscriptionPlan: ({
+    channelId
+  }: {
+    channelId: string;
+  }) => Promise<NullableSubscriptionPlan>;
+  isSubscribed: ({
+    address,
+    channelId
+  }: {
+    address: string;
+    channelId: string;
+  }) => Promise<Boolean>;
+  subscribers: ({
+    channelId,
+    limit,
+    startAfter
+  }: {
+    channelId: string;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ArrayOfSubscriberInfo>;
+  following: ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ArrayOfString>;
+  isFollowing: ({
+    address,
+    channelId
+  }: {
+    address: string;
+    channelId: string;
+  }) => Promise<Boolean>;
+  channelsByOwner: ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ArrayOfAddressChannelInfo>;
+  channelCleanupProgress: ({
+    channelId
+  }: {
+    channelId: string;
+  }) => Promise<NullableChannelCleanupProgress>;
+  pendingEarnings: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<ArrayOfCoin>;
+  channelTipStats: ({
+    channelId
+  }: {
+    channelId: string;
+  }) => Promise<TipStats>;
+  assetTipStats: ({
+    channelId,
+    publishId
+  }: {
+    channelId: string;
+    publishId: string;
+  }) => Promise<TipStats>;
+  tipperTotals: ({
+    channelId,
+    tipper
+  }: {
+    channelId: string;
+    tipper: string;
+  }) => Promise<ArrayOfCoin>;
+  topTippers: ({
+    channelId,
+    denom,
+    limit,
+    startAfter
+  }: {
+    channelId: string;
+    denom: string;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ArrayOfTipperInfo>;
 }
 export class OmniFlixChannelQueryClient implements OmniFlixChannelReadOnlyInterface {
   client: CosmWasmClient;
@@ -126,13 +298,37 @@ export class OmniFlixChannelQueryClient implements OmniFlixChannelReadOnlyInterf
     this.playlist = this.playlist.bind(this);
     this.playlists = this.playlists.bind(this);
     this.config = this.config.bind(this);
+    this.acceptedTipDenoms = this.acceptedTipDenoms.bind(this);
+    this.pendingProtocolAdmin = this.pendingProtocolAdmin.bind(this);
     this.assets = this.assets.bind(this);
     this.asset = this.asset.bind(this);
+    this.canAccessAsset = this.canAccessAsset.bind(this);
+    this.scheduledAssets = this.scheduledAssets.bind(this);
+    this.flagThresholds = this.flagThresholds.bind(this);
+    this.reviewQueue = this.reviewQueue.bind(this);
+    this.assetFlaggers = this.assetFlaggers.bind(this);
+    this.assetFlagReports = this.assetFlagReports.bind(this);
+    this.hasPurchased = this.hasPurchased.bind(this);
+    this.assetBuyers = this.assetBuyers.bind(this);
+    this.moderators = this.moderators.bind(this);
     this.reservedUsernames = this.reservedUsernames.bind(this);
     this.getChannelCollaborator = this.getChannelCollaborator.bind(this);
     this.getChannelCollaborators = this.getChannelCollaborators.bind(this);
+    this.collaboratorInvites = this.collaboratorInvites.bind(this);
     this.followersCount = this.followersCount.bind(this);
     this.followers = this.followers.bind(this);
+    this.subscriptionPlan = this.subscriptionPlan.bind(this);
+    this.isSubscribed = this.isSubscribed.bind(this);
+    this.subscribers = this.subscribers.bind(this);
+    this.following = this.following.bind(this);
+    this.isFollowing = this.isFollowing.bind(this);
+    this.channelsByOwner = this.channelsByOwner.bind(this);
+    this.channelCleanupProgress = this.channelCleanupProgress.bind(this);
+    this.pendingEarnings = this.pendingEarnings.bind(this);
+    this.channelTipStats = this.channelTipStats.bind(this);
+    this.assetTipStats = this.assetTipStats.bind(this);
+    this.tipperTotals = this.tipperTotals.bind(this);
+    this.topTippers = this.topTippers.bind(this);
   }
 
   isPaused = async (): Promise<Boolean> => {
@@ -239,6 +435,16 @@ export class OmniFlixChannelQueryClient implements OmniFlixChannelReadOnlyInterf
       config: {}
     });
   };
+  acceptedTipDenoms = async (): Promise<ArrayOfTipDenom> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      accepted_tip_denoms: {}
+    });
+  };
+  pendingProtocolAdmin = async (): Promise<NullablePendingProtocolAdmin> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      pending_protocol_admin: {}
+    });
+  };
   assets = async ({
     channelId,
     limit,
@@ -270,6 +476,150 @@ export class OmniFlixChannelQueryClient implements OmniFlixChannelReadOnlyInterf
       }
     });
   };
+  canAccessAsset = async ({
+    address,
+    channelId,
+    publishId
+  }: {
+    address: string;
+    channelId: string;
+    publishId: string;
+  }): Promise<Boolean> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      can_access_asset: {
+        address,
+        channel_id: channelId,
+        publish_id: publishId
+      }
+    });
+  };
+  scheduledAssets = async ({
+    channelId,
+    limit,
+    startAfter
+  }: {
+    channelId: string;
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ArrayOfAssetResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      scheduled_assets: {
+        channel_id: channelId,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  flagThresholds = async (): Promise<ArrayOfFlagThreshold> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      flag_thresholds: {}
+    });
+  };
+  reviewQueue = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string[];
+  }): Promise<ArrayOfAssetResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      review_queue: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  assetFlaggers = async ({
+    channelId,
+    limit,
+    publishId,
+    startAfter
+  }: {
+    channelId: string;
+    limit?: number;
+    publishId: string;
+    startAfter?: string;
+  }): Promise<ArrayOfFlaggerInfo> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      asset_flaggers: {
+        channel_id: channelId,
+        limit,
+        publish_id: publishId,
+        start_after: startAfter
+      }
+    });
+  };
+  assetFlagReports = async ({
+    channelId,
+    limit,
+    publishId,
+    startAfter
+  }: {
+    channelId: string;
+    limit?: number;
+    publishId: string;
+    startAfter?: string[];
+  }): Promise<ArrayOfFlagReport> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      asset_flag_reports: {
+        channel_id: channelId,
+        limit,
+        publish_id: publishId,
+        start_after: startAfter
+      }
+    });
+  };
+  hasPurchased = async ({
+    address,
+    channelId,
+    publishId
+  }: {
+    address: string;
+    channelId: string;
+    publishId: string;
+  }): Promise<Boolean> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      has_purchased: {
+        address,
+        channel_id: channelId,
+        publish_id: publishId
+      }
+    });
+  };
+  assetBuyers = async ({
+    channelId,
+    limit,
+    publishId,
+    startAfter
+  }: {
+    channelId: string;
+    limit?: number;
+    publishId: string;
+    startAfter?: string;
+  }): Promise<ArrayOfPurchaseReceipt> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      asset_buyers: {
+        channel_id: channelId,
+        limit,
+        publish_id: publishId,
+        start_after: startAfter
+      }
+    });
+  };
+  moderators = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ArrayOfAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      moderators: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
   reservedUsernames = async ({
     limit,
     startAfter
@@ -315,6 +665,23 @@ export class OmniFlixChannelQueryClient implements OmniFlixChannelReadOnlyInterf
       }
     });
   };
+  collaboratorInvites = async ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ArrayOfCollaboratorInviteInfo> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      collaborator_invites: {
+        address,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
   followersCount = async ({
     channelId
   }: {
@@ -343,6 +710,177 @@ export class OmniFlixChannelQueryClient implements OmniFlixChannelReadOnlyInterf
       }
     });
   };
+  subscriptionPlan = async ({
+    channelId
+  }: {
+    channelId: string;
+  }): Promise<NullableSubscriptionPlan> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      subscription_plan: {
+        channel_id: channelId
+      }
+    });
+  };
+  isSubscribed = async ({
+    address,
+    channelId
+  }: {
+    address: string;
+    channelId: string;
+  }): Promise<Boolean> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      is_subscribed: {
+        address,
+        channel_id: channelId
+      }
+    });
+  };
+  subscribers = async ({
+    channelId,
+    limit,
+    startAfter
+  }: {
+    channelId: string;
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ArrayOfSubscriberInfo> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      subscribers: {
+        channel_id: channelId,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  following = async ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ArrayOfString> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      following: {
+        address,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  isFollowing = async ({
+    address,
+    channelId
+  }: {
+    address: string;
+    channelId: string;
+  }): Promise<Boolean> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      is_following: {
+        address,
+        channel_id: channelId
+      }
+    });
+  };
+  channelsByOwner = async ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ArrayOfAddressChannelInfo> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      channels_by_owner: {
+        address,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  channelCleanupProgress = async ({
+    channelId
+  }: {
+    channelId: string;
+  }): Promise<NullableChannelCleanupProgress> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      channel_cleanup_progress: {
+        channel_id: channelId
+      }
+    });
+  };
+  pendingEarnings = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<ArrayOfCoin> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      pending_earnings: {
+        address
+      }
+    });
+  };
+  channelTipStats = async ({
+    channelId
+  }: {
+    channelId: string;
+  }): Promise<TipStats> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      channel_tip_stats: {
+        channel_id: channelId
+      }
+    });
+  };
+  assetTipStats = async ({
+    channelId,
+    publishId
+  }: {
+    channelId: string;
+    publishId: string;
+  }): Promise<TipStats> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      asset_tip_stats: {
+        channel_id: channelId,
+        publish_id: publishId
+      }
+    });
+  };
+  tipperTotals = async ({
+    channelId,
+    tipper
+  }: {
+    channelId: string;
+    tipper: string;
+  }): Promise<ArrayOfCoin> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      tipper_totals: {
+        channel_id: channelId,
+        tipper
+      }
+    });
+  };
+  topTippers = async ({
+    channelId,
+    denom,
+    limit,
+    startAfter
+  }: {
+    channelId: string;
+    denom: string;
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ArrayOfTipperInfo> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      top_tippers: {
+        channel_id: channelId,
+        denom,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
 }
 export interface OmniFlixChannelInterface extends OmniFlixChannelReadOnlyInterface {
   contractAddress: string;
@@ -350,12 +888,25 @@ export interface OmniFlixChannelInterface extends OmniFlixChannelReadOnlyInterfa
   adminSetConfig: ({
     channelCreationFee,
     feeCollector,
-    protocolAdmin
+    tipFeeRate,
+    usernameChangeFee,
+    usernameHoldPeriod
   }: {
     channelCreationFee?: Coin[];
     feeCollector?: string;
-    protocolAdmin?: string;
+    tipFeeRate?: Decimal;
+    usernameChangeFee?: Coin[];
+    usernameHoldPeriod?: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  adminProposeProtocolAdmin: ({
+    expiry,
+    newAdmin
+  }: {
+    expiry?: Expiration;
+    newAdmin: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  adminAcceptProtocolAdmin: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  adminCancelProtocolAdmin: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   adminRemoveAssets: ({
     assetKeys,
     refreshFlags
@@ -363,6 +914,54 @@ export interface OmniFlixChannelInterface extends OmniFlixChannelReadOnlyInterfa
     assetKeys: string[][];
     refreshFlags?: boolean;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  adminManageModerators: ({
+    addModerators,
+    removeModerators
+  }: {
+    addModerators?: string[];
+    removeModerators?: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  adminHideAssets: ({
+    assetKeys
+  }: {
+    assetKeys: string[][];
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  adminSuspendChannel: ({
+    channelId,
+    reason,
+    until
+  }: {
+    channelId: string;
+    reason: string;
+    until?: Timestamp;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  adminUnsuspendChannel: ({
+    channelId
+  }: {
+    channelId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  adminResetFlags: ({
+    assetKeys,
+    flags
+  }: {
+    assetKeys: string[][];
+    flags?: Flag[];
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  adminClearAllFlags: ({
+    limit
+  }: {
+    limit?: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  adminSetFlagThresholds: ({
+    thresholds
+  }: {
+    thresholds: FlagThreshold[];
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  adminRestoreAssets: ({
+    assetKeys
+  }: {
+    assetKeys: string[][];
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   adminManageReservedUsernames: ({
     addUsernames,
     removeUsernames
@@ -370,6 +969,13 @@ export interface OmniFlixChannelInterface extends OmniFlixChannelReadOnlyInterfa
     addUsernames?: ReservedUsername[];
     removeUsernames?: string[];
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  adminManageTipDenoms: ({
+    addTipDenoms,
+    removeTipDenoms
+  }: {
+    addTipDenoms?: TipDenom[];
+    removeTipDenoms?: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   pause: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   unpause: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   setPausers: ({
@@ -378,18 +984,24 @@ export interface OmniFlixChannelInterface extends OmniFlixChannelReadOnlyInterfa
     pausers: string[];
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   assetPublish: ({
+    accessPolicy,
     assetSource,
     channelId,
     isVisible,
     metadata,
     playlistId,
+    price,
+    publishAt,
     salt
   }: {
+    accessPolicy?: AccessPolicy;
     assetSource: AssetSource;
     channelId: string;
     isVisible: boolean;
     metadata: AssetMetadata;
     playlistId?: string;
+    price?: Coin;
+    publishAt?: Timestamp;
     salt: Binary;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   assetUnpublish: ({
@@ -400,22 +1012,44 @@ export interface OmniFlixChannelInterface extends OmniFlixChannelReadOnlyInterfa
     publishId: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   assetUpdateDetails: ({
+    accessPolicy,
     channelId,
+    clearPublishAt,
     description,
     isVisible,
     mediaUri,
     name,
+    publishAt,
     publishId,
     thumbnailUri
   }: {
+    accessPolicy?: AccessPolicy;
     channelId: string;
+    clearPublishAt?: boolean;
     description?: string;
     isVisible?: boolean;
     mediaUri?: string;
     name?: string;
+    publishAt?: Timestamp;
     publishId: string;
     thumbnailUri?: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  assetSetPrice: ({
+    channelId,
+    price,
+    publishId
+  }: {
+    channelId: string;
+    price?: Coin;
+    publishId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  assetPurchase: ({
+    channelId,
+    publishId
+  }: {
+    channelId: string;
+    publishId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   assetFlag: ({
     channelId,
     flag,
@@ -427,6 +1061,15 @@ export interface OmniFlixChannelInterface extends OmniFlixChannelReadOnlyInterfa
     interactiveVideoId?: string;
     publishId: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  assetRetractFlag: ({
+    channelId,
+    flag,
+    publishId
+  }: {
+    channelId: string;
+    flag: Flag;
+    publishId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   playlistCreate: ({
     channelId,
     playlistName,
@@ -492,6 +1135,20 @@ export interface OmniFlixChannelInterface extends OmniFlixChannelReadOnlyInterfa
   }: {
     channelId: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  channelCleanup: ({
+    channelId,
+    limit
+  }: {
+    channelId: string;
+    limit?: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  channelChangeUsername: ({
+    channelId,
+    newUserName
+  }: {
+    channelId: string;
+    newUserName: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   channelUpdateDetails: ({
     bannerPicture,
     channelId,
@@ -500,30 +1157,61 @@ export interface OmniFlixChannelInterface extends OmniFlixChannelReadOnlyInterfa
     paymentAddress,
     profilePicture
   }: {
-    bannerPicture?: string;
+    bannerPicture?: string;
+    channelId: string;
+    channelName?: string;
+    description?: string;
+    paymentAddress?: string;
+    profilePicture?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  channelSetPayoutMode: ({
+    channelId,
+    payoutMode
+  }: {
+    channelId: string;
+    payoutMode: PayoutMode;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  withdrawEarnings: (fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  channelTip: ({
+    amount,
+    assetId,
+    channelId
+  }: {
+    amount: Coin[];
+    assetId?: string;
+    channelId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  channelInviteCollaborator: ({
+    channelId,
+    collaboratorAddress,
+    collaboratorDetails,
+    expiry
+  }: {
+    channelId: string;
+    collaboratorAddress: string;
+    collaboratorDetails: ChannelCollaborator;
+    expiry?: Expiration;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  channelAcceptCollaboratorInvite: ({
+    channelId
+  }: {
     channelId: string;
-    channelName?: string;
-    description?: string;
-    paymentAddress?: string;
-    profilePicture?: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
-  channelTip: ({
-    amount,
-    assetId,
+  channelDeclineCollaboratorInvite: ({
     channelId
   }: {
-    amount: Coin;
-    assetId?: string;
     channelId: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
-  channelAddCollaborator: ({
+  channelUpdateCollaborator: ({
     channelId,
     collaboratorAddress,
-    collaboratorDetails
+    role,
+    share
   }: {
     channelId: string;
     collaboratorAddress: string;
-    collaboratorDetails: ChannelCollaborator;
+    role?: Role;
+    share?: Decimal;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   channelRemoveCollaborator: ({
     channelId,
@@ -532,6 +1220,21 @@ export interface OmniFlixChannelInterface extends OmniFlixChannelReadOnlyInterfa
     channelId: string;
     collaboratorAddress: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  channelLeaveCollaboration: ({
+    channelId
+  }: {
+    channelId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  channelPruneCollaborators: ({
+    channelId
+  }: {
+    channelId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  channelSyncOwner: ({
+    channelId
+  }: {
+    channelId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
   channelFollow: ({
     channelId
   }: {
@@ -542,6 +1245,20 @@ export interface OmniFlixChannelInterface extends OmniFlixChannelReadOnlyInterfa
   }: {
     channelId: string;
   }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  channelSetSubscriptionPlan: ({
+    channelId,
+    plan
+  }: {
+    channelId: string;
+    plan?: SubscriptionPlan;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
+  channelSubscribe: ({
+    channelId,
+    periods
+  }: {
+    channelId: string;
+    periods: number;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: Coin[]) => Promise<ExecuteResult>;
 }
 export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements OmniFlixChannelInterface {
   client: SigningCosmWasmClient;
@@ -554,15 +1271,30 @@ export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.adminSetConfig = this.adminSetConfig.bind(this);
+    this.adminProposeProtocolAdmin = this.adminProposeProtocolAdmin.bind(this);
+    this.adminAcceptProtocolAdmin = this.adminAcceptProtocolAdmin.bind(this);
+    this.adminCancelProtocolAdmin = this.adminCancelProtocolAdmin.bind(this);
     this.adminRemoveAssets = this.adminRemoveAssets.bind(this);
+    this.adminManageModerators = this.adminManageModerators.bind(this);
+    this.adminHideAssets = this.adminHideAssets.bind(this);
+    this.adminSuspendChannel = this.adminSuspendChannel.bind(this);
+    this.adminUnsuspendChannel = this.adminUnsuspendChannel.bind(this);
+    this.adminResetFlags = this.adminResetFlags.bind(this);
+    this.adminClearAllFlags = this.adminClearAllFlags.bind(this);
+    this.adminSetFlagThresholds = this.adminSetFlagThresholds.bind(this);
+    this.adminRestoreAssets = this.adminRestoreAssets.bind(this);
     this.adminManageReservedUsernames = this.adminManageReservedUsernames.bind(this);
+    this.adminManageTipDenoms = this.adminManageTipDenoms.bind(this);
     this.pause = this.pause.bind(this);
     this.unpause = this.unpause.bind(this);
     this.setPausers = this.setPausers.bind(this);
     this.assetPublish = this.assetPublish.bind(this);
     this.assetUnpublish = this.assetUnpublish.bind(this);
     this.assetUpdateDetails = this.assetUpdateDetails.bind(this);
+    this.assetSetPrice = this.assetSetPrice.bind(this);
+    this.assetPurchase = this.assetPurchase.bind(this);
     this.assetFlag = this.assetFlag.bind(this);
+    this.assetRetractFlag = this.assetRetractFlag.bind(this);
     this.playlistCreate = this.playlistCreate.bind(this);
     this.playlistDelete = this.playlistDelete.bind(this);
     this.playlistAddAsset = this.playlistAddAsset.bind(this);
@@ -570,31 +1302,73 @@ export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements
     this.playlistRefresh = this.playlistRefresh.bind(this);
     this.channelCreate = this.channelCreate.bind(this);
     this.channelDelete = this.channelDelete.bind(this);
+    this.channelCleanup = this.channelCleanup.bind(this);
+    this.channelChangeUsername = this.channelChangeUsername.bind(this);
     this.channelUpdateDetails = this.channelUpdateDetails.bind(this);
+    this.channelSetPayoutMode = this.channelSetPayoutMode.bind(this);
+    this.withdrawEarnings = this.withdrawEarnings.bind(this);
     this.channelTip = this.channelTip.bind(this);
-    this.channelAddCollaborator = this.channelAddCollaborator.bind(this);
+    this.channelInviteCollaborator = this.channelInviteCollaborator.bind(this);
+    this.channelAcceptCollaboratorInvite = this.channelAcceptCollaboratorInvite.bind(this);
+    this.channelDeclineCollaboratorInvite = this.channelDeclineCollaboratorInvite.bind(this);
+    this.channelUpdateCollaborator = this.channelUpdateCollaborator.bind(this);
     this.channelRemoveCollaborator = this.channelRemoveCollaborator.bind(this);
+    this.channelLeaveCollaboration = this.channelLeaveCollaboration.bind(this);
+    this.channelPruneCollaborators = this.channelPruneCollaborators.bind(this);
+    this.channelSyncOwner = this.channelSyncOwner.bind(this);
     this.channelFollow = this.channelFollow.bind(this);
     this.channelUnfollow = this.channelUnfollow.bind(this);
+    this.channelSetSubscriptionPlan = this.channelSetSubscriptionPlan.bind(this);
+    this.channelSubscribe = this.channelSubscribe.bind(this);
   }
 
   adminSetConfig = async ({
     channelCreationFee,
     feeCollector,
-    protocolAdmin
+    tipFeeRate,
+    usernameChangeFee,
+    usernameHoldPeriod
   }: {
     channelCreationFee?: Coin[];
     feeCollector?: string;
-    protocolAdmin?: string;
+    tipFeeRate?: Decimal;
+    usernameChangeFee?: Coin[];
+    usernameHoldPeriod?: number;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       admin_set_config: {
         channel_creation_fee: channelCreationFee,
         fee_collector: feeCollector,
-        protocol_admin: protocolAdmin
+        tip_fee_rate: tipFeeRate,
+        username_change_fee: usernameChangeFee,
+        username_hold_period: usernameHoldPeriod
+      }
+    }, fee, memo, _funds);
+  };
+  adminProposeProtocolAdmin = async ({
+    expiry,
+    newAdmin
+  }: {
+    expiry?: Expiration;
+    newAdmin: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_propose_protocol_admin: {
+        expiry,
+        new_admin: newAdmin
       }
     }, fee, memo, _funds);
   };
+  adminAcceptProtocolAdmin = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_accept_protocol_admin: {}
+    }, fee, memo, _funds);
+  };
+  adminCancelProtocolAdmin = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_cancel_protocol_admin: {}
+    }, fee, memo, _funds);
+  };
   adminRemoveAssets = async ({
     assetKeys,
     refreshFlags
@@ -609,6 +1383,106 @@ export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements
       }
     }, fee, memo, _funds);
   };
+  adminManageModerators = async ({
+    addModerators,
+    removeModerators
+  }: {
+    addModerators?: string[];
+    removeModerators?: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_manage_moderators: {
+        add_moderators: addModerators,
+        remove_moderators: removeModerators
+      }
+    }, fee, memo, _funds);
+  };
+  adminHideAssets = async ({
+    assetKeys
+  }: {
+    assetKeys: string[][];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_hide_assets: {
+        asset_keys: assetKeys
+      }
+    }, fee, memo, _funds);
+  };
+  adminSuspendChannel = async ({
+    channelId,
+    reason,
+    until
+  }: {
+    channelId: string;
+    reason: string;
+    until?: Timestamp;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_suspend_channel: {
+        channel_id: channelId,
+        reason,
+        until
+      }
+    }, fee, memo, _funds);
+  };
+  adminUnsuspendChannel = async ({
+    channelId
+  }: {
+    channelId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_unsuspend_channel: {
+        channel_id: channelId
+      }
+    }, fee, memo, _funds);
+  };
+  adminResetFlags = async ({
+    assetKeys,
+    flags
+  }: {
+    assetKeys: string[][];
+    flags?: Flag[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_reset_flags: {
+        asset_keys: assetKeys,
+        flags
+      }
+    }, fee, memo, _funds);
+  };
+  adminClearAllFlags = async ({
+    limit
+  }: {
+    limit?: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_clear_all_flags: {
+        limit
+      }
+    }, fee, memo, _funds);
+  };
+  adminSetFlagThresholds = async ({
+    thresholds
+  }: {
+    thresholds: FlagThreshold[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_set_flag_thresholds: {
+        thresholds
+      }
+    }, fee, memo, _funds);
+  };
+  adminRestoreAssets = async ({
+    assetKeys
+  }: {
+    assetKeys: string[][];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_restore_assets: {
+        asset_keys: assetKeys
+      }
+    }, fee, memo, _funds);
+  };
   adminManageReservedUsernames = async ({
     addUsernames,
     removeUsernames
@@ -623,6 +1497,20 @@ export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements
       }
     }, fee, memo, _funds);
   };
+  adminManageTipDenoms = async ({
+    addTipDenoms,
+    removeTipDenoms
+  }: {
+    addTipDenoms?: TipDenom[];
+    removeTipDenoms?: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_manage_tip_denoms: {
+        add_tip_denoms: addTipDenoms,
+        remove_tip_denoms: removeTipDenoms
+      }
+    }, fee, memo, _funds);
+  };
   pause = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       pause: {}
@@ -645,27 +1533,36 @@ export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements
     }, fee, memo, _funds);
   };
   assetPublish = async ({
+    accessPolicy,
     assetSource,
     channelId,
     isVisible,
     metadata,
     playlistId,
+    price,
+    publishAt,
     salt
   }: {
+    accessPolicy?: AccessPolicy;
     assetSource: AssetSource;
     channelId: string;
     isVisible: boolean;
     metadata: AssetMetadata;
     playlistId?: string;
+    price?: Coin;
+    publishAt?: Timestamp;
     salt: Binary;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       asset_publish: {
+        access_policy: accessPolicy,
         asset_source: assetSource,
         channel_id: channelId,
         is_visible: isVisible,
         metadata,
         playlist_id: playlistId,
+        price,
+        publish_at: publishAt,
         salt
       }
     }, fee, memo, _funds);
@@ -685,34 +1582,74 @@ export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements
     }, fee, memo, _funds);
   };
   assetUpdateDetails = async ({
+    accessPolicy,
     channelId,
+    clearPublishAt,
     description,
     isVisible,
     mediaUri,
     name,
+    publishAt,
     publishId,
     thumbnailUri
   }: {
+    accessPolicy?: AccessPolicy;
     channelId: string;
+    clearPublishAt?: boolean;
     description?: string;
     isVisible?: boolean;
     mediaUri?: string;
     name?: string;
+    publishAt?: Timestamp;
     publishId: string;
     thumbnailUri?: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       asset_update_details: {
+        access_policy: accessPolicy,
         channel_id: channelId,
+        clear_publish_at: clearPublishAt,
         description,
         is_visible: isVisible,
         media_uri: mediaUri,
         name,
+        publish_at: publishAt,
         publish_id: publishId,
         thumbnail_uri: thumbnailUri
       }
     }, fee, memo, _funds);
   };
+  assetSetPrice = async ({
+    channelId,
+    price,
+    publishId
+  }: {
+    channelId: string;
+    price?: Coin;
+    publishId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      asset_set_price: {
+        channel_id: channelId,
+        price,
+        publish_id: publishId
+      }
+    }, fee, memo, _funds);
+  };
+  assetPurchase = async ({
+    channelId,
+    publishId
+  }: {
+    channelId: string;
+    publishId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      asset_purchase: {
+        channel_id: channelId,
+        publish_id: publishId
+      }
+    }, fee, memo, _funds);
+  };
   assetFlag = async ({
     channelId,
     flag,
@@ -733,6 +1670,23 @@ export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements
       }
     }, fee, memo, _funds);
   };
+  assetRetractFlag = async ({
+    channelId,
+    flag,
+    publishId
+  }: {
+    channelId: string;
+    flag: Flag;
+    publishId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      asset_retract_flag: {
+        channel_id: channelId,
+        flag,
+        publish_id: publishId
+      }
+    }, fee, memo, _funds);
+  };
   playlistCreate = async ({
     channelId,
     playlistName,
@@ -855,6 +1809,34 @@ export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements
       }
     }, fee, memo, _funds);
   };
+  channelCleanup = async ({
+    channelId,
+    limit
+  }: {
+    channelId: string;
+    limit?: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      channel_cleanup: {
+        channel_id: channelId,
+        limit
+      }
+    }, fee, memo, _funds);
+  };
+  channelChangeUsername = async ({
+    channelId,
+    newUserName
+  }: {
+    channelId: string;
+    newUserName: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      channel_change_username: {
+        channel_id: channelId,
+        new_user_name: newUserName
+      }
+    }, fee, memo, _funds);
+  };
   channelUpdateDetails = async ({
     bannerPicture,
     channelId,
@@ -881,12 +1863,31 @@ export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements
       }
     }, fee, memo, _funds);
   };
+  channelSetPayoutMode = async ({
+    channelId,
+    payoutMode
+  }: {
+    channelId: string;
+    payoutMode: PayoutMode;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      channel_set_payout_mode: {
+        channel_id: channelId,
+        payout_mode: payoutMode
+      }
+    }, fee, memo, _funds);
+  };
+  withdrawEarnings = async (fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      withdraw_earnings: {}
+    }, fee, memo, _funds);
+  };
   channelTip = async ({
     amount,
     assetId,
     channelId
   }: {
-    amount: Coin;
+    amount: Coin[];
     assetId?: string;
     channelId: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
@@ -898,20 +1899,65 @@ export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements
       }
     }, fee, memo, _funds);
   };
-  channelAddCollaborator = async ({
+  channelInviteCollaborator = async ({
     channelId,
     collaboratorAddress,
-    collaboratorDetails
+    collaboratorDetails,
+    expiry
   }: {
     channelId: string;
     collaboratorAddress: string;
     collaboratorDetails: ChannelCollaborator;
+    expiry?: Expiration;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      channel_invite_collaborator: {
+        channel_id: channelId,
+        collaborator_address: collaboratorAddress,
+        collaborator_details: collaboratorDetails,
+        expiry
+      }
+    }, fee, memo, _funds);
+  };
+  channelAcceptCollaboratorInvite = async ({
+    channelId
+  }: {
+    channelId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      channel_accept_collaborator_invite: {
+        channel_id: channelId
+      }
+    }, fee, memo, _funds);
+  };
+  channelDeclineCollaboratorInvite = async ({
+    channelId
+  }: {
+    channelId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      channel_decline_collaborator_invite: {
+        channel_id: channelId
+      }
+    }, fee, memo, _funds);
+  };
+  channelUpdateCollaborator = async ({
+    channelId,
+    collaboratorAddress,
+    role,
+    share
+  }: {
+    channelId: string;
+    collaboratorAddress: string;
+    role?: Role;
+    share?: Decimal;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      channel_add_collaborator: {
+      channel_update_collaborator: {
         channel_id: channelId,
         collaborator_address: collaboratorAddress,
-        collaborator_details: collaboratorDetails
+        role,
+        share
       }
     }, fee, memo, _funds);
   };
@@ -929,6 +1975,39 @@ export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements
       }
     }, fee, memo, _funds);
   };
+  channelLeaveCollaboration = async ({
+    channelId
+  }: {
+    channelId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      channel_leave_collaboration: {
+        channel_id: channelId
+      }
+    }, fee, memo, _funds);
+  };
+  channelPruneCollaborators = async ({
+    channelId
+  }: {
+    channelId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      channel_prune_collaborators: {
+        channel_id: channelId
+      }
+    }, fee, memo, _funds);
+  };
+  channelSyncOwner = async ({
+    channelId
+  }: {
+    channelId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      channel_sync_owner: {
+        channel_id: channelId
+      }
+    }, fee, memo, _funds);
+  };
   channelFollow = async ({
     channelId
   }: {
@@ -951,4 +2030,32 @@ export class OmniFlixChannelClient extends OmniFlixChannelQueryClient implements
       }
     }, fee, memo, _funds);
   };
+  channelSetSubscriptionPlan = async ({
+    channelId,
+    plan
+  }: {
+    channelId: string;
+    plan?: SubscriptionPlan;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      channel_set_subscription_plan: {
+        channel_id: channelId,
+        plan
+      }
+    }, fee, memo, _funds);
+  };
+  channelSubscribe = async ({
+    channelId,
+    periods
+  }: {
+    channelId: string;
+    periods: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      channel_subscribe: {
+        channel_id: channelId,
+        periods
+      }
+    }, fee, memo, _funds);
+  };
 }
\ No newline at end of file
@@ -7,19 +7,32 @@
 import { MsgExecuteContractEncodeObject } from "@cosmjs/cosmwasm-stargate";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Uint128, Addr, InstantiateMsg, Coin, ChannelTokenDetails, ChannelsCollectionDetails, ReservedUsername, ExecuteMsg, AssetSource, Binary, Flag, Role, Decimal, AssetMetadata, ChannelCollaborator, QueryMsg, AssetResponse, Asset, FlagInfo, ArrayOfAssetResponse, ChannelResponse, CollaboratorInfo, ChannelDetails, String, ChannelMetadata, ArrayOfChannelResponse, ChannelConractConfig, AuthDetails, ArrayOfString, Uint64, ArrayOfCollaboratorInfo, Boolean, Playlist, ArrayOfPlaylist, ArrayOfReservedUsername } from "./OmniFlixChannel.types";
+import { Uint128, Addr, InstantiateMsg, Coin, ChannelTokenDetails, ChannelsCollectionDetails, ReservedUsername, ExecuteMsg, Decimal, Expiration, Timestamp, Uint64, Flag, AccessPolicy, AssetSource, Binary, PayoutMode, Role, FlagThreshold, TipDenom, AssetMetadata, ChannelCollaborator, SubscriptionPlan, QueryMsg, MigrateMsg, ArrayOfTipDenom, AssetResponse, Asset, FlagInfo, ArrayOfPurchaseReceipt, PurchaseReceipt, ArrayOfFlagReport, FlagReport, ArrayOfFlaggerInfo, FlaggerInfo, TipStats, ArrayOfAssetResponse, Boolean, ChannelResponse, CollaboratorInfo, ChannelSuspension, NullableChannelCleanupProgress, CleanupStage, ChannelCleanupProgress, ChannelDetails, String, ChannelMetadata, ArrayOfChannelResponse, ArrayOfAddressChannelInfo, ChannelRelation, AddressChannelInfo, ArrayOfCollaboratorInviteInfo, CollaboratorInviteInfo, ChannelConractConfig, AuthDetails, ArrayOfFlagThreshold, ArrayOfString, ArrayOfCollaboratorInfo, ArrayOfAddr, ArrayOfCoin, NullablePendingProtocolAdmin, PendingProtocolAdmin, Playlist, ArrayOfPlaylist, ArrayOfReservedUsername, ArrayOfSubscriberInfo, SubscriberInfo, NullableSubscriptionPlan, ArrayOfTipperInfo, TipperInfo } from "./OmniFlixChannel.types";
 export interface OmniFlixChannelMsg {
   contractAddress: string;
   sender: string;
   adminSetConfig: ({
     channelCreationFee,
     feeCollector,
-    protocolAdmin
+    tipFeeRate,
+    usernameChangeFee,
+    usernameHoldPeriod
   }: {
     channelCreationFee?: Coin[];
     feeCollector?: string;
-    protocolAdmin?: string;
+    tipFeeRate?: Decimal;
+    usernameChangeFee?: Coin[];
+    usernameHoldPeriod?: number;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  adminProposeProtocolAdmin: ({
+    expiry,
+    newAdmin
+  }: {
+    expiry?: Expiration;
+    newAdmin: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  adminAcceptProtocolAdmin: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  adminCancelProtocolAdmin: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
   adminRemoveAssets: ({
     assetKeys,
     refreshFlags
@@ -27,6 +40,54 @@ export interface OmniFlixChannelMsg {
     assetKeys: string[][];
     refreshFlags?: boolean;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  adminManageModerators: ({
+    addModerators,
+    removeModerators
+  }: {
+    addModerators?: string[];
+    removeModerators?: string[];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  adminHideAssets: ({
+    assetKeys
+  }: {
+    assetKeys: string[][];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  adminSuspendChannel: ({
+    channelId,
+    reason,
+    until
+  }: {
+    channelId: string;
+    reason: string;
+    until?: Timestamp;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  adminUnsuspendChannel: ({
+    channelId
+  }: {
+    channelId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  adminResetFlags: ({
+    assetKeys,
+    flags
+  }: {
+    assetKeys: string[][];
+    flags?: Flag[];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  adminClearAllFlags: ({
+    limit
+  }: {
+    limit?: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  adminSetFlagThresholds: ({
+    thresholds
+  }: {
+    thresholds: FlagThreshold[];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  adminRestoreAssets: ({
+    assetKeys
+  }: {
+    assetKeys: string[][];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   adminManageReservedUsernames: ({
     addUsernames,
     removeUsernames
@@ -34,6 +95,13 @@ export interface OmniFlixChannelMsg {
     addUsernames?: ReservedUsername[];
     removeUsernames?: string[];
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  adminManageTipDenoms: ({
+    addTipDenoms,
+    removeTipDenoms
+  }: {
+    addTipDenoms?: TipDenom[];
+    removeTipDenoms?: string[];
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   pause: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
   unpause: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
   setPausers: ({
@@ -42,18 +110,24 @@ export interface OmniFlixChannelMsg {
     pausers: string[];
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   assetPublish: ({
+    accessPolicy,
     assetSource,
     channelId,
     isVisible,
     metadata,
     playlistId,
+    price,
+    publishAt,
     salt
   }: {
+    accessPolicy?: AccessPolicy;
     assetSource: AssetSource;
     channelId: string;
     isVisible: boolean;
     metadata: AssetMetadata;
     playlistId?: string;
+    price?: Coin;
+    publishAt?: Timestamp;
     salt: Binary;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   assetUnpublish: ({
@@ -64,22 +138,44 @@ export interface OmniFlixChannelMsg {
     publishId: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   assetUpdateDetails: ({
+    accessPolicy,
     channelId,
+    clearPublishAt,
     description,
     isVisible,
     mediaUri,
     name,
+    publishAt,
     publishId,
     thumbnailUri
   }: {
+    accessPolicy?: AccessPolicy;
     channelId: string;
+    clearPublishAt?: boolean;
     description?: string;
     isVisible?: boolean;
     mediaUri?: string;
     name?: string;
+    publishAt?: Timestamp;
     publishId: string;
     thumbnailUri?: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  assetSetPrice: ({
+    channelId,
+    price,
+    publishId
+  }: {
+    channelId: string;
+    price?: Coin;
+    publishId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  assetPurchase: ({
+    channelId,
+    publishId
+  }: {
+    channelId: string;
+    publishId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   assetFlag: ({
     channelId,
     flag,
@@ -91,6 +187,15 @@ export interface OmniFlixChannelMsg {
     interactiveVideoId?: string;
     publishId: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  assetRetractFlag: ({
+    channelId,
+    flag,
+    publishId
+  }: {
+    channelId: string;
+    flag: Flag;
+    publishId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   playlistCreate: ({
     channelId,
     playlistName,
@@ -156,6 +261,20 @@ export interface OmniFlixChannelMsg {
   }: {
     channelId: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  channelCleanup: ({
+    channelId,
+    limit
+  }: {
+    channelId: string;
+    limit?: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  channelChangeUsername: ({
+    channelId,
+    newUserName
+  }: {
+    channelId: string;
+    newUserName: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   channelUpdateDetails: ({
     bannerPicture,
     channelId,
@@ -171,23 +290,54 @@ export interface OmniFlixChannelMsg {
     paymentAddress?: string;
     profilePicture?: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  channelSetPayoutMode: ({
+    channelId,
+    payoutMode
+  }: {
+    channelId: string;
+    payoutMode: PayoutMode;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  withdrawEarnings: (_funds?: Coin[]) => MsgExecuteContractEncodeObject;
   channelTip: ({
     amount,
     assetId,
     channelId
   }: {
-    amount: Coin;
+    amount: Coin[];
     assetId?: string;
     channelId: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
-  channelAddCollaborator: ({
+  channelInviteCollaborator: ({
     channelId,
     collaboratorAddress,
-    collaboratorDetails
+    collaboratorDetails,
+    expiry
   }: {
     channelId: string;
     collaboratorAddress: string;
     collaboratorDetails: ChannelCollaborator;
+    expiry?: Expiration;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  channelAcceptCollaboratorInvite: ({
+    channelId
+  }: {
+    channelId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  channelDeclineCollaboratorInvite: ({
+    channelId
+  }: {
+    channelId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  channelUpdateCollaborator: ({
+    channelId,
+    collaboratorAddress,
+    role,
+    share
+  }: {
+    channelId: string;
+    collaboratorAddress: string;
+    role?: Role;
+    share?: Decimal;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   channelRemoveCollaborator: ({
     channelId,
@@ -196,6 +346,21 @@ export interface OmniFlixChannelMsg {
     channelId: string;
     collaboratorAddress: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  channelLeaveCollaboration: ({
+    channelId
+  }: {
+    channelId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  channelPruneCollaborators: ({
+    channelId
+  }: {
+    channelId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  channelSyncOwner: ({
+    channelId
+  }: {
+    channelId: string;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
   channelFollow: ({
     channelId
   }: {
@@ -206,6 +371,20 @@ export interface OmniFlixChannelMsg {
   }: {
     channelId: string;
   }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  channelSetSubscriptionPlan: ({
+    channelId,
+    plan
+  }: {
+    channelId: string;
+    plan?: SubscriptionPlan;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  channelSubscribe: ({
+    channelId,
+    periods
+  }: {
+    channelId: string;
+    periods: number;
+  }, _funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
   sender: string;
@@ -215,15 +394,30 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.adminSetConfig = this.adminSetConfig.bind(this);
+    this.adminProposeProtocolAdmin = this.adminProposeProtocolAdmin.bind(this);
+    this.adminAcceptProtocolAdmin = this.adminAcceptProtocolAdmin.bind(this);
+    this.adminCancelProtocolAdmin = this.adminCancelProtocolAdmin.bind(this);
     this.adminRemoveAssets = this.adminRemoveAssets.bind(this);
+    this.adminManageModerators = this.adminManageModerators.bind(this);
+    this.adminHideAssets = this.adminHideAssets.bind(this);
+    this.adminSuspendChannel = this.adminSuspendChannel.bind(this);
+    this.adminUnsuspendChannel = this.adminUnsuspendChannel.bind(this);
+    this.adminResetFlags = this.adminResetFlags.bind(this);
+    this.adminClearAllFlags = this.adminClearAllFlags.bind(this);
+    this.adminSetFlagThresholds = this.adminSetFlagThresholds.bind(this);
+    this.adminRestoreAssets = this.adminRestoreAssets.bind(this);
     this.adminManageReservedUsernames = this.adminManageReservedUsernames.bind(this);
+    this.adminManageTipDenoms = this.adminManageTipDenoms.bind(this);
     this.pause = this.pause.bind(this);
     this.unpause = this.unpause.bind(this);
     this.setPausers = this.setPausers.bind(this);
     this.assetPublish = this.assetPublish.bind(this);
     this.assetUnpublish = this.assetUnpublish.bind(this);
     this.assetUpdateDetails = this.assetUpdateDetails.bind(this);
+    this.assetSetPrice = this.assetSetPrice.bind(this);
+    this.assetPurchase = this.assetPurchase.bind(this);
     this.assetFlag = this.assetFlag.bind(this);
+    this.assetRetractFlag = this.assetRetractFlag.bind(this);
     this.playlistCreate = this.playlistCreate.bind(this);
     this.playlistDelete = this.playlistDelete.bind(this);
     this.playlistAddAsset = this.playlistAddAsset.bind(this);
@@ -231,22 +425,38 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
     this.playlistRefresh = this.playlistRefresh.bind(this);
     this.channelCreate = this.channelCreate.bind(this);
     this.channelDelete = this.channelDelete.bind(this);
+    this.channelCleanup = this.channelCleanup.bind(this);
+    this.channelChangeUsername = this.channelChangeUsername.bind(this);
     this.channelUpdateDetails = this.channelUpdateDetails.bind(this);
+    this.channelSetPayoutMode = this.channelSetPayoutMode.bind(this);
+    this.withdrawEarnings = this.withdrawEarnings.bind(this);
     this.channelTip = this.channelTip.bind(this);
-    this.channelAddCollaborator = this.channelAddCollaborator.bind(this);
+    this.channelInviteCollaborator = this.channelInviteCollaborator.bind(this);
+    this.channelAcceptCollaboratorInvite = this.channelAcceptCollaboratorInvite.bind(this);
+    this.channelDeclineCollaboratorInvite = this.channelDeclineCollaboratorInvite.bind(this);
+    this.channelUpdateCollaborator = this.channelUpdateCollaborator.bind(this);
     this.channelRemoveCollaborator = this.channelRemoveCollaborator.bind(this);
+    this.channelLeaveCollaboration = this.channelLeaveCollaboration.bind(this);
+    this.channelPruneCollaborators = this.channelPruneCollaborators.bind(this);
+    this.channelSyncOwner = this.channelSyncOwner.bind(this);
     this.channelFollow = this.channelFollow.bind(this);
     this.channelUnfollow = this.channelUnfollow.bind(this);
+    this.channelSetSubscriptionPlan = this.channelSetSubscriptionPlan.bind(this);
+    this.channelSubscribe = this.channelSubscribe.bind(this);
   }
 
   adminSetConfig = ({
     channelCreationFee,
     feeCollector,
-    protocolAdmin
+    tipFeeRate,
+    usernameChangeFee,
+    usernameHoldPeriod
   }: {
     channelCreationFee?: Coin[];
     feeCollector?: string;
-    protocolAdmin?: string;
+    tipFeeRate?: Decimal;
+    usernameChangeFee?: Coin[];
+    usernameHoldPeriod?: number;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -257,19 +467,21 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
           admin_set_config: {
             channel_creation_fee: channelCreationFee,
             fee_collector: feeCollector,
-            protocol_admin: protocolAdmin
+            tip_fee_rate: tipFeeRate,
+            username_change_fee: usernameChangeFee,
+            username_hold_period: usernameHoldPeriod
           }
         })),
         funds: _funds
       })
     };
   };
-  adminRemoveAssets = ({
-    assetKeys,
-    refreshFlags
+  adminProposeProtocolAdmin = ({
+    expiry,
+    newAdmin
   }: {
-    assetKeys: string[][];
-    refreshFlags?: boolean;
+    expiry?: Expiration;
+    newAdmin: string;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -277,67 +489,69 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          admin_remove_assets: {
-            asset_keys: assetKeys,
-            refresh_flags: refreshFlags
+          admin_propose_protocol_admin: {
+            expiry,
+            new_admin: newAdmin
           }
         })),
         funds: _funds
       })
     };
   };
-  adminManageReservedUsernames = ({
-    addUsernames,
-    removeUsernames
-  }: {
-    addUsernames?: ReservedUsername[];
-    removeUsernames?: string[];
-  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+  adminAcceptProtocolAdmin = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
       value: MsgExecuteContract.fromPartial({
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          admin_manage_reserved_usernames: {
-            add_usernames: addUsernames,
-            remove_usernames: removeUsernames
-          }
+          admin_accept_protocol_admin: {}
         })),
         funds: _funds
       })
     };
   };
-  pause = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+  adminCancelProtocolAdmin = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
       value: MsgExecuteContract.fromPartial({
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          pause: {}
+          admin_cancel_protocol_admin: {}
         })),
         funds: _funds
       })
     };
   };
-  unpause = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+  adminRemoveAssets = ({
+    assetKeys,
+    refreshFlags
+  }: {
+    assetKeys: string[][];
+    refreshFlags?: boolean;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
       value: MsgExecuteContract.fromPartial({
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          unpause: {}
+          admin_remove_assets: {
+            asset_keys: assetKeys,
+            refresh_flags: refreshFlags
+          }
         })),
         funds: _funds
       })
     };
   };
-  setPausers = ({
-    pausers
+  adminManageModerators = ({
+    addModerators,
+    removeModerators
   }: {
-    pausers: string[];
+    addModerators?: string[];
+    removeModerators?: string[];
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -345,28 +559,19 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          set_pausers: {
-            pausers
+          admin_manage_moderators: {
+            add_moderators: addModerators,
+            remove_moderators: removeModerators
           }
         })),
         funds: _funds
       })
     };
   };
-  assetPublish = ({
-    assetSource,
-    channelId,
-    isVisible,
-    metadata,
-    playlistId,
-    salt
+  adminHideAssets = ({
+    assetKeys
   }: {
-    assetSource: AssetSource;
-    channelId: string;
-    isVisible: boolean;
-    metadata: AssetMetadata;
-    playlistId?: string;
-    salt: Binary;
+    assetKeys: string[][];
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -374,25 +579,22 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          asset_publish: {
-            asset_source: assetSource,
-            channel_id: channelId,
-            is_visible: isVisible,
-            metadata,
-            playlist_id: playlistId,
-            salt
+          admin_hide_assets: {
+            asset_keys: assetKeys
           }
         })),
         funds: _funds
       })
     };
   };
-  assetUnpublish = ({
+  adminSuspendChannel = ({
     channelId,
-    publishId
+    reason,
+    until
   }: {
     channelId: string;
-    publishId: string;
+    reason: string;
+    until?: Timestamp;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -400,31 +602,20 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          asset_unpublish: {
+          admin_suspend_channel: {
             channel_id: channelId,
-            publish_id: publishId
+            reason,
+            until
           }
         })),
         funds: _funds
       })
     };
   };
-  assetUpdateDetails = ({
-    channelId,
-    description,
-    isVisible,
-    mediaUri,
-    name,
-    publishId,
-    thumbnailUri
+  adminUnsuspendChannel = ({
+    channelId
   }: {
     channelId: string;
-    description?: string;
-    isVisible?: boolean;
-    mediaUri?: string;
-    name?: string;
-    publishId: string;
-    thumbnailUri?: string;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -432,30 +623,20 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          asset_update_details: {
-            channel_id: channelId,
-            description,
-            is_visible: isVisible,
-            media_uri: mediaUri,
-            name,
-            publish_id: publishId,
-            thumbnail_uri: thumbnailUri
+          admin_unsuspend_channel: {
+            channel_id: channelId
           }
         })),
         funds: _funds
       })
     };
   };
-  assetFlag = ({
-    channelId,
-    flag,
-    interactiveVideoId,
-    publishId
+  adminResetFlags = ({
+    assetKeys,
+    flags
   }: {
-    channelId: string;
-    flag: Flag;
-    interactiveVideoId?: string;
-    publishId: string;
+    assetKeys: string[][];
+    flags?: Flag[];
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -463,25 +644,19 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          asset_flag: {
-            channel_id: channelId,
-            flag,
-            interactive_video_id: interactiveVideoId,
-            publish_id: publishId
+          admin_reset_flags: {
+            asset_keys: assetKeys,
+            flags
           }
         })),
         funds: _funds
       })
     };
   };
-  playlistCreate = ({
-    channelId,
-    playlistName,
-    salt
+  adminClearAllFlags = ({
+    limit
   }: {
-    channelId: string;
-    playlistName: string;
-    salt: Binary;
+    limit?: number;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -489,22 +664,18 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          playlist_create: {
-            channel_id: channelId,
-            playlist_name: playlistName,
-            salt
+          admin_clear_all_flags: {
+            limit
           }
         })),
         funds: _funds
       })
     };
   };
-  playlistDelete = ({
-    channelId,
-    playlistId
+  adminSetFlagThresholds = ({
+    thresholds
   }: {
-    channelId: string;
-    playlistId: string;
+    thresholds: FlagThreshold[];
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -512,25 +683,18 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          playlist_delete: {
-            channel_id: channelId,
-            playlist_id: playlistId
+          admin_set_flag_thresholds: {
+            thresholds
           }
         })),
         funds: _funds
       })
     };
   };
-  playlistAddAsset = ({
-    assetChannelId,
-    channelId,
-    playlistId,
-    publishId
+  adminRestoreAssets = ({
+    assetKeys
   }: {
-    assetChannelId: string;
-    channelId: string;
-    playlistId: string;
-    publishId: string;
+    assetKeys: string[][];
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -538,25 +702,20 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          playlist_add_asset: {
-            asset_channel_id: assetChannelId,
-            channel_id: channelId,
-            playlist_id: playlistId,
-            publish_id: publishId
+          admin_restore_assets: {
+            asset_keys: assetKeys
           }
         })),
         funds: _funds
       })
     };
   };
-  playlistRemoveAsset = ({
-    channelId,
-    playlistId,
-    publishId
+  adminManageReservedUsernames = ({
+    addUsernames,
+    removeUsernames
   }: {
-    channelId: string;
-    playlistId: string;
-    publishId: string;
+    addUsernames?: ReservedUsername[];
+    removeUsernames?: string[];
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -564,19 +723,396 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          playlist_remove_asset: {
-            channel_id: channelId,
-            playlist_id: playlistId,
-            publish_id: publishId
+          admin_manage_reserved_usernames: {
+            add_usernames: addUsernames,
+            remove_usernames: removeUsernames
           }
         })),
         funds: _funds
       })
     };
   };
-  playlistRefresh = ({
-    channelId,
-    playlistId
+  adminManageTipDenoms = ({
+    addTipDenoms,
+    removeTipDenoms
+  }: {
+    addTipDenoms?: TipDenom[];
+    removeTipDenoms?: string[];
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          admin_manage_tip_denoms: {
+            add_tip_denoms: addTipDenoms,
+            remove_tip_denoms: removeTipDenoms
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  pause = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          pause: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
+  unpause = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          unpause: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
+  setPausers = ({
+    pausers
+  }: {
+    pausers: string[];
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_pausers: {
+            pausers
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  assetPublish = ({
+    accessPolicy,
+    assetSource,
+    channelId,
+    isVisible,
+    metadata,
+    playlistId,
+    price,
+    publishAt,
+    salt
+  }: {
+    accessPolicy?: AccessPolicy;
+    assetSource: AssetSource;
+    channelId: string;
+    isVisible: boolean;
+    metadata: AssetMetadata;
+    playlistId?: string;
+    price?: Coin;
+    publishAt?: Timestamp;
+    salt: Binary;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          asset_publish: {
+            access_policy: accessPolicy,
+            asset_source: assetSource,
+            channel_id: channelId,
+            is_visible: isVisible,
+            metadata,
+            playlist_id: playlistId,
+            price,
+            publish_at: publishAt,
+            salt
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  assetUnpublish = ({
+    channelId,
+    publishId
+  }: {
+    channelId: string;
+    publishId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          asset_unpublish: {
+            channel_id: channelId,
+            publish_id: publishId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  assetUpdateDetails = ({
+    accessPolicy,
+    channelId,
+    clearPublishAt,
+    description,
+    isVisible,
+    mediaUri,
+    name,
+    publishAt,
+    publishId,
+    thumbnailUri
+  }: {
+    accessPolicy?: AccessPolicy;
+    channelId: string;
+    clearPublishAt?: boolean;
+    description?: string;
+    isVisible?: boolean;
+    mediaUri?: string;
+    name?: string;
+    publishAt?: Timestamp;
+    publishId: string;
+    thumbnailUri?: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          asset_update_details: {
+            access_policy: accessPolicy,
+            channel_id: channelId,
+            clear_publish_at: clearPublishAt,
+            description,
+            is_visible: isVisible,
+            media_uri: mediaUri,
+            name,
+            publish_at: publishAt,
+            publish_id: publishId,
+            thumbnail_uri: thumbnailUri
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  assetSetPrice = ({
+    channelId,
+    price,
+    publishId
+  }: {
+    channelId: string;
+    price?: Coin;
+    publishId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          asset_set_price: {
+            channel_id: channelId,
+            price,
+            publish_id: publishId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  assetPurchase = ({
+    channelId,
+    publishId
+  }: {
+    channelId: string;
+    publishId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          asset_purchase: {
+            channel_id: channelId,
+            publish_id: publishId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  assetFlag = ({
+    channelId,
+    flag,
+    interactiveVideoId,
+    publishId
+  }: {
+    channelId: string;
+    flag: Flag;
+    interactiveVideoId?: string;
+    publishId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          asset_flag: {
+            channel_id: channelId,
+            flag,
+            interactive_video_id: interactiveVideoId,
+            publish_id: publishId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  assetRetractFlag = ({
+    channelId,
+    flag,
+    publishId
+  }: {
+    channelId: string;
+    flag: Flag;
+    publishId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          asset_retract_flag: {
+            channel_id: channelId,
+            flag,
+            publish_id: publishId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  playlistCreate = ({
+    channelId,
+    playlistName,
+    salt
+  }: {
+    channelId: string;
+    playlistName: string;
+    salt: Binary;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          playlist_create: {
+            channel_id: channelId,
+            playlist_name: playlistName,
+            salt
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  playlistDelete = ({
+    channelId,
+    playlistId
+  }: {
+    channelId: string;
+    playlistId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          playlist_delete: {
+            channel_id: channelId,
+            playlist_id: playlistId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  playlistAddAsset = ({
+    assetChannelId,
+    channelId,
+    playlistId,
+    publishId
+  }: {
+    assetChannelId: string;
+    channelId: string;
+    playlistId: string;
+    publishId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          playlist_add_asset: {
+            asset_channel_id: assetChannelId,
+            channel_id: channelId,
+            playlist_id: playlistId,
+            publish_id: publishId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  playlistRemoveAsset = ({
+    channelId,
+    playlistId,
+    publishId
+  }: {
+    channelId: string;
+    playlistId: string;
+    publishId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          playlist_remove_asset: {
+            channel_id: channelId,
+            playlist_id: playlistId,
+            publish_id: publishId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  playlistRefresh = ({
+    channelId,
+    playlistId
   }: {
     channelId: string;
     playlistId: string;
@@ -652,6 +1188,50 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
       })
     };
   };
+  channelCleanup = ({
+    channelId,
+    limit
+  }: {
+    channelId: string;
+    limit?: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          channel_cleanup: {
+            channel_id: channelId,
+            limit
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  channelChangeUsername = ({
+    channelId,
+    newUserName
+  }: {
+    channelId: string;
+    newUserName: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          channel_change_username: {
+            channel_id: channelId,
+            new_user_name: newUserName
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
   channelUpdateDetails = ({
     bannerPicture,
     channelId,
@@ -686,12 +1266,47 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
       })
     };
   };
+  channelSetPayoutMode = ({
+    channelId,
+    payoutMode
+  }: {
+    channelId: string;
+    payoutMode: PayoutMode;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          channel_set_payout_mode: {
+            channel_id: channelId,
+            payout_mode: payoutMode
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  withdrawEarnings = (_funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          withdraw_earnings: {}
+        })),
+        funds: _funds
+      })
+    };
+  };
   channelTip = ({
     amount,
     assetId,
     channelId
   }: {
-    amount: Coin;
+    amount: Coin[];
     assetId?: string;
     channelId: string;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
@@ -711,14 +1326,82 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
       })
     };
   };
-  channelAddCollaborator = ({
+  channelInviteCollaborator = ({
     channelId,
     collaboratorAddress,
-    collaboratorDetails
+    collaboratorDetails,
+    expiry
   }: {
     channelId: string;
     collaboratorAddress: string;
     collaboratorDetails: ChannelCollaborator;
+    expiry?: Expiration;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          channel_invite_collaborator: {
+            channel_id: channelId,
+            collaborator_address: collaboratorAddress,
+            collaborator_details: collaboratorDetails,
+            expiry
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  channelAcceptCollaboratorInvite = ({
+    channelId
+  }: {
+    channelId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          channel_accept_collaborator_invite: {
+            channel_id: channelId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  channelDeclineCollaboratorInvite = ({
+    channelId
+  }: {
+    channelId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          channel_decline_collaborator_invite: {
+            channel_id: channelId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  channelUpdateCollaborator = ({
+    channelId,
+    collaboratorAddress,
+    role,
+    share
+  }: {
+    channelId: string;
+    collaboratorAddress: string;
+    role?: Role;
+    share?: Decimal;
   }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -726,10 +1409,11 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          channel_add_collaborator: {
+          channel_update_collaborator: {
             channel_id: channelId,
             collaborator_address: collaboratorAddress,
-            collaborator_details: collaboratorDetails
+            role,
+            share
           }
         })),
         funds: _funds
@@ -758,6 +1442,63 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
       })
     };
   };
+  channelLeaveCollaboration = ({
+    channelId
+  }: {
+    channelId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          channel_leave_collaboration: {
+            channel_id: channelId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  channelPruneCollaborators = ({
+    channelId
+  }: {
+    channelId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          channel_prune_collaborators: {
+            channel_id: channelId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  channelSyncOwner = ({
+    channelId
+  }: {
+    channelId: string;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          channel_sync_owner: {
+            channel_id: channelId
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
   channelFollow = ({
     channelId
   }: {
@@ -796,4 +1537,48 @@ export class OmniFlixChannelMsgComposer implements OmniFlixChannelMsg {
       })
     };
   };
+  channelSetSubscriptionPlan = ({
+    channelId,
+    plan
+  }: {
+    channelId: string;
+    plan?: SubscriptionPlan;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          channel_set_subscription_plan: {
+            channel_id: channelId,
+            plan
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
+  channelSubscribe = ({
+    channelId,
+    periods
+  }: {
+    channelId: string;
+    periods: number;
+  }, _funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          channel_subscribe: {
+            channel_id: channelId,
+            periods
+          }
+        })),
+        funds: _funds
+      })
+    };
+  };
 }
\ No newline at end of file
@@ -48,18 +48,70 @@ export type ExecuteMsg = {
   admin_set_config: {
     channel_creation_fee?: Coin[] | null;
     fee_collector?: string | null;
-    protocol_admin?: string | null;
+    tip_fee_rate?: Decimal | null;
+    username_change_fee?: Coin[] | null;
+    username_hold_period?: number | null;
   };
+} | {
+  admin_propose_protocol_admin: {
+    expiry?: Expiration | null;
+    new_admin: string;
+  };
+} | {
+  admin_accept_protocol_admin: {};
+} | {
+  admin_cancel_protocol_admin: {};
 } | {
   admin_remove_assets: {
     asset_keys: [string, string][];
     refresh_flags?: boolean | null;
   };
+} | {
+  admin_manage_moderators: {
+    add_moderators?: string[] | null;
+    remove_moderators?: string[] | null;
+  };
+} | {
+  admin_hide_assets: {
+    asset_keys: [string, string][];
+  };
+} | {
+  admin_suspend_channel: {
+    channel_id: string;
+    reason: string;
+    until?: Timestamp | null;
+  };
+} | {
+  admin_unsuspend_channel: {
+    channel_id: string;
+  };
+} | {
+  admin_reset_flags: {
+    asset_keys: [string, string][];
+    flags?: Flag[] | null;
+  };
+} | {
+  admin_clear_all_flags: {
+    limit?: number | null;
+  };
+} | {
+  admin_set_flag_thresholds: {
+    thresholds: FlagThreshold[];
+  };
+} | {
+  admin_restore_assets: {
+    asset_keys: [string, string][];
+  };
 } | {
   admin_manage_reserved_usernames: {
     add_usernames?: ReservedUsername[] | null;
     remove_usernames?: string[] | null;
   };
+} | {
+  admin_manage_tip_denoms: {
+    add_tip_denoms?: TipDenom[] | null;
+    remove_tip_denoms?: string[] | null;
+  };
 } | {
   pause: {};
 } | {
@@ -70,11 +122,14 @@ export type ExecuteMsg = {
   };
 } | {
   asset_publish: {
+    access_policy?: AccessPolicy | null;
     asset_source: AssetSource;
     channel_id: string;
     is_visible: boolean;
     metadata: AssetMetadata;
     playlist_id?: string | null;
+    price?: Coin | null;
+    publish_at?: Timestamp | null;
     salt: Binary;
   };
 } | {
@@ -84,14 +139,28 @@ export type ExecuteMsg = {
   };
 } | {
   asset_update_details: {
+    access_policy?: AccessPolicy | null;
     channel_id: string;
+    clear_publish_at?: boolean | null;
     description?: string | null;
     is_visible?: boolean | null;
     media_uri?: string | null;
     name?: string | null;
+    publish_at?: Timestamp | null;
     publish_id: string;
     thumbnail_uri?: string | null;
   };
+} | {
+  asset_set_price: {
+    channel_id: string;
+    price?: Coin | null;
+    publish_id: string;
+  };
+} | {
+  asset_purchase: {
+    channel_id: string;
+    publish_id: string;
+  };
 } | {
   asset_flag: {
     channel_id: string;
@@ -99,6 +168,12 @@ export type ExecuteMsg = {
     interactive_video_id?: string | null;
     publish_id: string;
   };
+} | {
+  asset_retract_flag: {
+    channel_id: string;
+    flag: Flag;
+    publish_id: string;
+  };
 } | {
   playlist_create: {
     channel_id: string;
@@ -142,6 +217,16 @@ export type ExecuteMsg = {
   channel_delete: {
     channel_id: string;
   };
+} | {
+  channel_cleanup: {
+    channel_id: string;
+    limit?: number | null;
+  };
+} | {
+  channel_change_username: {
+    channel_id: string;
+    new_user_name: string;
+  };
 } | {
   channel_update_details: {
     banner_picture?: string | null;
@@ -151,23 +236,58 @@ export type ExecuteMsg = {
     payment_address?: string | null;
     profile_picture?: string | null;
   };
+} | {
+  channel_set_payout_mode: {
+    channel_id: string;
+    payout_mode: PayoutMode;
+  };
+} | {
+  withdraw_earnings: {};
 } | {
   channel_tip: {
-    amount: Coin;
+    amount: Coin[];
     asset_id?: string | null;
     channel_id: string;
   };
 } | {
-  channel_add_collaborator: {
+  channel_invite_collaborator: {
     channel_id: string;
     collaborator_address: string;
     collaborator_details: ChannelCollaborator;
+    expiry?: Expiration | null;
+  };
+} | {
+  channel_accept_collaborator_invite: {
+    channel_id: string;
+  };
+} | {
+  channel_decline_collaborator_invite: {
+    channel_id: string;
+  };
+} | {
+  channel_update_collaborator: {
+    channel_id: string;
+    collaborator_address: string;
+    role?: Role | null;
+    share?: Decimal | null;
   };
 } | {
   channel_remove_collaborator: {
     channel_id: string;
     collaborator_address: string;
   };
+} | {
+  channel_leave_collaboration: {
+    channel_id: string;
+  };
+} | {
+  channel_prune_collaborators: {
+    channel_id: string;
+  };
+} | {
+  channel_sync_owner: {
+    channel_id: string;
+  };
 } | {
   channel_follow: {
     channel_id: string;
@@ -176,6 +296,34 @@ export type ExecuteMsg = {
   channel_unfollow: {
     channel_id: string;
   };
+} | {
+  channel_set_subscription_plan: {
+    channel_id: string;
+    plan?: SubscriptionPlan | null;
+  };
+} | {
+  channel_subscribe: {
+    channel_id: string;
+    periods: number;
+  };
+};
+export type Decimal = string;
+export type Expiration = {
+  at_height: number;
+} | {
+  at_time: Timestamp;
+} | {
+  never: {};
+};
+export type Timestamp = Uint64;
+export type Uint64 = string;
+export type Flag = ("n_s_f_w" | "explicit" | "spam" | "hateful") | {
+  other: string;
+};
+export type AccessPolicy = "public" | "followers" | "subscribers" | {
+  collection_holders: {
+    collection_id: string;
+  };
 };
 export type AssetSource = {
   nft: {
@@ -186,11 +334,16 @@ export type AssetSource = {
   off_chain: {};
 };
 export type Binary = string;
-export type Flag = ("n_s_f_w" | "explicit" | "spam" | "hateful") | {
-  other: string;
-};
+export type PayoutMode = "push" | "pull";
 export type Role = "admin" | "publisher" | "moderator";
-export type Decimal = string;
+export interface FlagThreshold {
+  flag: Flag;
+  threshold: number;
+}
+export interface TipDenom {
+  denom: string;
+  min_amount?: Uint128 | null;
+}
 export interface AssetMetadata {
   description: string;
   media_uri: string;
@@ -198,9 +351,14 @@ export interface AssetMetadata {
   thumbnail_uri?: string | null;
 }
 export interface ChannelCollaborator {
+  expires_at?: Timestamp | null;
   role: Role;
   share: Decimal;
 }
+export interface SubscriptionPlan {
+  period: number;
+  price: Coin;
+}
 export type QueryMsg = {
   is_paused: {};
 } | {
@@ -239,6 +397,10 @@ export type QueryMsg = {
   };
 } | {
   config: {};
+} | {
+  accepted_tip_denoms: {};
+} | {
+  pending_protocol_admin: {};
 } | {
   assets: {
     channel_id: string;
@@ -250,6 +412,57 @@ export type QueryMsg = {
     channel_id: string;
     publish_id: string;
   };
+} | {
+  can_access_asset: {
+    address: string;
+    channel_id: string;
+    publish_id: string;
+  };
+} | {
+  scheduled_assets: {
+    channel_id: string;
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  flag_thresholds: {};
+} | {
+  review_queue: {
+    limit?: number | null;
+    start_after?: [string, string] | null;
+  };
+} | {
+  asset_flaggers: {
+    channel_id: string;
+    limit?: number | null;
+    publish_id: string;
+    start_after?: string | null;
+  };
+} | {
+  asset_flag_reports: {
+    channel_id: string;
+    limit?: number | null;
+    publish_id: string;
+    start_after?: [string, Flag] | null;
+  };
+} | {
+  has_purchased: {
+    address: string;
+    channel_id: string;
+    publish_id: string;
+  };
+} | {
+  asset_buyers: {
+    channel_id: string;
+    limit?: number | null;
+    publish_id: string;
+    start_after?: string | null;
+  };
+} | {
+  moderators: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
 } | {
   reserved_usernames: {
     limit?: number | null;
@@ -266,6 +479,12 @@ export type QueryMsg = {
     limit?: number | null;
     start_after?: string | null;
   };
+} | {
+  collaborator_invites: {
+    address: string;
+    limit?: number | null;
+    start_after?: string | null;
+  };
 } | {
   followers_count: {
     channel_id: string;
@@ -276,23 +495,113 @@ export type QueryMsg = {
     limit?: number | null;
     start_after?: string | null;
   };
+} | {
+  subscription_plan: {
+    channel_id: string;
+  };
+} | {
+  is_subscribed: {
+    address: string;
+    channel_id: string;
+  };
+} | {
+  subscribers: {
+    channel_id: string;
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  following: {
+    address: string;
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  is_following: {
+    address: string;
+    channel_id: string;
+  };
+} | {
+  channels_by_owner: {
+    address: string;
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  channel_cleanup_progress: {
+    channel_id: string;
+  };
+} | {
+  pending_earnings: {
+    address: string;
+  };
+} | {
+  channel_tip_stats: {
+    channel_id: string;
+  };
+} | {
+  asset_tip_stats: {
+    channel_id: string;
+    publish_id: string;
+  };
+} | {
+  tipper_totals: {
+    channel_id: string;
+    tipper: string;
+  };
+} | {
+  top_tippers: {
+    channel_id: string;
+    denom: string;
+    limit?: number | null;
+    start_after?: string | null;
+  };
 };
+export interface MigrateMsg {}
+export type ArrayOfTipDenom = TipDenom[];
 export interface AssetResponse {
   asset: Asset;
   flags: FlagInfo[];
   metadata: AssetMetadata;
 }
 export interface Asset {
+  access_policy?: AccessPolicy;
   asset_source: AssetSource;
   channel_id: string;
   is_visible: boolean;
+  price?: Coin | null;
+  publish_at?: Timestamp | null;
   publish_id: string;
+  under_review?: boolean;
 }
 export interface FlagInfo {
   count: number;
   flag: Flag;
 }
+export type ArrayOfPurchaseReceipt = PurchaseReceipt[];
+export interface PurchaseReceipt {
+  buyer: Addr;
+  price: Coin;
+  purchased_at: Timestamp;
+}
+export type ArrayOfFlagReport = FlagReport[];
+export interface FlagReport {
+  flag: Flag;
+  interactive_video_id?: string | null;
+  reported_at: Timestamp;
+  reporter: Addr;
+}
+export type ArrayOfFlaggerInfo = FlaggerInfo[];
+export interface FlaggerInfo {
+  flagger: Addr;
+  flags: Flag[];
+}
+export interface TipStats {
+  tip_count: number;
+  total: Coin[];
+}
 export type ArrayOfAssetResponse = AssetResponse[];
+export type Boolean = boolean;
 export interface ChannelResponse {
   banner_picture?: string | null;
   channel_id: string;
@@ -302,14 +611,28 @@ export interface ChannelResponse {
   follower_count: number;
   onft_id: string;
   payment_address: string;
+  payout_mode: PayoutMode;
   profile_picture?: string | null;
+  suspension?: ChannelSuspension | null;
   user_name: string;
 }
 export interface CollaboratorInfo {
   address: string;
+  expires_at?: Timestamp | null;
   role: string;
   share: Decimal;
 }
+export interface ChannelSuspension {
+  reason: string;
+  suspended_at: Timestamp;
+  until?: Timestamp | null;
+}
+export type NullableChannelCleanupProgress = ChannelCleanupProgress | null;
+export type CleanupStage = "collaborators" | "collaborator_invites" | "followers" | "subscriptions" | "username_holds" | "assets" | "playlists" | "tip_stats";
+export interface ChannelCleanupProgress {
+  removed_entries: number;
+  stage: CleanupStage;
+}
 export interface ChannelDetails {
   channel_id: string;
   onft_id: string;
@@ -324,24 +647,64 @@ export interface ChannelMetadata {
   profile_picture?: string | null;
 }
 export type ArrayOfChannelResponse = ChannelResponse[];
+export type ArrayOfAddressChannelInfo = AddressChannelInfo[];
+export type ChannelRelation = "owner" | {
+  collaborator: {
+    expires_at?: Timestamp | null;
+    role: string;
+    share: Decimal;
+  };
+};
+export interface AddressChannelInfo {
+  channel_id: string;
+  relation: ChannelRelation;
+  user_name: string;
+}
+export type ArrayOfCollaboratorInviteInfo = CollaboratorInviteInfo[];
+export interface CollaboratorInviteInfo {
+  channel_id: string;
+  expiry?: Expiration | null;
+  role: string;
+  share: Decimal;
+}
 export interface ChannelConractConfig {
   accepted_tip_denoms: string[];
   auth_details: AuthDetails;
   channel_creation_fee: Coin[];
   channels_collection_id: string;
+  tip_fee_rate?: Decimal;
+  username_change_fee?: Coin[];
+  username_hold_period?: number;
 }
 export interface AuthDetails {
   fee_collector: Addr;
   protocol_admin: Addr;
 }
+export type ArrayOfFlagThreshold = FlagThreshold[];
 export type ArrayOfString = string[];
-export type Uint64 = number;
 export type ArrayOfCollaboratorInfo = CollaboratorInfo[];
-export type Boolean = boolean;
+export type ArrayOfAddr = Addr[];
+export type ArrayOfCoin = Coin[];
+export type NullablePendingProtocolAdmin = PendingProtocolAdmin | null;
+export interface PendingProtocolAdmin {
+  address: Addr;
+  expiry?: Expiration | null;
+}
 export interface Playlist {
   assets: [string, string][];
   playlist_id: string;
   playlist_name: string;
 }
 export type ArrayOfPlaylist = Playlist[];
-export type ArrayOfReservedUsername = ReservedUsername[];
\ No newline at end of file
+export type ArrayOfReservedUsername = ReservedUsername[];
+export type ArrayOfSubscriberInfo = SubscriberInfo[];
+export interface SubscriberInfo {
+  address: string;
+  expires_at: Timestamp;
+}
+export type NullableSubscriptionPlan = SubscriptionPlan | null;
+export type ArrayOfTipperInfo = TipperInfo[];
+export interface TipperInfo {
+  address: string;
+  amount: Coin;
+}
\ No newline at end of file
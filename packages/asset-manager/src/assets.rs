@@ -1,17 +1,33 @@
 use crate::error::AssetError;
-use cosmwasm_std::{Order, StdResult, Storage};
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Timestamp};
 use cw_storage_plus::{Bound, Map};
 
 use omniflix_channel_types::{
-    asset::{Asset, AssetKey, AssetMetadata, Flag, FlagKey, PublishId},
+    asset::{
+        Asset, AssetKey, AssetMetadata, Flag, FlagKey, FlagReport, PublishId, PurchaseReceipt,
+    },
     channel::ChannelId,
-    msg::{AssetResponse, FlagInfo},
+    msg::{AssetResponse, FlagInfo, FlaggerInfo},
 };
 
 pub struct AssetsManager {
     pub assets: Map<AssetKey, Asset>,
     pub asset_metadata: Map<AssetKey, AssetMetadata>,
+    /// Number of accounts that flagged an asset with each flag
     pub flags: Map<FlagKey, u64>,
+    /// Flag counts recorded before flags were deduplicated per account. Never read, only removed.
+    pub legacy_flags: Map<FlagKey, u64>,
+    /// Flags raised by each account on an asset, at most one of each kind
+    pub flaggers: Map<(AssetKey, Addr), Vec<Flag>>,
+    /// Reports of the flags raised on an asset, keyed by reporter and flag key
+    pub flag_reports: Map<(AssetKey, Addr, String), FlagReport>,
+    pub purchases: Map<(AssetKey, Addr), PurchaseReceipt>,
+    /// Assets with a scheduled publish time, keyed by channel, publish time in nanoseconds and publish ID
+    pub scheduled_assets: Map<(ChannelId, u64, PublishId), bool>,
+    /// Assets under review with the time they were put under review
+    pub review_queue: Map<AssetKey, Timestamp>,
+    /// Flags whose count had reached the threshold when the asset was restored from review
+    pub restored_flags: Map<FlagKey, bool>,
 }
 
 const PAGINATION_LIMIT: u32 = 50;
@@ -22,7 +38,14 @@ impl AssetsManager {
         AssetsManager {
             assets: Map::new("assets"),
             asset_metadata: Map::new("asset_metadata"),
-            flags: Map::new("flags"),
+            flags: Map::new("flag_counts"),
+            legacy_flags: Map::new("flags"),
+            flaggers: Map::new("flaggers"),
+            flag_reports: Map::new("flag_reports"),
+            purchases: Map::new("purchases"),
+            scheduled_assets: Map::new("scheduled_assets"),
+            review_queue: Map::new("review_queue"),
+            restored_flags: Map::new("restored_flags"),
         }
     }
 
@@ -44,10 +67,40 @@ impl AssetsManager {
         self.asset_metadata
             .save(store, key, &metadata)
             .map_err(|_| AssetError::SaveAssetMetadataError {})?;
+        self.index_schedule(store, &asset)
+            .map_err(|_| AssetError::SaveAssetError {})?;
 
         Ok(())
     }
 
+    fn index_schedule(&self, store: &mut dyn Storage, asset: &Asset) -> StdResult<()> {
+        if let Some(publish_at) = asset.publish_at {
+            self.scheduled_assets.save(
+                store,
+                (
+                    asset.channel_id.clone(),
+                    publish_at.nanos(),
+                    asset.publish_id.clone(),
+                ),
+                &true,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn unindex_schedule(&self, store: &mut dyn Storage, asset: &Asset) {
+        if let Some(publish_at) = asset.publish_at {
+            self.scheduled_assets.remove(
+                store,
+                (
+                    asset.channel_id.clone(),
+                    publish_at.nanos(),
+                    asset.publish_id.clone(),
+                ),
+            );
+        }
+    }
+
     /// Retrieve an asset by its key.
     pub fn get_asset(&self, store: &dyn Storage, key: AssetKey) -> Result<Asset, AssetError> {
         self.assets
@@ -72,21 +125,28 @@ impl AssetsManager {
         keys: Vec<AssetKey>,
     ) -> Result<(), AssetError> {
         for key in keys {
-            if self.assets.load(store, key.clone()).is_err() {
-                return Err(AssetError::AssetNotFound {});
-            }
+            let asset = self
+                .assets
+                .load(store, key.clone())
+                .map_err(|_| AssetError::AssetNotFound {})?;
+            self.unindex_schedule(store, &asset);
             self.assets.remove(store, key.clone());
-            self.asset_metadata.remove(store, key);
+            self.asset_metadata.remove(store, key.clone());
+            self.review_queue.remove(store, key.clone());
+            self.delete_flags(store, key);
         }
         Ok(())
     }
 
+    /// Returns the assets of a channel, leaving out the assets under review.
+    /// Assets scheduled after `now` are reported as not visible.
     pub fn get_all_assets(
         &self,
         store: &dyn Storage,
         channel_id: ChannelId,
         start_after: Option<PublishId>,
         limit: Option<u32>,
+        now: Timestamp,
     ) -> StdResult<Vec<AssetResponse>> {
         let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
         let start = start_after.map(Bound::exclusive);
@@ -95,33 +155,84 @@ impl AssetsManager {
             .assets
             .prefix(channel_id.clone())
             .range(store, start, None, Order::Ascending)
+            .filter(|result| !matches!(result, Ok((_, asset)) if asset.under_review))
             .take(limit)
-            .map(|result| {
-                result.map(|(_, asset)| {
-                    let flags = self
-                        .get_all_flags_for_asset(
-                            store,
-                            channel_id.clone(),
-                            asset.publish_id.clone(),
-                        )
-                        .unwrap_or_default();
-                    let metadata = self
-                        .get_asset_metadata(store, (channel_id.clone(), asset.publish_id.clone()))
-                        .unwrap_or_default();
-
-                    AssetResponse {
-                        asset: asset.clone(),
-                        flags,
-                        metadata,
-                    }
-                })
-            })
+            .map(|result| result.map(|(_, asset)| self.build_asset_response(store, asset, now)))
             .collect::<Result<Vec<_>, _>>()
             .unwrap_or_default();
 
         Ok(assets)
     }
 
+    /// Returns the visible assets of a channel that are scheduled after `now`,
+    /// ordered by their publish time.
+    pub fn get_scheduled_assets(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+        start_after: Option<PublishId>,
+        limit: Option<u32>,
+        now: Timestamp,
+    ) -> StdResult<Vec<AssetResponse>> {
+        let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+
+        // Start after the given asset, unless it is no longer upcoming
+        let upcoming = (now.nanos() + 1, PublishId::new());
+        let cursor = start_after
+            .and_then(|publish_id| {
+                self.assets
+                    .may_load(store, (channel_id.clone(), publish_id.clone()))
+                    .ok()
+                    .flatten()
+                    .and_then(|asset| asset.publish_at)
+                    .map(|publish_at| (publish_at.nanos(), publish_id))
+            })
+            .filter(|cursor| *cursor >= upcoming);
+        let start = match cursor {
+            Some(cursor) => Bound::exclusive(cursor),
+            None => Bound::inclusive(upcoming),
+        };
+
+        let assets = self
+            .scheduled_assets
+            .sub_prefix(channel_id.clone())
+            .keys(store, Some(start), None, Order::Ascending)
+            .filter_map(|key| {
+                let (_, publish_id) = key.ok()?;
+                self.assets
+                    .may_load(store, (channel_id.clone(), publish_id))
+                    .ok()
+                    .flatten()
+            })
+            .filter(|asset| asset.is_visible && !asset.under_review)
+            .take(limit)
+            .map(|asset| self.build_asset_response(store, asset, now))
+            .collect();
+
+        Ok(assets)
+    }
+
+    fn build_asset_response(
+        &self,
+        store: &dyn Storage,
+        mut asset: Asset,
+        now: Timestamp,
+    ) -> AssetResponse {
+        let flags = self
+            .get_all_flags_for_asset(store, asset.channel_id.clone(), asset.publish_id.clone())
+            .unwrap_or_default();
+        let metadata = self
+            .get_asset_metadata(store, (asset.channel_id.clone(), asset.publish_id.clone()))
+            .unwrap_or_default();
+        asset.is_visible = asset.is_live(now);
+
+        AssetResponse {
+            asset,
+            flags,
+            metadata,
+        }
+    }
+
     /// Update an existing asset by its key.
     pub fn update_asset(
         &self,
@@ -129,13 +240,17 @@ impl AssetsManager {
         key: AssetKey,
         updated_asset: Asset,
     ) -> Result<(), AssetError> {
-        if !self.assets.has(store, key.clone()) {
-            return Err(AssetError::AssetNotFound {});
-        }
+        let asset = self
+            .assets
+            .load(store, key.clone())
+            .map_err(|_| AssetError::AssetNotFound {})?;
 
+        self.unindex_schedule(store, &asset);
         self.assets
             .save(store, key, &updated_asset)
             .map_err(|_| AssetError::SaveAssetError {})?;
+        self.index_schedule(store, &updated_asset)
+            .map_err(|_| AssetError::SaveAssetError {})?;
 
         Ok(())
     }
@@ -166,17 +281,236 @@ impl AssetsManager {
         self.asset_metadata
             .prefix(channel_id.clone())
             .clear(store, None);
+        self.scheduled_assets
+            .sub_prefix(channel_id.clone())
+            .clear(store, None);
+        self.review_queue
+            .prefix(channel_id.clone())
+            .clear(store, None);
+        Ok(())
+    }
+
+    /// Returns the publish IDs of up to `limit` assets of a channel
+    pub fn get_channel_publish_ids(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+        limit: usize,
+    ) -> StdResult<Vec<PublishId>> {
+        self.assets
+            .prefix(channel_id)
+            .keys(store, None, None, Order::Ascending)
+            .take(limit)
+            .collect()
+    }
+
+    /// Removes up to `limit` flagger records, flag reports and purchase receipts of an asset of a
+    /// deleted channel
+    ///
+    /// # Returns
+    /// * The number of removed entries
+    pub fn delete_asset_records(
+        &self,
+        store: &mut dyn Storage,
+        key: AssetKey,
+        limit: usize,
+    ) -> StdResult<usize> {
+        let flaggers = self
+            .flaggers
+            .prefix(key.clone())
+            .keys(store, None, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+        for flagger in flaggers.iter() {
+            self.flaggers.remove(store, (key.clone(), flagger.clone()));
+        }
+
+        let reports = self
+            .flag_reports
+            .sub_prefix(key.clone())
+            .keys(store, None, None, Order::Ascending)
+            .take(limit - flaggers.len())
+            .collect::<StdResult<Vec<_>>>()?;
+        for (reporter, flag_key) in reports.iter() {
+            self.flag_reports
+                .remove(store, (key.clone(), reporter.clone(), flag_key.clone()));
+        }
+        let removed = flaggers.len() + reports.len();
+
+        let buyers = self
+            .purchases
+            .prefix(key.clone())
+            .keys(store, None, None, Order::Ascending)
+            .take(limit - removed)
+            .collect::<StdResult<Vec<_>>>()?;
+        for buyer in buyers.iter() {
+            self.purchases.remove(store, (key.clone(), buyer.clone()));
+        }
+        Ok(removed + buyers.len())
+    }
+
+    /// Removes an asset of a deleted channel with its metadata and flag counts.
+    /// Its records must be removed first with `delete_asset_records`.
+    pub fn delete_channel_asset(&self, store: &mut dyn Storage, key: AssetKey) -> StdResult<()> {
+        if let Some(asset) = self.assets.may_load(store, key.clone())? {
+            self.unindex_schedule(store, &asset);
+        }
+        self.assets.remove(store, key.clone());
+        self.asset_metadata.remove(store, key.clone());
+        self.review_queue.remove(store, key.clone());
+        self.delete_flag_counts(store, key);
+        Ok(())
+    }
+
+    /// Puts an asset under review or takes it out of review.
+    pub fn set_under_review(
+        &self,
+        store: &mut dyn Storage,
+        key: AssetKey,
+        under_review: bool,
+        now: Timestamp,
+    ) -> Result<(), AssetError> {
+        let mut asset = self.get_asset(store, key.clone())?;
+        if asset.under_review == under_review {
+            return Err(if under_review {
+                AssetError::AssetAlreadyUnderReview {}
+            } else {
+                AssetError::AssetNotUnderReview {}
+            });
+        }
+        asset.under_review = under_review;
+        self.assets
+            .save(store, key.clone(), &asset)
+            .map_err(|_| AssetError::SaveAssetError {})?;
+        if under_review {
+            self.review_queue
+                .save(store, key, &now)
+                .map_err(|_| AssetError::SaveAssetError {})?;
+        } else {
+            self.review_queue.remove(store, key);
+        }
+        Ok(())
+    }
+
+    /// Returns the assets under review of all channels, ordered by asset key.
+    pub fn get_review_queue(
+        &self,
+        store: &dyn Storage,
+        start_after: Option<AssetKey>,
+        limit: Option<u32>,
+        now: Timestamp,
+    ) -> StdResult<Vec<AssetResponse>> {
+        let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+
+        let assets = self
+            .review_queue
+            .keys(store, start, None, Order::Ascending)
+            .take(limit)
+            .filter_map(|key| self.assets.may_load(store, key.ok()?).ok().flatten())
+            .map(|asset| self.build_asset_response(store, asset, now))
+            .collect();
+
+        Ok(assets)
+    }
+
+    /// Removes the given kinds of flags from an asset, or all of its flags if no kinds are given.
+    /// The flags are also dropped from the records of the accounts that raised them.
+    pub fn reset_asset_flags(
+        &self,
+        store: &mut dyn Storage,
+        key: AssetKey,
+        flags: Option<Vec<Flag>>,
+    ) -> Result<(), AssetError> {
+        if !self.assets.has(store, key.clone()) {
+            return Err(AssetError::AssetNotFound {});
+        }
+        let flag_keys: Vec<String> = flags
+            .unwrap_or_else(Flag::values)
+            .iter()
+            .map(|flag| flag.to_key())
+            .collect();
+        for flag_key in flag_keys.iter() {
+            self.flags.remove(store, (flag_key.clone(), key.clone()));
+            self.restored_flags
+                .remove(store, (flag_key.clone(), key.clone()));
+        }
+
+        let records = self
+            .flaggers
+            .prefix(key.clone())
+            .range(store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .map_err(|_| AssetError::SaveFlagError {})?;
+        for (flagger, mut raised) in records {
+            for flag in raised.iter() {
+                if flag_keys.contains(&flag.to_key()) {
+                    self.flag_reports
+                        .remove(store, (key.clone(), flagger.clone(), flag.to_key()));
+                }
+            }
+            raised.retain(|flag| !flag_keys.contains(&flag.to_key()));
+            if raised.is_empty() {
+                self.flaggers.remove(store, (key.clone(), flagger));
+            } else {
+                self.flaggers
+                    .save(store, (key.clone(), flagger), &raised)
+                    .map_err(|_| AssetError::SaveFlagError {})?;
+            }
+        }
         Ok(())
     }
 
+    /// Removes the flag counts and flagger records of an asset
+    fn delete_flags(&self, store: &mut dyn Storage, key: AssetKey) {
+        self.delete_flag_counts(store, key.clone());
+        self.flaggers.prefix(key.clone()).clear(store, None);
+        self.flag_reports.sub_prefix(key).clear(store, None);
+    }
+
+    /// Removes the flag counts and restored markers of an asset
+    fn delete_flag_counts(&self, store: &mut dyn Storage, key: AssetKey) {
+        for flag in Flag::values() {
+            self.flags.remove(store, (flag.to_key(), key.clone()));
+            self.restored_flags
+                .remove(store, (flag.to_key(), key.clone()));
+        }
+    }
+
+    /// Records a flag raised by an account with its report.
+    /// An account can raise each kind of flag once per asset.
     pub fn add_flag(
         &self,
         store: &mut dyn Storage,
         channel_id: ChannelId,
         publish_id: PublishId,
-        flag: Flag,
+        report: FlagReport,
     ) -> Result<(), AssetError> {
-        let key = (flag.to_key(), (channel_id, publish_id));
+        let flag = report.flag.clone();
+        let flagger = report.reporter.clone();
+        let asset_key = (channel_id, publish_id);
+        if !self.assets.has(store, asset_key.clone()) {
+            return Err(AssetError::AssetNotFound {});
+        }
+
+        let flagger_key = (asset_key.clone(), flagger.clone());
+        let mut flags = self
+            .flaggers
+            .may_load(store, flagger_key.clone())
+            .map_err(|_| AssetError::SaveFlagError {})?
+            .unwrap_or_default();
+        if flags.iter().any(|f| f.to_key() == flag.to_key()) {
+            return Err(AssetError::AlreadyFlagged {});
+        }
+        flags.push(flag.clone());
+        self.flaggers
+            .save(store, flagger_key, &flags)
+            .map_err(|_| AssetError::SaveFlagError {})?;
+        self.flag_reports
+            .save(store, (asset_key.clone(), flagger, flag.to_key()), &report)
+            .map_err(|_| AssetError::SaveFlagError {})?;
+
+        let key = (flag.to_key(), asset_key);
         let flagged_count = self.flags.load(store, key.clone()).unwrap_or(0);
         // save the new count
         self.flags
@@ -185,6 +519,88 @@ impl AssetsManager {
         Ok(())
     }
 
+    /// Retracts a flag previously raised by an account.
+    pub fn remove_flag(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        publish_id: PublishId,
+        flag: Flag,
+        flagger: Addr,
+    ) -> Result<(), AssetError> {
+        let asset_key = (channel_id, publish_id);
+        let flagger_key = (asset_key.clone(), flagger.clone());
+        let mut flags = self
+            .flaggers
+            .may_load(store, flagger_key.clone())
+            .map_err(|_| AssetError::FlagNotFound {})?
+            .unwrap_or_default();
+        let len_before = flags.len();
+        flags.retain(|f| f.to_key() != flag.to_key());
+        if flags.len() == len_before {
+            return Err(AssetError::FlagNotFound {});
+        }
+        self.flag_reports
+            .remove(store, (asset_key.clone(), flagger, flag.to_key()));
+        if flags.is_empty() {
+            self.flaggers.remove(store, flagger_key);
+        } else {
+            self.flaggers
+                .save(store, flagger_key, &flags)
+                .map_err(|_| AssetError::SaveFlagError {})?;
+        }
+
+        let key = (flag.to_key(), asset_key);
+        let flagged_count = self.flags.load(store, key.clone()).unwrap_or(0);
+        if flagged_count <= 1 {
+            self.flags.remove(store, key);
+        } else {
+            self.flags
+                .save(store, key, &(flagged_count - 1))
+                .map_err(|_| AssetError::SaveFlagError {})?;
+        }
+        Ok(())
+    }
+
+    /// Returns the accounts that flagged an asset with their flags, ordered by address.
+    pub fn get_flaggers(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+        publish_id: PublishId,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<FlaggerInfo>> {
+        let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+
+        self.flaggers
+            .prefix((channel_id, publish_id))
+            .range(store, start, None, Order::Ascending)
+            .take(limit)
+            .map(|result| result.map(|(flagger, flags)| FlaggerInfo { flagger, flags }))
+            .collect()
+    }
+
+    /// Returns the flag reports of an asset, ordered by reporter address and flag key.
+    pub fn get_flag_reports(
+        &self,
+        store: &dyn Storage,
+        key: AssetKey,
+        start_after: Option<(Addr, String)>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<FlagReport>> {
+        let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+
+        self.flag_reports
+            .sub_prefix(key)
+            .range(store, start, None, Order::Ascending)
+            .take(limit)
+            .map(|result| result.map(|(_, report)| report))
+            .collect()
+    }
+
     pub fn get_flag_count(
         &self,
         store: &dyn Storage,
@@ -221,10 +637,156 @@ impl AssetsManager {
         }
         Ok(flags)
     }
-    pub fn remove_all_flags(&self, store: &mut dyn Storage) -> Result<(), AssetError> {
-        self.flags.clear(store);
+
+    /// Removes up to `limit` flag counts and flagger records of all assets.
+    ///
+    /// # Returns
+    /// * True once no flags are left
+    pub fn remove_flags_with_limit(
+        &self,
+        store: &mut dyn Storage,
+        limit: usize,
+    ) -> Result<bool, AssetError> {
+        let flag_keys = self
+            .flags
+            .keys(store, None, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()
+            .map_err(|_| AssetError::RemoveFlagsWithLimitError {})?;
+        for flag_key in flag_keys.iter() {
+            self.flags.remove(store, flag_key.clone());
+        }
+
+        let flagger_keys = self
+            .flaggers
+            .keys(store, None, None, Order::Ascending)
+            .take(limit - flag_keys.len())
+            .collect::<StdResult<Vec<_>>>()
+            .map_err(|_| AssetError::RemoveFlagsWithLimitError {})?;
+        let removed = flag_keys.len() + flagger_keys.len();
+        for flagger_key in flagger_keys {
+            self.flaggers.remove(store, flagger_key);
+        }
+
+        let report_keys = self
+            .flag_reports
+            .keys(store, None, None, Order::Ascending)
+            .take(limit - removed)
+            .collect::<StdResult<Vec<_>>>()
+            .map_err(|_| AssetError::RemoveFlagsWithLimitError {})?;
+        let removed = removed + report_keys.len();
+        for report_key in report_keys {
+            self.flag_reports.remove(store, report_key);
+        }
+
+        let restored_keys = self
+            .restored_flags
+            .keys(store, None, None, Order::Ascending)
+            .take(limit - removed)
+            .collect::<StdResult<Vec<_>>>()
+            .map_err(|_| AssetError::RemoveFlagsWithLimitError {})?;
+        let removed = removed + restored_keys.len();
+        for restored_key in restored_keys {
+            self.restored_flags.remove(store, restored_key);
+        }
+
+        self.remove_legacy_flags_with_limit(store, limit - removed)?;
+
+        Ok(self.flags.is_empty(store)
+            && self.flaggers.is_empty(store)
+            && self.flag_reports.is_empty(store)
+            && self.restored_flags.is_empty(store)
+            && self.legacy_flags.is_empty(store))
+    }
+
+    /// Removes up to `limit` of the flag counts recorded before flags were deduplicated.
+    ///
+    /// # Returns
+    /// * `true` if no legacy flag counts are left
+    pub fn remove_legacy_flags_with_limit(
+        &self,
+        store: &mut dyn Storage,
+        limit: usize,
+    ) -> Result<bool, AssetError> {
+        let legacy_keys = self
+            .legacy_flags
+            .keys(store, None, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()
+            .map_err(|_| AssetError::RemoveFlagsWithLimitError {})?;
+        for legacy_key in legacy_keys {
+            self.legacy_flags.remove(store, legacy_key);
+        }
+        Ok(self.legacy_flags.is_empty(store))
+    }
+
+    /// Marks or unmarks a flag of an asset as restored from review at its current count.
+    pub fn set_flag_restored(
+        &self,
+        store: &mut dyn Storage,
+        key: AssetKey,
+        flag: Flag,
+        restored: bool,
+    ) -> Result<(), AssetError> {
+        let flag_key = (flag.to_key(), key);
+        if restored {
+            self.restored_flags
+                .save(store, flag_key, &true)
+                .map_err(|_| AssetError::SaveFlagError {})?;
+        } else {
+            self.restored_flags.remove(store, flag_key);
+        }
         Ok(())
     }
+
+    pub fn is_flag_restored(&self, store: &dyn Storage, key: AssetKey, flag: Flag) -> bool {
+        self.restored_flags.has(store, (flag.to_key(), key))
+    }
+
+    /// Record a purchase, ensuring the buyer has not purchased the asset before.
+    pub fn add_purchase(
+        &self,
+        store: &mut dyn Storage,
+        key: AssetKey,
+        receipt: PurchaseReceipt,
+    ) -> Result<(), AssetError> {
+        let purchase_key = (key, receipt.buyer.clone());
+        if self.purchases.has(store, purchase_key.clone()) {
+            return Err(AssetError::AssetAlreadyPurchased {});
+        }
+        self.purchases
+            .save(store, purchase_key, &receipt)
+            .map_err(|_| AssetError::SavePurchaseError {})?;
+        Ok(())
+    }
+
+    pub fn get_purchase(
+        &self,
+        store: &dyn Storage,
+        key: AssetKey,
+        buyer: Addr,
+    ) -> StdResult<Option<PurchaseReceipt>> {
+        self.purchases.may_load(store, (key, buyer))
+    }
+
+    /// Returns the purchase receipts of an asset, ordered by buyer address.
+    pub fn get_buyers(
+        &self,
+        store: &dyn Storage,
+        key: AssetKey,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<PurchaseReceipt>> {
+        let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+
+        self.purchases
+            .prefix(key)
+            .range(store, start, None, Order::Ascending)
+            .take(limit)
+            .map(|result| result.map(|(_, receipt)| receipt))
+            .collect()
+    }
 }
 
 // #[cfg(test)]
@@ -59,6 +59,12 @@ pub enum AssetError {
     #[error("Error saving flag")]
     SaveFlagError {},
 
+    #[error("Asset already flagged with this flag")]
+    AlreadyFlagged {},
+
+    #[error("Flag not found")]
+    FlagNotFound {},
+
     #[error("Error removing flags with limit")]
     RemoveFlagsWithLimitError {},
 
@@ -67,4 +73,19 @@ pub enum AssetError {
 
     #[error("Asset metadata not found")]
     AssetMetadataNotFound {},
+
+    #[error("Asset is not for sale")]
+    AssetNotForSale {},
+
+    #[error("Asset already purchased")]
+    AssetAlreadyPurchased {},
+
+    #[error("Error saving purchase receipt")]
+    SavePurchaseError {},
+
+    #[error("Asset is already under review")]
+    AssetAlreadyUnderReview {},
+
+    #[error("Asset is not under review")]
+    AssetNotUnderReview {},
 }
@@ -191,6 +191,29 @@ impl PlaylistsManager {
         Ok(removed_asset_keys)
     }
 
+    /// Removes up to `limit` playlists of a deleted channel
+    ///
+    /// # Returns
+    /// * The number of removed entries
+    pub fn delete_channel_playlists(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        limit: usize,
+    ) -> StdResult<usize> {
+        let playlist_ids = self
+            .playlists
+            .prefix(channel_id.clone())
+            .keys(store, None, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+        for playlist_id in playlist_ids.iter() {
+            self.playlists
+                .remove(store, (channel_id.clone(), playlist_id.clone()));
+        }
+        Ok(playlist_ids.len())
+    }
+
     // Delete all playlists for a channel
     pub fn delete_playlists_by_channel_id(&self, store: &mut dyn Storage, channel_id: ChannelId) {
         self.playlists.prefix(channel_id).clear(store, None)
@@ -1,9 +1,14 @@
-use cosmwasm_std::{Addr, Decimal, Order, StdResult, Storage};
+use cosmwasm_std::{Addr, BlockInfo, Decimal, Order, StdResult, Storage, Timestamp};
 use cw_storage_plus::{Bound, Map};
 
 use crate::error::ChannelError;
+use crate::username::normalize_username;
 use omniflix_channel_types::{
-    channel::{ChannelCollaborator, ChannelDetails, ChannelId, ChannelMetadata, UserName},
+    channel::{
+        ChannelCleanupProgress, ChannelCollaborator, ChannelDetails, ChannelId, ChannelMetadata,
+        ChannelSuspension, CollaboratorInvite, PayoutMode, Role, SubscriptionPlan, UserName,
+        UsernameHold,
+    },
     msg::{CollaboratorInfo, ReservedUsername},
 };
 
@@ -12,25 +17,61 @@ const CHANNEL_METADATA: &str = "cm"; // channel_metadata
 const USERNAME_TO_CHANNEL_ID: &str = "u2i"; // username_to_channel_id
 const CHANNEL_ID_TO_USERNAME: &str = "i2u"; // channel_id_to_username
 const RESERVED_USERNAMES: &str = "ru"; // reserved_usernames
+const USERNAME_HOLDS: &str = "uh"; // username_holds
+const HOLDS_BY_CHANNEL: &str = "uhc"; // holds_by_channel
 const CHANNEL_COLLABORATORS: &str = "col"; // channel_collaborators
 const TOTAL_COLLABORATOR_SHARES: &str = "tcs"; // total_collaborator_shares
+const COLLABORATOR_INVITES: &str = "ci"; // collaborator_invites
+const INVITES_BY_ADDRESS: &str = "cia"; // invites_by_address
+const CHANNELS_BY_COLLABORATOR: &str = "cbc"; // channels_by_collaborator
+const CHANNEL_OWNER: &str = "cow"; // channel_owner
+const CHANNELS_BY_OWNER: &str = "cbo"; // channels_by_owner
 const TOTAL_UNIQUE_COLLABORATOR_LIMIT: u32 = 10;
 
 const FOLLOWERS: &str = "f"; // followers
 const FOLLOWERS_COUNT: &str = "fc"; // followers_count
+const FOLLOWING: &str = "fw"; // following
+const SUBSCRIPTION_PLANS: &str = "spl"; // subscription_plans
+const SUBSCRIPTIONS: &str = "sub"; // subscriptions
+const PAYOUT_MODE: &str = "pmo"; // payout_mode
+const CHANNEL_CLEANUPS: &str = "ccl"; // channel_cleanups
+const SUSPENSIONS: &str = "sus"; // suspensions
 
-const PAGINATION_LIMIT: u32 = 50;
+pub const PAGINATION_LIMIT: u32 = 50;
 
 pub struct ChannelsManager {
     pub channel_details: Map<ChannelId, ChannelDetails>,
     pub channel_metadata: Map<ChannelId, ChannelMetadata>,
+    /// Keyed by the normalized username, see `normalize_username`
     pub username_to_channel_id: Map<UserName, ChannelId>,
+    /// The username of each channel in its display casing
     pub channel_id_to_username: Map<ChannelId, UserName>,
+    /// Keyed by the normalized username
     pub reserved_usernames: Map<UserName, Option<Addr>>,
+    /// Normalized usernames released by a username change, held for their previous channel
+    pub username_holds: Map<UserName, UsernameHold>,
+    /// The held usernames of each channel, used to remove them with the channel
+    pub holds_by_channel: Map<(ChannelId, UserName), bool>,
     pub channel_collaborators: Map<(ChannelId, Addr), ChannelCollaborator>,
     pub total_collaborator_shares: Map<ChannelId, Decimal>,
+    pub collaborator_invites: Map<(ChannelId, Addr), CollaboratorInvite>,
+    pub invites_by_address: Map<(Addr, ChannelId), bool>,
+    pub channels_by_collaborator: Map<(Addr, ChannelId), bool>,
+    /// The last known owner of each channel ONFT. Ownership is verified against the ONFT module.
+    pub channel_owner: Map<ChannelId, Addr>,
+    pub channels_by_owner: Map<(Addr, ChannelId), bool>,
     pub followers: Map<(ChannelId, Addr), bool>,
     pub followers_count: Map<ChannelId, u64>,
+    /// Reverse index of `followers`, the channels followed by an address
+    pub following: Map<(Addr, ChannelId), bool>,
+    pub subscription_plans: Map<ChannelId, SubscriptionPlan>,
+    /// The time each subscription of a channel is paid until
+    pub subscriptions: Map<(ChannelId, Addr), Timestamp>,
+    pub payout_mode: Map<ChannelId, PayoutMode>,
+    /// Deleted channels whose state is not fully removed yet
+    pub channel_cleanups: Map<ChannelId, ChannelCleanupProgress>,
+    /// Channels suspended by the protocol admin or a protocol moderator
+    pub suspensions: Map<ChannelId, ChannelSuspension>,
 }
 #[allow(clippy::new_without_default)]
 impl ChannelsManager {
@@ -40,11 +81,24 @@ impl ChannelsManager {
             username_to_channel_id: Map::new(USERNAME_TO_CHANNEL_ID),
             channel_id_to_username: Map::new(CHANNEL_ID_TO_USERNAME),
             reserved_usernames: Map::new(RESERVED_USERNAMES),
+            username_holds: Map::new(USERNAME_HOLDS),
+            holds_by_channel: Map::new(HOLDS_BY_CHANNEL),
             channel_metadata: Map::new(CHANNEL_METADATA),
             channel_collaborators: Map::new(CHANNEL_COLLABORATORS),
             total_collaborator_shares: Map::new(TOTAL_COLLABORATOR_SHARES),
+            collaborator_invites: Map::new(COLLABORATOR_INVITES),
+            invites_by_address: Map::new(INVITES_BY_ADDRESS),
+            channels_by_collaborator: Map::new(CHANNELS_BY_COLLABORATOR),
+            channel_owner: Map::new(CHANNEL_OWNER),
+            channels_by_owner: Map::new(CHANNELS_BY_OWNER),
             followers: Map::new(FOLLOWERS),
             followers_count: Map::new(FOLLOWERS_COUNT),
+            following: Map::new(FOLLOWING),
+            subscription_plans: Map::new(SUBSCRIPTION_PLANS),
+            subscriptions: Map::new(SUBSCRIPTIONS),
+            payout_mode: Map::new(PAYOUT_MODE),
+            channel_cleanups: Map::new(CHANNEL_CLEANUPS),
+            suspensions: Map::new(SUSPENSIONS),
         }
     }
 
@@ -55,7 +109,11 @@ impl ChannelsManager {
     ) -> Result<(), ChannelError> {
         for username in usernames {
             self.reserved_usernames
-                .save(store, username.username.clone(), &username.address)
+                .save(
+                    store,
+                    normalize_username(&username.username),
+                    &username.address,
+                )
                 .map_err(|_| ChannelError::SaveReservedUsernamesFailed {})?;
         }
         Ok(())
@@ -66,11 +124,12 @@ impl ChannelsManager {
         usernames: Vec<UserName>,
     ) -> Result<(), ChannelError> {
         for username in usernames {
+            let username = normalize_username(&username);
             // return error if username does not exist
             if !self.reserved_usernames.has(store, username.clone()) {
                 return Err(ChannelError::UsernameNotReserved {});
             }
-            self.reserved_usernames.remove(store, username.clone());
+            self.reserved_usernames.remove(store, username);
         }
         Ok(())
     }
@@ -104,6 +163,7 @@ impl ChannelsManager {
         store: &dyn Storage,
         username: UserName,
     ) -> StdResult<Option<Option<Addr>>> {
+        let username = normalize_username(&username);
         // Check if the username exists in the map
         if !self.reserved_usernames.has(store, username.clone()) {
             return Ok(None); // Username doesn't exist
@@ -120,13 +180,17 @@ impl ChannelsManager {
         channel_details: ChannelDetails,
         channel_metadata: ChannelMetadata,
     ) -> Result<(), ChannelError> {
-        // Check if the channel ID or username already exists
-        if self.channel_details.has(store, channel_id.clone()) {
+        // Check if the channel ID or username already exists.
+        // A deleted channel's ID can not be reused until its cleanup is finished.
+        if self.channel_details.has(store, channel_id.clone())
+            || self.channel_cleanups.has(store, channel_id.clone())
+        {
             return Err(ChannelError::ChannelIdAlreadyExists {});
         }
+        let normalized_user_name = normalize_username(&channel_details.user_name);
         if self
             .username_to_channel_id
-            .has(store, channel_details.user_name.clone())
+            .has(store, normalized_user_name.clone())
         {
             return Err(ChannelError::UserNameAlreadyTaken {});
         }
@@ -136,7 +200,7 @@ impl ChannelsManager {
             .save(store, channel_id.clone(), &channel_details)
             .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
         self.username_to_channel_id
-            .save(store, channel_details.user_name.clone(), &channel_id)
+            .save(store, normalized_user_name, &channel_id)
             .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
         self.channel_id_to_username
             .save(store, channel_id.clone(), &channel_details.user_name)
@@ -148,6 +212,100 @@ impl ChannelsManager {
         Ok(())
     }
 
+    /// Fails if the username is held for a channel other than `channel_id`
+    pub fn check_username_hold(
+        &self,
+        store: &dyn Storage,
+        user_name: UserName,
+        channel_id: ChannelId,
+        block: &BlockInfo,
+    ) -> Result<(), ChannelError> {
+        if let Some(hold) = self
+            .username_holds
+            .may_load(store, normalize_username(&user_name))?
+        {
+            if hold.channel_id != channel_id && block.time < hold.expires_at {
+                return Err(ChannelError::UserNameOnHold {});
+            }
+        }
+        Ok(())
+    }
+
+    /// Changes the username of a channel and updates both username mappings.
+    /// The previous username is held for the channel for `hold_period` seconds.
+    /// A channel can change the display casing or separators of its own username.
+    ///
+    /// # Returns
+    /// * The previous username of the channel
+    pub fn change_username(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        new_user_name: UserName,
+        block: &BlockInfo,
+        hold_period: u64,
+    ) -> Result<UserName, ChannelError> {
+        let mut channel_details = self.get_channel_details(store, channel_id.clone())?;
+        let normalized_new = normalize_username(&new_user_name);
+        let normalized_old = normalize_username(&channel_details.user_name);
+        if normalized_new != normalized_old
+            && self
+                .username_to_channel_id
+                .has(store, normalized_new.clone())
+        {
+            return Err(ChannelError::UserNameAlreadyTaken {});
+        }
+        self.check_username_hold(store, new_user_name.clone(), channel_id.clone(), block)?;
+
+        let old_user_name = channel_details.user_name;
+        channel_details.user_name = new_user_name.clone();
+
+        self.channel_details
+            .save(store, channel_id.clone(), &channel_details)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+        self.channel_id_to_username
+            .save(store, channel_id.clone(), &new_user_name)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+
+        // Only the display casing changed, the normalized username stays with the channel
+        if normalized_new == normalized_old {
+            return Ok(old_user_name);
+        }
+
+        self.username_to_channel_id
+            .remove(store, normalized_old.clone());
+        self.username_to_channel_id
+            .save(store, normalized_new.clone(), &channel_id)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+
+        // The channel takes back its own held username, or an expired hold is dropped
+        if let Some(hold) = self
+            .username_holds
+            .may_load(store, normalized_new.clone())?
+        {
+            self.username_holds.remove(store, normalized_new.clone());
+            self.holds_by_channel
+                .remove(store, (hold.channel_id, normalized_new));
+        }
+        if hold_period > 0 {
+            self.username_holds
+                .save(
+                    store,
+                    normalized_old.clone(),
+                    &UsernameHold {
+                        channel_id: channel_id.clone(),
+                        expires_at: block.time.plus_seconds(hold_period),
+                    },
+                )
+                .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+            self.holds_by_channel
+                .save(store, (channel_id, normalized_old), &true)
+                .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+        }
+
+        Ok(old_user_name)
+    }
+
     pub fn delete_channel(
         &self,
         store: &mut dyn Storage,
@@ -164,9 +322,178 @@ impl ChannelsManager {
 
         // Remove channel details and mappings
         self.channel_details.remove(store, channel_id.clone());
-        self.username_to_channel_id.remove(store, user_name.clone());
-        self.channel_id_to_username.remove(store, channel_id);
+        // Channels created before normalization may share a normalized username,
+        // only the channel the mapping points to releases it
+        let normalized_user_name = normalize_username(&user_name);
+        if self
+            .username_to_channel_id
+            .may_load(store, normalized_user_name.clone())?
+            == Some(channel_id.clone())
+        {
+            self.username_to_channel_id
+                .remove(store, normalized_user_name);
+        }
+        self.channel_id_to_username
+            .remove(store, channel_id.clone());
+        self.channel_metadata.remove(store, channel_id.clone());
+        self.total_collaborator_shares
+            .remove(store, channel_id.clone());
+        self.followers_count.remove(store, channel_id.clone());
+        self.payout_mode.remove(store, channel_id.clone());
+        self.subscription_plans.remove(store, channel_id.clone());
+        self.suspensions.remove(store, channel_id.clone());
+        if let Some(owner) = self.channel_owner.may_load(store, channel_id.clone())? {
+            self.channel_owner.remove(store, channel_id.clone());
+            self.channels_by_owner.remove(store, (owner, channel_id));
+        }
+
+        Ok(())
+    }
+
+    /// Removes up to `limit` collaborators of a deleted channel
+    ///
+    /// # Returns
+    /// * The number of removed entries
+    pub fn delete_collaborators(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        limit: usize,
+    ) -> Result<usize, ChannelError> {
+        let addresses = self
+            .channel_collaborators
+            .prefix(channel_id.clone())
+            .keys(store, None, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+        for address in addresses.iter() {
+            self.channel_collaborators
+                .remove(store, (channel_id.clone(), address.clone()));
+            self.channels_by_collaborator
+                .remove(store, (address.clone(), channel_id.clone()));
+        }
+        Ok(addresses.len())
+    }
+
+    /// Removes up to `limit` pending collaborator invites of a deleted channel
+    ///
+    /// # Returns
+    /// * The number of removed entries
+    pub fn delete_collaborator_invites(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        limit: usize,
+    ) -> Result<usize, ChannelError> {
+        let addresses = self
+            .collaborator_invites
+            .prefix(channel_id.clone())
+            .keys(store, None, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+        for address in addresses.iter() {
+            self.collaborator_invites
+                .remove(store, (channel_id.clone(), address.clone()));
+            self.invites_by_address
+                .remove(store, (address.clone(), channel_id.clone()));
+        }
+        Ok(addresses.len())
+    }
+
+    /// Removes up to `limit` followers of a deleted channel
+    ///
+    /// # Returns
+    /// * The number of removed entries
+    pub fn delete_followers(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        limit: usize,
+    ) -> Result<usize, ChannelError> {
+        let followers = self
+            .followers
+            .prefix(channel_id.clone())
+            .keys(store, None, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+        for follower in followers.iter() {
+            self.followers
+                .remove(store, (channel_id.clone(), follower.clone()));
+            self.following
+                .remove(store, (follower.clone(), channel_id.clone()));
+        }
+        Ok(followers.len())
+    }
+
+    /// Removes up to `limit` username holds of a deleted channel
+    ///
+    /// # Returns
+    /// * The number of removed entries
+    pub fn delete_username_holds(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        limit: usize,
+    ) -> Result<usize, ChannelError> {
+        let user_names = self
+            .holds_by_channel
+            .prefix(channel_id.clone())
+            .keys(store, None, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+        for user_name in user_names.iter() {
+            self.holds_by_channel
+                .remove(store, (channel_id.clone(), user_name.clone()));
+            self.username_holds.remove(store, user_name.clone());
+        }
+        Ok(user_names.len())
+    }
+
+    /// Removes up to `limit` subscriptions of a deleted channel
+    ///
+    /// # Returns
+    /// * The number of removed entries
+    pub fn delete_subscriptions(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        limit: usize,
+    ) -> Result<usize, ChannelError> {
+        let subscribers = self
+            .subscriptions
+            .prefix(channel_id.clone())
+            .keys(store, None, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+        for subscriber in subscribers.iter() {
+            self.subscriptions
+                .remove(store, (channel_id.clone(), subscriber.clone()));
+        }
+        Ok(subscribers.len())
+    }
+
+    pub fn get_channel_cleanup(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+    ) -> StdResult<Option<ChannelCleanupProgress>> {
+        self.channel_cleanups.may_load(store, channel_id)
+    }
 
+    /// Saves the cleanup progress of a deleted channel, or removes it once the cleanup is finished
+    pub fn set_channel_cleanup(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        progress: Option<ChannelCleanupProgress>,
+    ) -> Result<(), ChannelError> {
+        match progress {
+            Some(progress) => self
+                .channel_cleanups
+                .save(store, channel_id, &progress)
+                .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?,
+            None => self.channel_cleanups.remove(store, channel_id),
+        }
         Ok(())
     }
 
@@ -186,6 +513,83 @@ impl ChannelsManager {
             .collect()
     }
 
+    /// Records the owner of a channel ONFT, replacing the previously recorded owner
+    pub fn set_channel_owner(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        owner: Addr,
+    ) -> Result<(), ChannelError> {
+        if !self.channel_details.has(store, channel_id.clone()) {
+            return Err(ChannelError::ChannelIdNotFound {});
+        }
+        if let Some(previous_owner) = self.channel_owner.may_load(store, channel_id.clone())? {
+            self.channels_by_owner
+                .remove(store, (previous_owner, channel_id.clone()));
+        }
+        self.channel_owner
+            .save(store, channel_id.clone(), &owner)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+        self.channels_by_owner
+            .save(store, (owner, channel_id), &true)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+        Ok(())
+    }
+
+    pub fn get_channel_owner(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+    ) -> StdResult<Option<Addr>> {
+        self.channel_owner.may_load(store, channel_id)
+    }
+
+    /// Returns the channels recorded for an owner, ordered by channel ID.
+    /// The records are not verified against the ONFT module.
+    pub fn get_channels_by_owner(
+        &self,
+        store: &dyn Storage,
+        owner: Addr,
+        start_after: Option<ChannelId>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<ChannelId>> {
+        let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+        self.channels_by_owner
+            .prefix(owner)
+            .keys(store, start, None, Order::Ascending)
+            .take(limit)
+            .collect()
+    }
+
+    /// Returns the channels an address collaborates on with the collaborator details,
+    /// ordered by channel ID
+    pub fn get_channels_by_collaborator(
+        &self,
+        store: &dyn Storage,
+        address: Addr,
+        start_after: Option<ChannelId>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<(ChannelId, ChannelCollaborator)>> {
+        let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+        let channel_ids = self
+            .channels_by_collaborator
+            .prefix(address.clone())
+            .keys(store, start, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        let mut channels = vec![];
+        for channel_id in channel_ids {
+            let collaborator = self
+                .channel_collaborators
+                .load(store, (channel_id.clone(), address.clone()))?;
+            channels.push((channel_id, collaborator));
+        }
+        Ok(channels)
+    }
+
     pub fn get_channel_details(
         &self,
         store: &dyn Storage,
@@ -210,6 +614,91 @@ impl ChannelsManager {
         Ok(())
     }
 
+    pub fn set_payout_mode(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        payout_mode: PayoutMode,
+    ) -> Result<(), ChannelError> {
+        if !self.channel_details.has(store, channel_id.clone()) {
+            return Err(ChannelError::ChannelIdNotFound {});
+        }
+        self.payout_mode
+            .save(store, channel_id, &payout_mode)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+        Ok(())
+    }
+
+    /// Suspends a channel, replacing any existing suspension.
+    pub fn suspend_channel(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        suspension: ChannelSuspension,
+    ) -> Result<(), ChannelError> {
+        if !self.channel_details.has(store, channel_id.clone()) {
+            return Err(ChannelError::ChannelIdNotFound {});
+        }
+        self.suspensions
+            .save(store, channel_id, &suspension)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+        Ok(())
+    }
+
+    pub fn unsuspend_channel(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+    ) -> Result<(), ChannelError> {
+        if !self.suspensions.has(store, channel_id.clone()) {
+            return Err(ChannelError::ChannelNotSuspended {});
+        }
+        self.suspensions.remove(store, channel_id);
+        Ok(())
+    }
+
+    /// Returns the suspension of a channel if it is active at `now`.
+    pub fn get_suspension(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+        now: Timestamp,
+    ) -> StdResult<Option<ChannelSuspension>> {
+        let suspension = self
+            .suspensions
+            .may_load(store, channel_id)?
+            .filter(|suspension| suspension.is_active(now));
+        Ok(suspension)
+    }
+
+    /// Returns an error if the channel is suspended at `now`.
+    pub fn ensure_not_suspended(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+        now: Timestamp,
+    ) -> Result<(), ChannelError> {
+        if let Some(suspension) = self.get_suspension(store, channel_id, now)? {
+            return Err(ChannelError::ChannelSuspended {
+                reason: suspension.reason,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the payout mode of a channel. Channels without a setting use `PayoutMode::Push`.
+    pub fn get_payout_mode(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+    ) -> Result<PayoutMode, ChannelError> {
+        let payout_mode = self
+            .payout_mode
+            .may_load(store, channel_id)?
+            .unwrap_or_default();
+        Ok(payout_mode)
+    }
+
     pub fn get_channel_metadata(
         &self,
         store: &dyn Storage,
@@ -243,7 +732,7 @@ impl ChannelsManager {
         user_name: UserName,
     ) -> Result<ChannelId, ChannelError> {
         self.username_to_channel_id
-            .load(store, user_name)
+            .load(store, normalize_username(&user_name))
             .map_err(|_| ChannelError::UserNameNotFound {})
     }
 
@@ -254,6 +743,39 @@ impl ChannelsManager {
         address: Addr,
         collaborator: ChannelCollaborator,
     ) -> Result<(), ChannelError> {
+        let new_total = self.validate_new_collaborator(
+            store,
+            channel_id.clone(),
+            address.clone(),
+            &collaborator,
+        )?;
+
+        // Save collaborator and update total shares
+        self.channel_collaborators
+            .save(store, (channel_id.clone(), address.clone()), &collaborator)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+        self.channels_by_collaborator
+            .save(store, (address, channel_id.clone()), &true)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+
+        self.total_collaborator_shares
+            .save(store, channel_id, &new_total)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+
+        Ok(())
+    }
+
+    /// Checks that a collaborator can be added to a channel
+    ///
+    /// # Returns
+    /// * The total collaborator share of the channel after adding the collaborator
+    fn validate_new_collaborator(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+        address: Addr,
+        collaborator: &ChannelCollaborator,
+    ) -> Result<Decimal, ChannelError> {
         // Check if channel exists
         if !self.channel_details.has(store, channel_id.clone()) {
             return Err(ChannelError::ChannelIdNotFound {});
@@ -288,16 +810,146 @@ impl ChannelsManager {
             return Err(ChannelError::InvalidSharePercentage {});
         }
 
-        // Save collaborator and update total shares
-        self.channel_collaborators
-            .save(store, (channel_id.clone(), address), &collaborator)
+        Ok(new_total)
+    }
+
+    /// Saves an invite for a collaborator, replacing any existing invite.
+    /// The collaborator is validated against the current collaborators of the channel,
+    /// and again when the invite is accepted.
+    pub fn add_collaborator_invite(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        address: Addr,
+        invite: CollaboratorInvite,
+    ) -> Result<(), ChannelError> {
+        self.validate_new_collaborator(
+            store,
+            channel_id.clone(),
+            address.clone(),
+            &invite.collaborator,
+        )?;
+
+        self.collaborator_invites
+            .save(store, (channel_id.clone(), address.clone()), &invite)
             .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+        self.invites_by_address
+            .save(store, (address, channel_id), &true)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+        Ok(())
+    }
+
+    pub fn get_collaborator_invite(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+        address: Addr,
+    ) -> Result<CollaboratorInvite, ChannelError> {
+        self.collaborator_invites
+            .load(store, (channel_id, address))
+            .map_err(|_| ChannelError::CollaboratorInviteNotFound {})
+    }
+
+    pub fn remove_collaborator_invite(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        address: Addr,
+    ) -> Result<(), ChannelError> {
+        if !self
+            .collaborator_invites
+            .has(store, (channel_id.clone(), address.clone()))
+        {
+            return Err(ChannelError::CollaboratorInviteNotFound {});
+        }
+        self.collaborator_invites
+            .remove(store, (channel_id.clone(), address.clone()));
+        self.invites_by_address.remove(store, (address, channel_id));
+        Ok(())
+    }
+
+    /// Returns the pending invites of an address that are not expired at the given block,
+    /// ordered by channel ID
+    pub fn get_collaborator_invites_by_address(
+        &self,
+        store: &dyn Storage,
+        address: Addr,
+        start_after: Option<ChannelId>,
+        limit: Option<u32>,
+        block: &BlockInfo,
+    ) -> Result<Vec<(ChannelId, CollaboratorInvite)>, ChannelError> {
+        let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
 
+        let invites = self
+            .invites_by_address
+            .prefix(address.clone())
+            .keys(store, start, None, Order::Ascending)
+            .map(|channel_id| {
+                let channel_id = channel_id?;
+                let invite = self
+                    .collaborator_invites
+                    .load(store, (channel_id.clone(), address.clone()))?;
+                Ok((channel_id, invite))
+            })
+            .filter(
+                |item: &StdResult<(ChannelId, CollaboratorInvite)>| match item {
+                    Ok((_, invite)) => invite.expiry.is_none_or(|expiry| !expiry.is_expired(block)),
+                    Err(_) => true,
+                },
+            )
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+        Ok(invites)
+    }
+
+    /// Changes the role and/or share of an existing collaborator.
+    /// Expired collaborators can not be updated.
+    ///
+    /// # Returns
+    /// * The collaborator before and after the update
+    pub fn update_collaborator(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        address: Addr,
+        role: Option<Role>,
+        share: Option<Decimal>,
+        block: &BlockInfo,
+    ) -> Result<(ChannelCollaborator, ChannelCollaborator), ChannelError> {
+        let old_collaborator = self.get_collaborator(store, channel_id.clone(), address.clone())?;
+        if old_collaborator.is_expired(block) {
+            return Err(ChannelError::CollaboratorExpired {});
+        }
+        let mut new_collaborator = old_collaborator.clone();
+        if let Some(role) = role {
+            new_collaborator.role = role;
+        }
+        if let Some(share) = share {
+            new_collaborator.share = share;
+        }
+
+        // Validate total share doesn't exceed 100% with the new share
+        let current_total = self
+            .total_collaborator_shares
+            .load(store, channel_id.clone())
+            .unwrap_or(Decimal::zero());
+        let new_total = current_total
+            .checked_sub(old_collaborator.share)
+            .and_then(|total| total.checked_add(new_collaborator.share))
+            .map_err(|_| ChannelError::InvalidSharePercentage {})?;
+        if new_total > Decimal::one() {
+            return Err(ChannelError::InvalidSharePercentage {});
+        }
+
+        self.channel_collaborators
+            .save(store, (channel_id.clone(), address), &new_collaborator)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
         self.total_collaborator_shares
             .save(store, channel_id, &new_total)
             .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
 
-        Ok(())
+        Ok((old_collaborator, new_collaborator))
     }
 
     pub fn remove_collaborator(
@@ -324,6 +976,8 @@ impl ChannelsManager {
         // Remove the collaborator
         self.channel_collaborators
             .remove(store, (channel_id.clone(), address.clone()));
+        self.channels_by_collaborator
+            .remove(store, (address, channel_id.clone()));
         // Update total shares
         let current_total = self
             .total_collaborator_shares
@@ -335,10 +989,13 @@ impl ChannelsManager {
             .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
         Ok(())
     }
+    /// Returns the shares of the collaborators that are not expired at the given block.
+    /// The share of an expired collaborator is left to the channel payment address.
     pub fn get_collaborator_shares(
         &self,
         store: &dyn Storage,
         channel_id: ChannelId,
+        block: &BlockInfo,
     ) -> Result<Vec<(Addr, Decimal)>, ChannelError> {
         // Check if channel exists
         if !self.channel_details.has(store, channel_id.clone()) {
@@ -350,10 +1007,53 @@ impl ChannelsManager {
             .prefix(channel_id)
             .range(store, None, None, Order::Ascending)
             .filter_map(|item| item.ok())
+            .filter(|(_, collaborator)| !collaborator.is_expired(block))
             .map(|(addr, collaborator)| (addr, collaborator.share))
             .collect();
         Ok(shares)
     }
+
+    /// Removes the collaborators that are expired at the given block and
+    /// recalculates the total collaborator shares from the remaining collaborators
+    ///
+    /// # Returns
+    /// * The addresses of the removed collaborators
+    pub fn prune_expired_collaborators(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        block: &BlockInfo,
+    ) -> Result<Vec<Addr>, ChannelError> {
+        // Check if channel exists
+        if !self.channel_details.has(store, channel_id.clone()) {
+            return Err(ChannelError::ChannelIdNotFound {});
+        }
+
+        let collaborators = self
+            .channel_collaborators
+            .prefix(channel_id.clone())
+            .range(store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        let mut pruned = vec![];
+        let mut total = Decimal::zero();
+        for (address, collaborator) in collaborators {
+            if collaborator.is_expired(block) {
+                self.channel_collaborators
+                    .remove(store, (channel_id.clone(), address.clone()));
+                self.channels_by_collaborator
+                    .remove(store, (address.clone(), channel_id.clone()));
+                pruned.push(address);
+            } else {
+                total += collaborator.share;
+            }
+        }
+
+        self.total_collaborator_shares
+            .save(store, channel_id, &total)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+        Ok(pruned)
+    }
     pub fn get_collaborator(
         &self,
         store: &dyn Storage,
@@ -399,6 +1099,7 @@ impl ChannelsManager {
                     address: addr.to_string(),
                     role: collab.role.to_string(),
                     share: collab.share,
+                    expires_at: collab.expires_at,
                 })
             })
             .collect::<Result<Vec<_>, _>>()
@@ -427,6 +1128,9 @@ impl ChannelsManager {
         self.followers
             .save(store, (channel_id.clone(), follower.clone()), &true)
             .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+        self.following
+            .save(store, (follower, channel_id.clone()), &true)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
 
         let current_count = self
             .followers_count
@@ -457,6 +1161,7 @@ impl ChannelsManager {
         }
         self.followers
             .remove(store, (channel_id.clone(), follower.clone()));
+        self.following.remove(store, (follower, channel_id.clone()));
 
         let current_count = self
             .followers_count
@@ -465,71 +1170,198 @@ impl ChannelsManager {
         self.followers_count
             .save(store, channel_id.clone(), &(current_count - 1))
             .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
-        Ok(())
+        Ok(())
+    }
+
+    pub fn get_followers_count(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+    ) -> Result<u64, ChannelError> {
+        // Check if channel exists
+        if !self.channel_details.has(store, channel_id.clone()) {
+            return Err(ChannelError::ChannelIdNotFound {});
+        }
+        let count = self
+            .followers_count
+            .load(store, channel_id.clone())
+            .unwrap_or(0);
+        Ok(count)
+    }
+
+    pub fn is_follower(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+        follower: Addr,
+    ) -> Result<bool, ChannelError> {
+        // Check if channel exists
+        if !self.channel_details.has(store, channel_id.clone()) {
+            return Err(ChannelError::ChannelIdNotFound {});
+        }
+        let follower = self
+            .followers
+            .has(store, (channel_id.clone(), follower.clone()));
+        Ok(follower)
+    }
+
+    pub fn get_followers(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Addr>, ChannelError> {
+        let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+        let start = start_after.map(|addr| Bound::exclusive(Addr::unchecked(addr)));
+
+        let followers = self
+            .followers
+            .prefix(channel_id)
+            .keys(store, start, None, Order::Ascending)
+            .take(limit)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_or_default();
+        Ok(followers)
+    }
+
+    /// Sets the subscription plan of a channel, or removes it.
+    /// Existing subscriptions stay active until they expire.
+    pub fn set_subscription_plan(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        plan: Option<SubscriptionPlan>,
+    ) -> Result<(), ChannelError> {
+        if !self.channel_details.has(store, channel_id.clone()) {
+            return Err(ChannelError::ChannelIdNotFound {});
+        }
+        match plan {
+            Some(plan) => self
+                .subscription_plans
+                .save(store, channel_id, &plan)
+                .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?,
+            None => self.subscription_plans.remove(store, channel_id),
+        }
+        Ok(())
+    }
+
+    pub fn get_subscription_plan(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+    ) -> Result<Option<SubscriptionPlan>, ChannelError> {
+        if !self.channel_details.has(store, channel_id.clone()) {
+            return Err(ChannelError::ChannelIdNotFound {});
+        }
+        Ok(self.subscription_plans.may_load(store, channel_id)?)
+    }
+
+    /// Extends the subscription of an address by `periods` periods of the channel plan.
+    /// An active subscription is extended from its end, otherwise from the current block.
+    ///
+    /// # Returns
+    /// * The time the subscription is paid until
+    pub fn add_subscription(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        subscriber: Addr,
+        periods: u32,
+        block: &BlockInfo,
+    ) -> Result<Timestamp, ChannelError> {
+        let plan = self
+            .get_subscription_plan(store, channel_id.clone())?
+            .ok_or(ChannelError::SubscriptionPlanNotFound {})?;
+
+        let start = self
+            .subscriptions
+            .may_load(store, (channel_id.clone(), subscriber.clone()))?
+            .filter(|expires_at| *expires_at > block.time)
+            .unwrap_or(block.time);
+        let expires_at = start.plus_seconds(plan.period.saturating_mul(periods as u64));
+
+        self.subscriptions
+            .save(store, (channel_id, subscriber), &expires_at)
+            .map_err(|_| ChannelError::SaveChannelDetailsFailed {})?;
+        Ok(expires_at)
     }
 
-    pub fn get_followers_count(
+    /// Returns the time the subscription of an address is paid until, if it is active
+    pub fn get_active_subscription(
         &self,
         store: &dyn Storage,
         channel_id: ChannelId,
-    ) -> Result<u64, ChannelError> {
-        // Check if channel exists
+        subscriber: Addr,
+        block: &BlockInfo,
+    ) -> Result<Option<Timestamp>, ChannelError> {
         if !self.channel_details.has(store, channel_id.clone()) {
             return Err(ChannelError::ChannelIdNotFound {});
         }
-        let count = self
-            .followers_count
-            .load(store, channel_id.clone())
-            .unwrap_or(0);
-        Ok(count)
+        let expires_at = self
+            .subscriptions
+            .may_load(store, (channel_id, subscriber))?
+            .filter(|expires_at| *expires_at > block.time);
+        Ok(expires_at)
     }
 
-    pub fn is_follower(
+    /// Returns the subscribers of a channel with the time their subscription is paid until,
+    /// ordered by address. Subscriptions expired at the given block are skipped.
+    pub fn get_subscribers(
         &self,
         store: &dyn Storage,
         channel_id: ChannelId,
-        follower: Addr,
-    ) -> Result<bool, ChannelError> {
-        // Check if channel exists
+        start_after: Option<String>,
+        limit: Option<u32>,
+        block: &BlockInfo,
+    ) -> Result<Vec<(Addr, Timestamp)>, ChannelError> {
         if !self.channel_details.has(store, channel_id.clone()) {
             return Err(ChannelError::ChannelIdNotFound {});
         }
-        let follower = self
-            .followers
-            .has(store, (channel_id.clone(), follower.clone()));
-        Ok(follower)
+        let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+        let start = start_after.map(|addr| Bound::exclusive(Addr::unchecked(addr)));
+
+        let subscribers = self
+            .subscriptions
+            .prefix(channel_id)
+            .range(store, start, None, Order::Ascending)
+            .filter(|item| match item {
+                Ok((_, expires_at)) => *expires_at > block.time,
+                Err(_) => true,
+            })
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+        Ok(subscribers)
     }
 
-    pub fn get_followers(
+    /// Returns the channels followed by an address, ordered by channel ID
+    pub fn get_following(
         &self,
         store: &dyn Storage,
-        channel_id: ChannelId,
-        start_after: Option<String>,
+        address: Addr,
+        start_after: Option<ChannelId>,
         limit: Option<u32>,
-    ) -> Result<Vec<Addr>, ChannelError> {
+    ) -> StdResult<Vec<ChannelId>> {
         let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
-        let start = start_after.map(|addr| Bound::exclusive(Addr::unchecked(addr)));
+        let start = start_after.map(Bound::exclusive);
 
-        let followers = self
-            .followers
-            .prefix(channel_id)
+        self.following
+            .prefix(address)
             .keys(store, start, None, Order::Ascending)
             .take(limit)
-            .collect::<Result<Vec<_>, _>>()
-            .unwrap_or_default();
-        Ok(followers)
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use cosmwasm_std::testing::mock_dependencies;
-    use omniflix_channel_types::channel::Role;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
 
     #[test]
     fn test_collaborator_operations() {
         let mut deps = mock_dependencies();
+        let env = mock_env();
         let channels = ChannelsManager::new();
         let channel_id1 = "channel1".to_string();
         let channel_id2 = "channel2".to_string();
@@ -585,6 +1417,7 @@ mod tests {
         let collab1 = ChannelCollaborator {
             role: Role::Moderator,
             share: Decimal::percent(50), // 50%
+            expires_at: None,
         };
 
         let result = channels.add_collaborator(
@@ -598,6 +1431,7 @@ mod tests {
         let collab2 = ChannelCollaborator {
             role: Role::Publisher,
             share: Decimal::percent(50), // 50%
+            expires_at: None,
         };
         let result = channels.add_collaborator(
             &mut deps.storage,
@@ -611,6 +1445,7 @@ mod tests {
         let collab3 = ChannelCollaborator {
             role: Role::Moderator,
             share: Decimal::percent(30), // 30%
+            expires_at: None,
         };
         let result = channels.add_collaborator(
             &mut deps.storage,
@@ -623,6 +1458,7 @@ mod tests {
         let collab4 = ChannelCollaborator {
             role: Role::Publisher,
             share: Decimal::percent(70), // 70%
+            expires_at: None,
         };
         let result = channels.add_collaborator(
             &mut deps.storage,
@@ -634,7 +1470,7 @@ mod tests {
 
         // Verify shares for channel 1
         let shares1 = channels
-            .get_collaborator_shares(&deps.storage, channel_id1.clone())
+            .get_collaborator_shares(&deps.storage, channel_id1.clone(), &env.block)
             .unwrap();
         assert_eq!(shares1.len(), 2);
         assert!(shares1.contains(&(addr1.clone(), Decimal::percent(50))));
@@ -642,7 +1478,7 @@ mod tests {
 
         // Verify shares for channel 2
         let shares2 = channels
-            .get_collaborator_shares(&deps.storage, channel_id2.clone())
+            .get_collaborator_shares(&deps.storage, channel_id2.clone(), &env.block)
             .unwrap();
         assert_eq!(shares2.len(), 2);
         assert!(shares2.contains(&(addr3.clone(), Decimal::percent(30))));
@@ -655,14 +1491,14 @@ mod tests {
 
         // Verify channel 1 shares updated
         let shares1_after = channels
-            .get_collaborator_shares(&deps.storage, channel_id1.clone())
+            .get_collaborator_shares(&deps.storage, channel_id1.clone(), &env.block)
             .unwrap();
         assert_eq!(shares1_after.len(), 1);
         assert!(shares1_after.contains(&(addr2.clone(), Decimal::percent(50))));
 
         // Verify channel 2 shares unchanged
         let shares2_after = channels
-            .get_collaborator_shares(&deps.storage, channel_id2.clone())
+            .get_collaborator_shares(&deps.storage, channel_id2.clone(), &env.block)
             .unwrap();
         assert_eq!(shares2_after.len(), 2);
         assert!(shares2_after.contains(&(addr3.clone(), Decimal::percent(30))));
@@ -703,6 +1539,7 @@ mod tests {
         let collab1 = ChannelCollaborator {
             role: Role::Moderator,
             share: Decimal::percent(50),
+            expires_at: None,
         };
         let result = channels.add_collaborator(
             &mut deps.storage,
@@ -717,6 +1554,7 @@ mod tests {
         let collab2 = ChannelCollaborator {
             role: Role::Moderator,
             share: Decimal::percent(50),
+            expires_at: None,
         };
         let result = channels.add_collaborator(
             &mut deps.storage,
@@ -730,6 +1568,7 @@ mod tests {
         let collab3 = ChannelCollaborator {
             role: Role::Moderator,
             share: Decimal::percent(50),
+            expires_at: None,
         };
         let result = channels.add_collaborator(
             &mut deps.storage,
@@ -740,6 +1579,130 @@ mod tests {
         assert_eq!(result.unwrap_err(), ChannelError::InvalidSharePercentage {});
     }
 
+    #[test]
+    fn test_prune_expired_collaborators() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let channels = ChannelsManager::new();
+        let channel_id = "channel1".to_string();
+        let addr1 = Addr::unchecked("addr1");
+        let addr2 = Addr::unchecked("addr2");
+
+        // Create channel
+        let channel_details = ChannelDetails {
+            channel_id: channel_id.clone(),
+            onft_id: "".to_string(),
+            payment_address: Addr::unchecked("payment_address"),
+            user_name: "user1".to_string(),
+        };
+        channels
+            .add_channel(
+                &mut deps.storage,
+                channel_id.clone(),
+                channel_details,
+                ChannelMetadata {
+                    channel_name: "channel1".to_string(),
+                    description: None,
+                    profile_picture: None,
+                    banner_picture: None,
+                },
+            )
+            .unwrap();
+
+        // Collaborator that expires in 100 seconds
+        let collab1 = ChannelCollaborator {
+            role: Role::Moderator,
+            share: Decimal::percent(30),
+            expires_at: Some(env.block.time.plus_seconds(100)),
+        };
+        channels
+            .add_collaborator(
+                &mut deps.storage,
+                channel_id.clone(),
+                addr1.clone(),
+                collab1,
+            )
+            .unwrap();
+
+        // Collaborator without expiration
+        let collab2 = ChannelCollaborator {
+            role: Role::Publisher,
+            share: Decimal::percent(20),
+            expires_at: None,
+        };
+        channels
+            .add_collaborator(
+                &mut deps.storage,
+                channel_id.clone(),
+                addr2.clone(),
+                collab2,
+            )
+            .unwrap();
+
+        // Nothing to prune before the expiration
+        let pruned = channels
+            .prune_expired_collaborators(&mut deps.storage, channel_id.clone(), &env.block)
+            .unwrap();
+        assert!(pruned.is_empty());
+
+        let mut block = env.block.clone();
+        block.time = block.time.plus_seconds(100);
+
+        // Expired collaborators do not receive shares
+        let shares = channels
+            .get_collaborator_shares(&deps.storage, channel_id.clone(), &block)
+            .unwrap();
+        assert_eq!(shares, vec![(addr2.clone(), Decimal::percent(20))]);
+
+        // Expired collaborators can not be updated
+        let result = channels.update_collaborator(
+            &mut deps.storage,
+            channel_id.clone(),
+            addr1.clone(),
+            None,
+            Some(Decimal::percent(10)),
+            &block,
+        );
+        assert_eq!(result, Err(ChannelError::CollaboratorExpired {}));
+
+        // A missing share total is rejected instead of underflowing
+        let total_shares = channels
+            .total_collaborator_shares
+            .load(&deps.storage, channel_id.clone())
+            .unwrap();
+        channels
+            .total_collaborator_shares
+            .remove(&mut deps.storage, channel_id.clone());
+        let result = channels.update_collaborator(
+            &mut deps.storage,
+            channel_id.clone(),
+            addr2.clone(),
+            None,
+            Some(Decimal::percent(10)),
+            &block,
+        );
+        assert_eq!(result, Err(ChannelError::InvalidSharePercentage {}));
+        channels
+            .total_collaborator_shares
+            .save(&mut deps.storage, channel_id.clone(), &total_shares)
+            .unwrap();
+
+        let pruned = channels
+            .prune_expired_collaborators(&mut deps.storage, channel_id.clone(), &block)
+            .unwrap();
+        assert_eq!(pruned, vec![addr1.clone()]);
+
+        let total_shares = channels
+            .total_collaborator_shares
+            .load(&deps.storage, channel_id.clone())
+            .unwrap();
+        assert_eq!(total_shares, Decimal::percent(20));
+        assert_eq!(
+            channels.get_collaborator(&deps.storage, channel_id, addr1),
+            Err(ChannelError::CollaboratorNotFound {})
+        );
+    }
+
     #[test]
     fn test_duplicate_collaborator() {
         let mut deps = mock_dependencies();
@@ -772,6 +1735,7 @@ mod tests {
         let collab = ChannelCollaborator {
             role: Role::Moderator,
             share: Decimal::percent(50),
+            expires_at: None,
         };
         let result = channels.add_collaborator(
             &mut deps.storage,
@@ -791,6 +1755,286 @@ mod tests {
         assert!(matches!(result, Err(ChannelError::CollaboratorExists {})));
     }
 
+    #[test]
+    fn test_collaborator_invites_by_address() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let channels = ChannelsManager::new();
+        let invitee = Addr::unchecked("invitee");
+
+        for (channel_id, expiry) in [
+            (
+                "channel1",
+                Some(cw_utils::Expiration::AtHeight(env.block.height)),
+            ),
+            ("channel2", None),
+        ] {
+            channels
+                .add_channel(
+                    &mut deps.storage,
+                    channel_id.to_string(),
+                    ChannelDetails {
+                        channel_id: channel_id.to_string(),
+                        onft_id: "".to_string(),
+                        payment_address: Addr::unchecked("payment_address"),
+                        user_name: channel_id.to_string(),
+                    },
+                    ChannelMetadata {
+                        channel_name: channel_id.to_string(),
+                        description: None,
+                        profile_picture: None,
+                        banner_picture: None,
+                    },
+                )
+                .unwrap();
+            channels
+                .add_collaborator_invite(
+                    &mut deps.storage,
+                    channel_id.to_string(),
+                    invitee.clone(),
+                    CollaboratorInvite {
+                        collaborator: ChannelCollaborator {
+                            role: Role::Publisher,
+                            share: Decimal::percent(10),
+                            expires_at: None,
+                        },
+                        expiry,
+                    },
+                )
+                .unwrap();
+        }
+
+        // Expired invites do not count towards the page limit
+        let invites = channels
+            .get_collaborator_invites_by_address(&deps.storage, invitee, None, Some(1), &env.block)
+            .unwrap();
+        assert_eq!(invites.len(), 1);
+        assert_eq!(invites[0].0, "channel2".to_string());
+    }
+
+    #[test]
+    fn test_change_username() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let channels = ChannelsManager::new();
+        let channel_id1 = "channel1".to_string();
+        let channel_id2 = "channel2".to_string();
+
+        for (channel_id, user_name) in [(&channel_id1, "user1"), (&channel_id2, "user2")] {
+            channels
+                .add_channel(
+                    &mut deps.storage,
+                    channel_id.clone(),
+                    ChannelDetails {
+                        channel_id: channel_id.clone(),
+                        onft_id: "".to_string(),
+                        payment_address: Addr::unchecked("payment_address"),
+                        user_name: user_name.to_string(),
+                    },
+                    ChannelMetadata {
+                        channel_name: user_name.to_string(),
+                        description: None,
+                        profile_picture: None,
+                        banner_picture: None,
+                    },
+                )
+                .unwrap();
+        }
+
+        // Username taken by another channel
+        let res = channels.change_username(
+            &mut deps.storage,
+            channel_id1.clone(),
+            "user2".to_string(),
+            &env.block,
+            100,
+        );
+        assert_eq!(res, Err(ChannelError::UserNameAlreadyTaken {}));
+
+        let old_user_name = channels
+            .change_username(
+                &mut deps.storage,
+                channel_id1.clone(),
+                "user1new".to_string(),
+                &env.block,
+                100,
+            )
+            .unwrap();
+        assert_eq!(old_user_name, "user1");
+        assert_eq!(
+            channels
+                .get_channel_id(&deps.storage, "user1new".to_string())
+                .unwrap(),
+            channel_id1
+        );
+        assert_eq!(
+            channels
+                .get_channel_details(&deps.storage, channel_id1.clone())
+                .unwrap()
+                .user_name,
+            "user1new"
+        );
+        assert_eq!(
+            channels.get_channel_id(&deps.storage, "user1".to_string()),
+            Err(ChannelError::UserNameNotFound {})
+        );
+
+        // The old username is held for the first channel
+        let res = channels.change_username(
+            &mut deps.storage,
+            channel_id2.clone(),
+            "user1".to_string(),
+            &env.block,
+            100,
+        );
+        assert_eq!(res, Err(ChannelError::UserNameOnHold {}));
+
+        // The hold is released after the hold period
+        let mut block = env.block.clone();
+        block.time = block.time.plus_seconds(100);
+        channels
+            .change_username(
+                &mut deps.storage,
+                channel_id2.clone(),
+                "user1".to_string(),
+                &block,
+                100,
+            )
+            .unwrap();
+        assert_eq!(
+            channels
+                .get_channel_id(&deps.storage, "user1".to_string())
+                .unwrap(),
+            channel_id2
+        );
+
+        // The dropped hold is no longer indexed for the first channel
+        assert_eq!(
+            channels
+                .delete_username_holds(&mut deps.storage, channel_id1.clone(), 10)
+                .unwrap(),
+            0
+        );
+
+        // The holds of a deleted channel are removed with it
+        assert_eq!(
+            channels
+                .delete_username_holds(&mut deps.storage, channel_id2.clone(), 10)
+                .unwrap(),
+            1
+        );
+        assert!(channels.username_holds.is_empty(&deps.storage));
+        assert!(channels.holds_by_channel.is_empty(&deps.storage));
+    }
+
+    #[test]
+    fn test_normalized_usernames() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let channels = ChannelsManager::new();
+        let channel_id1 = "channel1".to_string();
+        let channel_id2 = "channel2".to_string();
+
+        let channel_details = |channel_id: &ChannelId, user_name: &str| ChannelDetails {
+            channel_id: channel_id.clone(),
+            onft_id: "".to_string(),
+            payment_address: Addr::unchecked("payment_address"),
+            user_name: user_name.to_string(),
+        };
+        let channel_metadata = ChannelMetadata {
+            channel_name: "channel".to_string(),
+            description: None,
+            profile_picture: None,
+            banner_picture: None,
+        };
+
+        channels
+            .add_channel(
+                &mut deps.storage,
+                channel_id1.clone(),
+                channel_details(&channel_id1, "Alice"),
+                channel_metadata.clone(),
+            )
+            .unwrap();
+
+        // Usernames that normalize to the same key are taken
+        for user_name in ["alice", "alice_", "a1ice", "A.L.I.C.E"] {
+            let res = channels.add_channel(
+                &mut deps.storage,
+                channel_id2.clone(),
+                channel_details(&channel_id2, user_name),
+                channel_metadata.clone(),
+            );
+            assert_eq!(res, Err(ChannelError::UserNameAlreadyTaken {}));
+        }
+
+        // Lookups are normalized, the display casing is kept
+        assert_eq!(
+            channels
+                .get_channel_id(&deps.storage, "ALICE".to_string())
+                .unwrap(),
+            channel_id1
+        );
+        assert_eq!(
+            channels
+                .get_channel_details(&deps.storage, channel_id1.clone())
+                .unwrap()
+                .user_name,
+            "Alice"
+        );
+
+        // A channel can change the display casing of its own username without a hold
+        channels
+            .change_username(
+                &mut deps.storage,
+                channel_id1.clone(),
+                "ALICE".to_string(),
+                &env.block,
+                100,
+            )
+            .unwrap();
+        assert_eq!(
+            channels
+                .get_channel_details(&deps.storage, channel_id1.clone())
+                .unwrap()
+                .user_name,
+            "ALICE"
+        );
+        assert_eq!(
+            channels
+                .get_channel_id(&deps.storage, "alice".to_string())
+                .unwrap(),
+            channel_id1
+        );
+        assert!(channels.username_holds.is_empty(&deps.storage));
+
+        // Reservations are normalized
+        channels
+            .add_reserved_usernames(
+                &mut deps.storage,
+                vec![ReservedUsername {
+                    username: "Bob".to_string(),
+                    address: None,
+                }],
+            )
+            .unwrap();
+        assert_eq!(
+            channels
+                .get_reserved_status(&deps.storage, "b0b".to_string())
+                .unwrap(),
+            Some(None)
+        );
+        channels
+            .remove_reserved_usernames(&mut deps.storage, vec!["BOB".to_string()])
+            .unwrap();
+        assert_eq!(
+            channels
+                .get_reserved_status(&deps.storage, "bob".to_string())
+                .unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn test_follower_operations() {
         let mut deps = mock_dependencies();
@@ -860,6 +2104,12 @@ mod tests {
         assert!(followers.contains(&follower1));
         assert!(followers.contains(&follower2));
 
+        // Test get_following
+        let following = channels
+            .get_following(&deps.storage, follower1.clone(), None, None)
+            .unwrap();
+        assert_eq!(following, vec![channel_id.clone()]);
+
         // Test removing follower
         let result =
             channels.remove_follower(&mut deps.storage, channel_id.clone(), follower1.clone());
@@ -883,5 +2133,133 @@ mod tests {
         assert_eq!(followers.len(), 1);
         assert!(followers.contains(&follower2));
         assert!(!followers.contains(&follower1));
+        assert!(channels
+            .get_following(&deps.storage, follower1.clone(), None, None)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_subscriptions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let channels = ChannelsManager::new();
+        let channel_id = "channel1".to_string();
+        let subscriber = deps.api.addr_make("subscriber");
+
+        channels
+            .add_channel(
+                &mut deps.storage,
+                channel_id.clone(),
+                ChannelDetails {
+                    channel_id: channel_id.clone(),
+                    onft_id: "".to_string(),
+                    payment_address: Addr::unchecked("payment_address"),
+                    user_name: "user1".to_string(),
+                },
+                ChannelMetadata {
+                    channel_name: "channel1".to_string(),
+                    description: None,
+                    profile_picture: None,
+                    banner_picture: None,
+                },
+            )
+            .unwrap();
+
+        // No plan set
+        let res = channels.add_subscription(
+            &mut deps.storage,
+            channel_id.clone(),
+            subscriber.clone(),
+            1,
+            &env.block,
+        );
+        assert_eq!(res, Err(ChannelError::SubscriptionPlanNotFound {}));
+
+        channels
+            .set_subscription_plan(
+                &mut deps.storage,
+                channel_id.clone(),
+                Some(SubscriptionPlan {
+                    price: cosmwasm_std::coin(100, "uflix"),
+                    period: 100,
+                }),
+            )
+            .unwrap();
+
+        // Two periods from the current block
+        let expires_at = channels
+            .add_subscription(
+                &mut deps.storage,
+                channel_id.clone(),
+                subscriber.clone(),
+                2,
+                &env.block,
+            )
+            .unwrap();
+        assert_eq!(expires_at, env.block.time.plus_seconds(200));
+
+        // An active subscription is extended from its end
+        let mut block = env.block.clone();
+        block.time = block.time.plus_seconds(50);
+        let expires_at = channels
+            .add_subscription(
+                &mut deps.storage,
+                channel_id.clone(),
+                subscriber.clone(),
+                1,
+                &block,
+            )
+            .unwrap();
+        assert_eq!(expires_at, env.block.time.plus_seconds(300));
+
+        // Expired subscriptions are not active
+        block.time = env.block.time.plus_seconds(300);
+        assert_eq!(
+            channels
+                .get_active_subscription(
+                    &deps.storage,
+                    channel_id.clone(),
+                    subscriber.clone(),
+                    &block
+                )
+                .unwrap(),
+            None
+        );
+
+        // An expired subscription is renewed from the current block
+        let expires_at = channels
+            .add_subscription(
+                &mut deps.storage,
+                channel_id.clone(),
+                subscriber.clone(),
+                1,
+                &block,
+            )
+            .unwrap();
+        assert_eq!(expires_at, block.time.plus_seconds(100));
+
+        // Expired subscriptions do not count towards the page limit
+        channels
+            .add_subscription(
+                &mut deps.storage,
+                channel_id.clone(),
+                Addr::unchecked("alice"),
+                1,
+                &env.block,
+            )
+            .unwrap();
+        let subscribers = channels
+            .get_subscribers(&deps.storage, channel_id.clone(), None, Some(1), &block)
+            .unwrap();
+        assert_eq!(subscribers, vec![(subscriber.clone(), expires_at)]);
+
+        // Subscriptions are removed with the channel
+        assert_eq!(
+            channels
+                .delete_subscriptions(&mut deps.storage, channel_id.clone(), 10)
+                .unwrap(),
+            2
+        );
     }
 }
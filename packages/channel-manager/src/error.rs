@@ -1,7 +1,11 @@
+use cosmwasm_std::StdError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
 pub enum ChannelError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
     #[error("Channel ID not found")]
     ChannelIdNotFound {},
 
@@ -14,6 +18,9 @@ pub enum ChannelError {
     #[error("User name already taken")]
     UserNameAlreadyTaken {},
 
+    #[error("User name is held for its previous channel")]
+    UserNameOnHold {},
+
     #[error("Saving channel details failed")]
     SaveChannelDetailsFailed {},
 
@@ -35,6 +42,12 @@ pub enum ChannelError {
     #[error("Collaborator expired")]
     CollaboratorExpired {},
 
+    #[error("Collaborator invite not found")]
+    CollaboratorInviteNotFound {},
+
+    #[error("Collaborator invite expired")]
+    CollaboratorInviteExpired {},
+
     #[error("Total unique collaborators limit exceeded")]
     TotalUniqueCollaboratorsLimitExceeded {},
 
@@ -43,4 +56,16 @@ pub enum ChannelError {
 
     #[error("Already following")]
     AlreadyFollowing {},
+
+    #[error("Subscription plan not found")]
+    SubscriptionPlanNotFound {},
+
+    #[error("Saving tip stats failed")]
+    SaveTipStatsFailed {},
+
+    #[error("Channel is suspended: {reason}")]
+    ChannelSuspended { reason: String },
+
+    #[error("Channel is not suspended")]
+    ChannelNotSuspended {},
 }
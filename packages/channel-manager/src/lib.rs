@@ -1,2 +1,4 @@
 pub mod channel;
 pub mod error;
+pub mod tips;
+pub mod username;
@@ -0,0 +1,589 @@
+use cosmwasm_std::{Addr, Coin, Order, Storage, Uint128};
+use cw_storage_plus::{Bound, Map};
+
+use crate::error::ChannelError;
+use omniflix_channel_types::{
+    asset::PublishId,
+    channel::ChannelId,
+    msg::{TipStats, TipperInfo},
+};
+
+const CHANNEL_TIPS: &str = "ctp"; // channel_tips
+const CHANNEL_TIP_COUNT: &str = "ctc"; // channel_tip_count
+const ASSET_TIPS: &str = "atp"; // asset_tips
+const ASSET_TIP_COUNT: &str = "atc"; // asset_tip_count
+const TIPPER_TIPS: &str = "ttp"; // tipper_tips
+const TIPPER_RANKING: &str = "ttr"; // tipper_ranking
+const PENDING_EARNINGS: &str = "pe"; // pending_earnings
+
+const PAGINATION_LIMIT: u32 = 50;
+
+type Denom = String;
+
+/// Lifetime tip totals. All amounts are the gross amounts sent by the tippers.
+pub struct TipsManager {
+    pub channel_tips: Map<(ChannelId, Denom), Uint128>,
+    pub channel_tip_count: Map<ChannelId, u64>,
+    pub asset_tips: Map<(ChannelId, PublishId, Denom), Uint128>,
+    pub asset_tip_count: Map<(ChannelId, PublishId), u64>,
+    pub tipper_tips: Map<(ChannelId, Addr, Denom), Uint128>,
+    /// Tippers of a channel ordered by their total for a denom
+    pub tipper_ranking: Map<((ChannelId, Denom), u128, Addr), ()>,
+    /// Tip shares credited to an address by channels in `PayoutMode::Pull`
+    pub pending_earnings: Map<(Addr, Denom), Uint128>,
+}
+
+#[allow(clippy::new_without_default)]
+impl TipsManager {
+    pub const fn new() -> Self {
+        TipsManager {
+            channel_tips: Map::new(CHANNEL_TIPS),
+            channel_tip_count: Map::new(CHANNEL_TIP_COUNT),
+            asset_tips: Map::new(ASSET_TIPS),
+            asset_tip_count: Map::new(ASSET_TIP_COUNT),
+            tipper_tips: Map::new(TIPPER_TIPS),
+            tipper_ranking: Map::new(TIPPER_RANKING),
+            pending_earnings: Map::new(PENDING_EARNINGS),
+        }
+    }
+
+    /// Records a single tip, which may contain several denoms
+    pub fn record_tip(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        publish_id: Option<PublishId>,
+        tipper: Addr,
+        amount: Vec<Coin>,
+    ) -> Result<(), ChannelError> {
+        for coin in amount {
+            add_amount(
+                &self.channel_tips,
+                store,
+                (channel_id.clone(), coin.denom.clone()),
+                coin.amount,
+            )?;
+            if let Some(publish_id) = publish_id.clone() {
+                add_amount(
+                    &self.asset_tips,
+                    store,
+                    (channel_id.clone(), publish_id, coin.denom.clone()),
+                    coin.amount,
+                )?;
+            }
+
+            let previous_total = self
+                .tipper_tips
+                .may_load(
+                    store,
+                    (channel_id.clone(), tipper.clone(), coin.denom.clone()),
+                )?
+                .unwrap_or_default();
+            let new_total = add_amount(
+                &self.tipper_tips,
+                store,
+                (channel_id.clone(), tipper.clone(), coin.denom.clone()),
+                coin.amount,
+            )?;
+            self.tipper_ranking.remove(
+                store,
+                (
+                    (channel_id.clone(), coin.denom.clone()),
+                    previous_total.u128(),
+                    tipper.clone(),
+                ),
+            );
+            self.tipper_ranking.save(
+                store,
+                (
+                    (channel_id.clone(), coin.denom.clone()),
+                    new_total.u128(),
+                    tipper.clone(),
+                ),
+                &(),
+            )?;
+        }
+
+        let count = self
+            .channel_tip_count
+            .may_load(store, channel_id.clone())?
+            .unwrap_or(0);
+        self.channel_tip_count
+            .save(store, channel_id.clone(), &(count + 1))?;
+        if let Some(publish_id) = publish_id {
+            let count = self
+                .asset_tip_count
+                .may_load(store, (channel_id.clone(), publish_id.clone()))?
+                .unwrap_or(0);
+            self.asset_tip_count
+                .save(store, (channel_id, publish_id), &(count + 1))?;
+        }
+        Ok(())
+    }
+
+    pub fn get_channel_tip_stats(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+    ) -> Result<TipStats, ChannelError> {
+        let total = self
+            .channel_tips
+            .prefix(channel_id.clone())
+            .range(store, None, None, Order::Ascending)
+            .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+            .collect::<Result<Vec<_>, _>>()?;
+        let tip_count = self
+            .channel_tip_count
+            .may_load(store, channel_id)?
+            .unwrap_or(0);
+        Ok(TipStats { total, tip_count })
+    }
+
+    pub fn get_asset_tip_stats(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+        publish_id: PublishId,
+    ) -> Result<TipStats, ChannelError> {
+        let total = self
+            .asset_tips
+            .prefix((channel_id.clone(), publish_id.clone()))
+            .range(store, None, None, Order::Ascending)
+            .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+            .collect::<Result<Vec<_>, _>>()?;
+        let tip_count = self
+            .asset_tip_count
+            .may_load(store, (channel_id, publish_id))?
+            .unwrap_or(0);
+        Ok(TipStats { total, tip_count })
+    }
+
+    pub fn get_tipper_totals(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+        tipper: Addr,
+    ) -> Result<Vec<Coin>, ChannelError> {
+        let totals = self
+            .tipper_tips
+            .prefix((channel_id, tipper))
+            .range(store, None, None, Order::Ascending)
+            .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(totals)
+    }
+
+    /// Returns the tippers of a channel for a denom, highest total first
+    ///
+    /// `start_after` is the address of the last tipper of the previous page
+    pub fn get_top_tippers(
+        &self,
+        store: &dyn Storage,
+        channel_id: ChannelId,
+        denom: Denom,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    ) -> Result<Vec<TipperInfo>, ChannelError> {
+        let limit = limit.unwrap_or(PAGINATION_LIMIT).min(PAGINATION_LIMIT) as usize;
+        let end = match start_after {
+            Some(tipper) => {
+                let total = self
+                    .tipper_tips
+                    .may_load(store, (channel_id.clone(), tipper.clone(), denom.clone()))?
+                    .unwrap_or_default();
+                Some(Bound::exclusive((total.u128(), tipper)))
+            }
+            None => None,
+        };
+
+        let tippers = self
+            .tipper_ranking
+            .sub_prefix((channel_id, denom.clone()))
+            .keys(store, None, end, Order::Descending)
+            .take(limit)
+            .map(|item| {
+                item.map(|(amount, address)| TipperInfo {
+                    address: address.to_string(),
+                    amount: Coin {
+                        denom: denom.clone(),
+                        amount: Uint128::new(amount),
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(tippers)
+    }
+
+    /// Removes up to `limit` tip totals of an asset of a deleted channel.
+    /// The tip count is removed once no tip totals are left.
+    ///
+    /// # Returns
+    /// * The number of removed entries
+    pub fn delete_asset_tip_stats(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        publish_id: PublishId,
+        limit: usize,
+    ) -> Result<usize, ChannelError> {
+        let denoms = self
+            .asset_tips
+            .prefix((channel_id.clone(), publish_id.clone()))
+            .keys(store, None, None, Order::Ascending)
+            .take(limit)
+            .collect::<Result<Vec<_>, _>>()?;
+        for denom in denoms.iter() {
+            self.asset_tips.remove(
+                store,
+                (channel_id.clone(), publish_id.clone(), denom.clone()),
+            );
+        }
+        if denoms.len() < limit {
+            self.asset_tip_count.remove(store, (channel_id, publish_id));
+        }
+        Ok(denoms.len())
+    }
+
+    /// Removes up to `limit` tipper totals of a deleted channel, with their ranking entries,
+    /// followed by the channel totals. The tip count is removed once no totals are left.
+    /// Pending earnings are kept, as they are owed to the recipients.
+    ///
+    /// # Returns
+    /// * The number of removed entries
+    pub fn delete_channel_tip_stats(
+        &self,
+        store: &mut dyn Storage,
+        channel_id: ChannelId,
+        limit: usize,
+    ) -> Result<usize, ChannelError> {
+        let tipper_totals = self
+            .tipper_tips
+            .sub_prefix(channel_id.clone())
+            .range(store, None, None, Order::Ascending)
+            .take(limit)
+            .collect::<Result<Vec<_>, _>>()?;
+        for ((tipper, denom), total) in tipper_totals.iter() {
+            self.tipper_tips
+                .remove(store, (channel_id.clone(), tipper.clone(), denom.clone()));
+            self.tipper_ranking.remove(
+                store,
+                (
+                    (channel_id.clone(), denom.clone()),
+                    total.u128(),
+                    tipper.clone(),
+                ),
+            );
+        }
+        let mut removed = tipper_totals.len();
+        if removed < limit {
+            let denoms = self
+                .channel_tips
+                .prefix(channel_id.clone())
+                .keys(store, None, None, Order::Ascending)
+                .take(limit - removed)
+                .collect::<Result<Vec<_>, _>>()?;
+            for denom in denoms.iter() {
+                self.channel_tips
+                    .remove(store, (channel_id.clone(), denom.clone()));
+            }
+            removed += denoms.len();
+        }
+        if removed < limit {
+            self.channel_tip_count.remove(store, channel_id);
+        }
+        Ok(removed)
+    }
+
+    /// Credits a share of a tip to an address. Zero amounts are ignored.
+    pub fn credit_earnings(
+        &self,
+        store: &mut dyn Storage,
+        recipient: Addr,
+        amount: Coin,
+    ) -> Result<(), ChannelError> {
+        if amount.amount.is_zero() {
+            return Ok(());
+        }
+        add_amount(
+            &self.pending_earnings,
+            store,
+            (recipient, amount.denom),
+            amount.amount,
+        )?;
+        Ok(())
+    }
+
+    pub fn get_pending_earnings(
+        &self,
+        store: &dyn Storage,
+        recipient: Addr,
+    ) -> Result<Vec<Coin>, ChannelError> {
+        let earnings = self
+            .pending_earnings
+            .prefix(recipient)
+            .range(store, None, None, Order::Ascending)
+            .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(earnings)
+    }
+
+    /// Clears the pending earnings of an address and returns them
+    pub fn take_pending_earnings(
+        &self,
+        store: &mut dyn Storage,
+        recipient: Addr,
+    ) -> Result<Vec<Coin>, ChannelError> {
+        let earnings = self.get_pending_earnings(store, recipient.clone())?;
+        for coin in earnings.iter() {
+            self.pending_earnings
+                .remove(store, (recipient.clone(), coin.denom.clone()));
+        }
+        Ok(earnings)
+    }
+}
+
+/// Adds an amount to a stored total and returns the new total
+fn add_amount<'a, K>(
+    map: &Map<K, Uint128>,
+    store: &mut dyn Storage,
+    key: K,
+    amount: Uint128,
+) -> Result<Uint128, ChannelError>
+where
+    K: cw_storage_plus::PrimaryKey<'a>,
+{
+    let total = map
+        .may_load(store, key.clone())?
+        .unwrap_or_default()
+        .checked_add(amount)
+        .map_err(|_| ChannelError::SaveTipStatsFailed {})?;
+    map.save(store, key, &total)?;
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{coin, testing::mock_dependencies};
+
+    #[test]
+    fn test_record_tip() {
+        let mut deps = mock_dependencies();
+        let tips = TipsManager::new();
+        let channel_id = "channel1".to_string();
+        let tipper1 = Addr::unchecked("tipper1");
+        let tipper2 = Addr::unchecked("tipper2");
+
+        tips.record_tip(
+            &mut deps.storage,
+            channel_id.clone(),
+            Some("asset1".to_string()),
+            tipper1.clone(),
+            vec![coin(100, "uatom"), coin(1000, "uflix")],
+        )
+        .unwrap();
+        tips.record_tip(
+            &mut deps.storage,
+            channel_id.clone(),
+            None,
+            tipper2.clone(),
+            vec![coin(600, "uflix")],
+        )
+        .unwrap();
+        tips.record_tip(
+            &mut deps.storage,
+            channel_id.clone(),
+            None,
+            tipper2.clone(),
+            vec![coin(600, "uflix")],
+        )
+        .unwrap();
+
+        let stats = tips
+            .get_channel_tip_stats(&deps.storage, channel_id.clone())
+            .unwrap();
+        assert_eq!(stats.total, vec![coin(100, "uatom"), coin(2200, "uflix")]);
+        assert_eq!(stats.tip_count, 3);
+
+        let stats = tips
+            .get_asset_tip_stats(&deps.storage, channel_id.clone(), "asset1".to_string())
+            .unwrap();
+        assert_eq!(stats.total, vec![coin(100, "uatom"), coin(1000, "uflix")]);
+        assert_eq!(stats.tip_count, 1);
+
+        let totals = tips
+            .get_tipper_totals(&deps.storage, channel_id.clone(), tipper2.clone())
+            .unwrap();
+        assert_eq!(totals, vec![coin(1200, "uflix")]);
+
+        // Tipper 2 overtook tipper 1 and the old ranking entry is removed
+        let top = tips
+            .get_top_tippers(
+                &deps.storage,
+                channel_id.clone(),
+                "uflix".to_string(),
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            top,
+            vec![
+                TipperInfo {
+                    address: tipper2.to_string(),
+                    amount: coin(1200, "uflix"),
+                },
+                TipperInfo {
+                    address: tipper1.to_string(),
+                    amount: coin(1000, "uflix"),
+                },
+            ]
+        );
+
+        // Pagination
+        let top = tips
+            .get_top_tippers(
+                &deps.storage,
+                channel_id.clone(),
+                "uflix".to_string(),
+                Some(tipper2),
+                Some(1),
+            )
+            .unwrap();
+        assert_eq!(
+            top,
+            vec![TipperInfo {
+                address: tipper1.to_string(),
+                amount: coin(1000, "uflix"),
+            }]
+        );
+
+        // Unknown channel has no stats
+        let stats = tips
+            .get_channel_tip_stats(&deps.storage, "channel2".to_string())
+            .unwrap();
+        assert_eq!(stats.total, vec![]);
+        assert_eq!(stats.tip_count, 0);
+    }
+
+    #[test]
+    fn test_delete_channel_tip_stats() {
+        let mut deps = mock_dependencies();
+        let tips = TipsManager::new();
+        let channel_id = "channel1".to_string();
+
+        for i in 0..3 {
+            tips.record_tip(
+                &mut deps.storage,
+                channel_id.clone(),
+                Some("asset1".to_string()),
+                Addr::unchecked(format!("tipper{}", i)),
+                vec![coin(100, "uflix")],
+            )
+            .unwrap();
+        }
+        tips.record_tip(
+            &mut deps.storage,
+            channel_id.clone(),
+            Some("asset1".to_string()),
+            Addr::unchecked("tipper0"),
+            vec![coin(100, "uatom")],
+        )
+        .unwrap();
+
+        // The asset tip count is kept until all asset totals are removed
+        let removed = tips
+            .delete_asset_tip_stats(
+                &mut deps.storage,
+                channel_id.clone(),
+                "asset1".to_string(),
+                1,
+            )
+            .unwrap();
+        assert_eq!(removed, 1);
+        let stats = tips
+            .get_asset_tip_stats(&deps.storage, channel_id.clone(), "asset1".to_string())
+            .unwrap();
+        assert_eq!(stats.total.len(), 1);
+        assert_eq!(stats.tip_count, 4);
+
+        let removed = tips
+            .delete_asset_tip_stats(
+                &mut deps.storage,
+                channel_id.clone(),
+                "asset1".to_string(),
+                2,
+            )
+            .unwrap();
+        assert_eq!(removed, 1);
+        let stats = tips
+            .get_asset_tip_stats(&deps.storage, channel_id.clone(), "asset1".to_string())
+            .unwrap();
+        assert_eq!(stats.total, vec![]);
+        assert_eq!(stats.tip_count, 0);
+
+        // Channel totals are kept until all tipper totals are removed
+        let removed = tips
+            .delete_channel_tip_stats(&mut deps.storage, channel_id.clone(), 2)
+            .unwrap();
+        assert_eq!(removed, 2);
+        let stats = tips
+            .get_channel_tip_stats(&deps.storage, channel_id.clone())
+            .unwrap();
+        assert_eq!(stats.total.len(), 2);
+        assert_eq!(stats.tip_count, 4);
+
+        // The channel totals count against the limit
+        let removed = tips
+            .delete_channel_tip_stats(&mut deps.storage, channel_id.clone(), 3)
+            .unwrap();
+        assert_eq!(removed, 3);
+        let stats = tips
+            .get_channel_tip_stats(&deps.storage, channel_id.clone())
+            .unwrap();
+        assert_eq!(stats.total.len(), 1);
+        assert_eq!(stats.tip_count, 4);
+
+        let removed = tips
+            .delete_channel_tip_stats(&mut deps.storage, channel_id.clone(), 3)
+            .unwrap();
+        assert_eq!(removed, 1);
+        let stats = tips
+            .get_channel_tip_stats(&deps.storage, channel_id.clone())
+            .unwrap();
+        assert_eq!(stats.total, vec![]);
+        assert_eq!(stats.tip_count, 0);
+
+        let top = tips
+            .get_top_tippers(&deps.storage, channel_id, "uflix".to_string(), None, None)
+            .unwrap();
+        assert_eq!(top, vec![]);
+    }
+
+    #[test]
+    fn test_pending_earnings() {
+        let mut deps = mock_dependencies();
+        let tips = TipsManager::new();
+        let recipient = Addr::unchecked("recipient");
+
+        tips.credit_earnings(&mut deps.storage, recipient.clone(), coin(100, "uflix"))
+            .unwrap();
+        tips.credit_earnings(&mut deps.storage, recipient.clone(), coin(50, "uflix"))
+            .unwrap();
+        tips.credit_earnings(&mut deps.storage, recipient.clone(), coin(0, "uatom"))
+            .unwrap();
+
+        let earnings = tips
+            .get_pending_earnings(&deps.storage, recipient.clone())
+            .unwrap();
+        assert_eq!(earnings, vec![coin(150, "uflix")]);
+
+        let earnings = tips
+            .take_pending_earnings(&mut deps.storage, recipient.clone())
+            .unwrap();
+        assert_eq!(earnings, vec![coin(150, "uflix")]);
+
+        let earnings = tips.get_pending_earnings(&deps.storage, recipient).unwrap();
+        assert_eq!(earnings, vec![]);
+    }
+}
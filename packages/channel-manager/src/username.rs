@@ -0,0 +1,38 @@
+use omniflix_channel_types::channel::UserName;
+
+/// Separators that are dropped from the canonical form of a username
+const USERNAME_SEPARATORS: [char; 3] = ['_', '-', '.'];
+
+/// Returns the canonical form of a username, used as the key for uniqueness,
+/// reservations, holds and lookups. The display casing is kept in the channel details.
+///
+/// The canonical form is lowercase, has no separators and folds characters that
+/// look alike (`0` to `o`, `1` and `i` to `l`), so `Alice`, `alice_` and `a1ice`
+/// all map to the same key.
+pub fn normalize_username(user_name: &str) -> UserName {
+    user_name
+        .chars()
+        .filter(|c| !USERNAME_SEPARATORS.contains(c))
+        .map(|c| match c.to_ascii_lowercase() {
+            '0' => 'o',
+            '1' | 'i' => 'l',
+            c => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_username() {
+        assert_eq!(normalize_username("alice"), "allce");
+        assert_eq!(normalize_username("Alice"), normalize_username("alice"));
+        assert_eq!(normalize_username("alice_"), normalize_username("alice"));
+        assert_eq!(normalize_username("a.l-i_c.e"), normalize_username("alice"));
+        assert_eq!(normalize_username("ALICE"), normalize_username("a1ice"));
+        assert_eq!(normalize_username("b0b"), normalize_username("bob"));
+        assert_ne!(normalize_username("alice"), normalize_username("alicia"));
+    }
+}
@@ -2,7 +2,8 @@ use anyhow::{bail, Result};
 use cosmwasm_std::{from_json, to_json_binary, Addr, Api, Binary, BlockInfo, Querier, Storage};
 use cw_multi_test::{error::AnyResult, AppResponse, CosmosRouter, Stargate};
 use omniflix_std::types::omniflix::onft::v1beta1::{
-    Collection, Denom, MsgCreateDenom, MsgMintOnft, QueryOnftRequest, QueryOnftResponse,
+    Collection, Denom, IdCollection, MsgCreateDenom, MsgMintOnft, MsgTransferOnft, Owner,
+    QueryOnftRequest, QueryOnftResponse, QueryOwnerOnfTsRequest, QueryOwnerOnfTsResponse,
 };
 use omniflix_std::types::{
     cosmos::base::v1beta1::Coin,
@@ -76,6 +77,28 @@ impl Stargate for StargateKeeper {
                 storage.set(key.as_bytes(), &serialized_collection);
             };
         }
+        if type_url == *"/OmniFlix.onft.v1beta1.MsgTransferONFT" {
+            let parsed_msg: Result<MsgTransferOnft, DecodeError> =
+                Message::decode(value.as_slice());
+            if let Ok(msg) = parsed_msg {
+                let key = format!("collections:{}:{}", COLLECTION_PREFIX, msg.denom_id);
+                let serialized_collection = storage.get(key.as_bytes());
+                let mut collection: Collection = from_json(serialized_collection.unwrap())
+                    .expect("Failed to deserialize Collection");
+                let onft = collection
+                    .onfts
+                    .iter_mut()
+                    .find(|onft| onft.id == msg.id)
+                    .expect("Onft not found");
+                if onft.owner != sender.to_string() {
+                    bail!("Onft not owned by sender");
+                }
+                onft.owner = msg.recipient;
+                let serialized_collection =
+                    to_json_binary(&collection).expect("Failed to serialize Collection");
+                storage.set(key.as_bytes(), &serialized_collection);
+            };
+        }
         Ok(AppResponse::default())
     }
 
@@ -120,6 +143,37 @@ impl Stargate for StargateKeeper {
                 return Ok(to_json_binary(&response)?);
             }
         }
+        if path == *"/OmniFlix.onft.v1beta1.Query/OwnerONFTs" {
+            let query_msg: Result<QueryOwnerOnfTsRequest, DecodeError> =
+                Message::decode(data.as_slice());
+            if let Ok(msg) = query_msg {
+                let key = format!("collections:{}:{}", COLLECTION_PREFIX, msg.denom_id);
+                let onft_ids = match storage.get(key.as_bytes()) {
+                    Some(serialized_collection) => {
+                        let collection: Collection = from_json(serialized_collection)
+                            .expect("Failed to deserialize Collection");
+                        collection
+                            .onfts
+                            .into_iter()
+                            .filter(|onft| onft.owner == msg.owner)
+                            .map(|onft| onft.id)
+                            .collect()
+                    }
+                    None => vec![],
+                };
+                let response = QueryOwnerOnfTsResponse {
+                    owner: Some(Owner {
+                        address: msg.owner,
+                        id_collections: vec![IdCollection {
+                            denom_id: msg.denom_id,
+                            onft_ids,
+                        }],
+                    }),
+                    pagination: None,
+                };
+                return Ok(to_json_binary(&response)?);
+            }
+        }
         Ok(data)
     }
 
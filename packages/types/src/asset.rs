@@ -1,4 +1,5 @@
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Timestamp};
 
 use crate::channel::ChannelId;
 
@@ -37,12 +38,82 @@ impl std::fmt::Display for AssetSource {
     }
 }
 
+/// Who can access the media of a visible asset.
+/// Channel owners and collaborators can always access the assets of their channel.
+#[cw_serde]
+#[derive(Default)]
+pub enum AccessPolicy {
+    #[default]
+    Public,
+    /// Followers and active subscribers of the channel
+    Followers,
+    /// Active subscribers of the channel
+    Subscribers,
+    /// Holders of at least one ONFT of the collection
+    CollectionHolders { collection_id: String },
+}
+
+impl std::fmt::Display for AccessPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AccessPolicy::Public => write!(f, "public"),
+            AccessPolicy::Followers => write!(f, "followers"),
+            AccessPolicy::Subscribers => write!(f, "subscribers"),
+            AccessPolicy::CollectionHolders { collection_id } => {
+                write!(f, "collection_holders: {}", collection_id)
+            }
+        }
+    }
+}
+
 #[cw_serde]
 pub struct Asset {
     pub channel_id: String,
     pub publish_id: String,
     pub asset_source: AssetSource,
     pub is_visible: bool,
+    /// Assets published before access policies existed are public
+    #[serde(default)]
+    pub access_policy: AccessPolicy,
+    /// Price of a pay-per-view asset. Buyers can always access the asset while it is visible.
+    #[serde(default)]
+    pub price: Option<Coin>,
+    /// Scheduled publish time. The asset is not visible before this time.
+    #[serde(default)]
+    pub publish_at: Option<Timestamp>,
+    /// Set once the asset crosses a flag threshold. The asset is hidden until it is restored.
+    #[serde(default)]
+    pub under_review: bool,
+}
+
+impl Asset {
+    /// Returns true if the asset is visible, not under review and its scheduled publish time,
+    /// if any, has passed
+    pub fn is_live(&self, now: Timestamp) -> bool {
+        self.is_visible
+            && !self.under_review
+            && self.publish_at.is_none_or(|publish_at| publish_at <= now)
+    }
+}
+
+/// Record of a pay-per-view purchase, kept after the asset is unpublished
+#[cw_serde]
+pub struct PurchaseReceipt {
+    pub buyer: Addr,
+    /// The price paid, as the asset price may change later
+    pub price: Coin,
+    pub purchased_at: Timestamp,
+}
+
+/// Record of a flag raised by an account, kept until the flag is retracted or reset
+#[cw_serde]
+pub struct FlagReport {
+    pub reporter: Addr,
+    /// The flag as raised, including the reason text of `Flag::Other`
+    pub flag: Flag,
+    /// The interactive video the flag was raised from, if any
+    pub interactive_video_id: Option<String>,
+    pub reported_at: Timestamp,
 }
 
 #[cw_serde]
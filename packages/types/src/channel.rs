@@ -1,5 +1,6 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal};
+use cosmwasm_std::{Addr, BlockInfo, Coin, Decimal, Timestamp};
+use cw_utils::Expiration;
 pub type ChannelId = String;
 pub type UserName = String;
 
@@ -30,6 +31,87 @@ pub struct ChannelOnftData {
 pub struct ChannelCollaborator {
     pub role: Role,
     pub share: Decimal,
+    /// (Optional) The time after which the collaborator loses its role and share.
+    /// Never expires if not set.
+    pub expires_at: Option<Timestamp>,
+}
+
+impl ChannelCollaborator {
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| block.time >= expires_at)
+    }
+}
+
+/// A username released by a username change, held for its previous channel
+#[cw_serde]
+pub struct UsernameHold {
+    pub channel_id: ChannelId,
+    pub expires_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct CollaboratorInvite {
+    pub collaborator: ChannelCollaborator,
+    /// (Optional) The expiration of the invite. Never expires if not set.
+    pub expiry: Option<Expiration>,
+}
+
+/// A suspension of a channel by the protocol admin or a protocol moderator
+#[cw_serde]
+pub struct ChannelSuspension {
+    pub reason: String,
+    pub suspended_at: Timestamp,
+    /// (Optional) The end of the suspension. Lasts until the channel is unsuspended if not set.
+    pub until: Option<Timestamp>,
+}
+
+impl ChannelSuspension {
+    pub fn is_active(&self, now: Timestamp) -> bool {
+        self.until.is_none_or(|until| now < until)
+    }
+}
+
+/// The paid subscription tier of a channel
+#[cw_serde]
+pub struct SubscriptionPlan {
+    /// The price of a single period, in an accepted tip denom
+    pub price: Coin,
+    /// The length of a period in seconds
+    pub period: u64,
+}
+
+/// How tips to a channel reach the collaborators and the payment address
+#[cw_serde]
+#[derive(Default)]
+pub enum PayoutMode {
+    /// Shares are sent with a bank message as part of the tip
+    #[default]
+    Push,
+    /// Shares are credited in the contract and claimed with `WithdrawEarnings`
+    Pull,
+}
+
+/// The per-channel state that is removed after a channel is deleted, in order
+#[cw_serde]
+pub enum CleanupStage {
+    Collaborators,
+    CollaboratorInvites,
+    Followers,
+    Subscriptions,
+    UsernameHolds,
+    Assets,
+    Playlists,
+    TipStats,
+}
+
+/// Progress of the cleanup of a deleted channel that did not fit in a single transaction
+#[cw_serde]
+pub struct ChannelCleanupProgress {
+    /// The stage to continue the cleanup from
+    pub stage: CleanupStage,
+    /// Number of entries removed so far
+    pub removed_entries: u64,
 }
 
 #[cw_serde]
@@ -49,3 +131,12 @@ impl Display for Role {
         }
     }
 }
+
+impl Display for PayoutMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            PayoutMode::Push => write!(f, "push"),
+            PayoutMode::Pull => write!(f, "pull"),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Coin, Decimal};
+use cw_utils::Expiration;
 
 #[cw_serde]
 pub struct ChannelConractConfig {
@@ -7,6 +8,26 @@ pub struct ChannelConractConfig {
     pub channel_creation_fee: Vec<Coin>,
     pub accepted_tip_denoms: Vec<String>,
     pub auth_details: AuthDetails,
+    /// The share of every tip sent to the fee collector. Defaults to zero for configs
+    /// saved before the field existed.
+    #[serde(default)]
+    pub tip_fee_rate: Decimal,
+    /// The fee sent to the fee collector when a channel changes its username
+    #[serde(default)]
+    pub username_change_fee: Vec<Coin>,
+    /// Seconds a username released by a username change is held for its previous channel
+    #[serde(default = "default_username_hold_period")]
+    pub username_hold_period: u64,
+}
+
+/// The highest tip fee rate the protocol admin can set
+pub const MAX_TIP_FEE_RATE: Decimal = Decimal::percent(20);
+
+/// The username hold period of a new contract, seven days
+pub const DEFAULT_USERNAME_HOLD_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+fn default_username_hold_period() -> u64 {
+    DEFAULT_USERNAME_HOLD_PERIOD
 }
 
 #[cw_serde]
@@ -14,3 +35,11 @@ pub struct AuthDetails {
     pub protocol_admin: Addr,
     pub fee_collector: Addr,
 }
+
+#[cw_serde]
+pub struct PendingProtocolAdmin {
+    /// The address proposed as the new protocol admin
+    pub address: Addr,
+    /// (Optional) The expiration of the proposal. Never expires if not set.
+    pub expiry: Option<Expiration>,
+}
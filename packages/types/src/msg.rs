@@ -1,10 +1,17 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary, Coin, Decimal};
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Timestamp, Uint128};
+use cw_utils::Expiration;
 
 use crate::{
-    asset::{Asset, AssetKey, AssetMetadata, AssetSource, Flag, Playlist},
-    channel::{ChannelCollaborator, ChannelDetails, ChannelMetadata},
-    config::ChannelConractConfig,
+    asset::{
+        AccessPolicy, Asset, AssetKey, AssetMetadata, AssetSource, Flag, FlagReport, Playlist,
+        PurchaseReceipt,
+    },
+    channel::{
+        ChannelCleanupProgress, ChannelCollaborator, ChannelDetails, ChannelMetadata,
+        ChannelSuspension, PayoutMode, Role, SubscriptionPlan,
+    },
+    config::{ChannelConractConfig, PendingProtocolAdmin},
 };
 
 #[cw_serde]
@@ -18,6 +25,9 @@ pub struct InstantiateMsg {
     pub reserved_usernames: Vec<ReservedUsername>,
 }
 
+#[cw_serde]
+pub struct MigrateMsg {}
+
 #[cw_serde]
 pub struct ChannelsCollectionDetails {
     pub collection_id: String,
@@ -49,26 +59,111 @@ pub struct ReservedUsername {
     pub address: Option<Addr>,
 }
 
+#[cw_serde]
+pub struct TipDenom {
+    pub denom: String,
+    /// (Optional) The minimum amount accepted for a single tip in this denom.
+    pub min_amount: Option<Uint128>,
+}
+
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Updates the configuration of the contract, including the channel creation fee,
-    /// protocol admin, and fee collector. Only callable by the protocol admin.
+    /// fee collector and tip fee rate. Only callable by the protocol admin.
+    /// The protocol admin is changed through `AdminProposeProtocolAdmin`.
     AdminSetConfig {
         /// (Optional) The new channel creation fee.
         channel_creation_fee: Option<Vec<Coin>>,
-        /// (Optional) The new admin address.
-        protocol_admin: Option<String>,
         /// (Optional) The new fee collector address.
         fee_collector: Option<String>,
+        /// (Optional) The new share of each tip sent to the fee collector.
+        /// Can not exceed `MAX_TIP_FEE_RATE`.
+        tip_fee_rate: Option<Decimal>,
+        /// (Optional) The new fee for changing the username of a channel.
+        username_change_fee: Option<Vec<Coin>>,
+        /// (Optional) The new number of seconds a released username is held for its previous channel.
+        username_hold_period: Option<u64>,
+    },
+    /// Proposes a new protocol admin. The proposed address must accept the role
+    /// with `AdminAcceptProtocolAdmin` before it takes effect.
+    /// Replaces any existing proposal. Only callable by the protocol admin.
+    AdminProposeProtocolAdmin {
+        /// The address of the proposed protocol admin.
+        new_admin: String,
+        /// (Optional) The expiration of the proposal.
+        expiry: Option<Expiration>,
     },
+    /// Accepts a pending protocol admin proposal.
+    /// Only callable by the proposed address before the proposal expires.
+    AdminAcceptProtocolAdmin {},
+    /// Cancels a pending protocol admin proposal.
+    /// Only callable by the protocol admin.
+    AdminCancelProtocolAdmin {},
     /// Removes assets from the contract.
-    /// Only callable by the protocol admin.    
+    /// Only callable by the protocol admin or a protocol moderator.
     AdminRemoveAssets {
         /// The keys of the assets to be removed.
         asset_keys: Vec<AssetKey>,
-        /// Removes all flags from the assets if set to true.
+        /// Removes the first batch of the flags of all assets if set to true.
+        /// The rest is removed with `AdminClearAllFlags`. Only the protocol admin can set this.
         refresh_flags: Option<bool>,
     },
+    /// Manages the protocol moderators.
+    /// Only callable by the protocol admin.
+    AdminManageModerators {
+        /// (Optional) A list of addresses to be added as moderators.
+        add_moderators: Option<Vec<String>>,
+        /// (Optional) A list of addresses to be removed from the moderators.
+        remove_moderators: Option<Vec<String>>,
+    },
+    /// Puts assets under review, hiding them until they are restored.
+    /// Only callable by the protocol admin or a protocol moderator.
+    AdminHideAssets {
+        /// The keys of the assets to be hidden.
+        asset_keys: Vec<AssetKey>,
+    },
+    /// Suspends a channel. While suspended, the channel can not publish, create playlists,
+    /// update its details or receive tips. Suspending a suspended channel replaces its suspension.
+    /// Only callable by the protocol admin or a protocol moderator.
+    AdminSuspendChannel {
+        /// The ID of the channel to be suspended.
+        channel_id: String,
+        /// The reason shown with the channel.
+        reason: String,
+        /// (Optional) The end of the suspension. Lasts until the channel is unsuspended if not set.
+        until: Option<Timestamp>,
+    },
+    /// Lifts the suspension of a channel.
+    /// Only callable by the protocol admin or a protocol moderator.
+    AdminUnsuspendChannel {
+        /// The ID of the suspended channel.
+        channel_id: String,
+    },
+    /// Removes the flags of the given assets.
+    /// Only callable by the protocol admin or a protocol moderator.
+    AdminResetFlags {
+        /// The keys of the assets whose flags are removed.
+        asset_keys: Vec<AssetKey>,
+        /// (Optional) The kinds of flags to be removed. Removes every kind if not set.
+        flags: Option<Vec<Flag>>,
+    },
+    /// Removes the flags of all assets in batches. Call repeatedly until the
+    /// `flags_cleared` attribute is true. Only callable by the protocol admin.
+    AdminClearAllFlags {
+        /// (Optional) The maximum number of entries to remove.
+        limit: Option<u32>,
+    },
+    /// Sets the flag thresholds. An asset is put under review, hiding it, once the number of
+    /// accounts that raised a flag reaches the threshold of that flag.
+    /// A threshold of zero removes the threshold of the flag.
+    /// Only callable by the protocol admin.
+    AdminSetFlagThresholds { thresholds: Vec<FlagThreshold> },
+    /// Takes assets out of review, making them visible again.
+    /// Only callable by the protocol admin or a protocol moderator.
+    AdminRestoreAssets {
+        /// The keys of the assets under review to be restored.
+        asset_keys: Vec<AssetKey>,
+    },
     /// Manages reserved usernames.
     /// Only callable by the protocol admin.
     /// Can set an address as a reserved username
@@ -80,6 +175,15 @@ pub enum ExecuteMsg {
         /// (Optional) A list of addresses to be removed from reserved usernames.
         remove_usernames: Option<Vec<String>>,
     },
+    /// Manages the denoms accepted for tipping.
+    /// Only callable by the protocol admin.
+    /// Adding a denom that is already accepted updates its minimum amount.
+    AdminManageTipDenoms {
+        /// (Optional) A list of denoms to be accepted for tipping.
+        add_tip_denoms: Option<Vec<TipDenom>>,
+        /// (Optional) A list of denoms to be removed from the accepted tip denoms.
+        remove_tip_denoms: Option<Vec<String>>,
+    },
     /// Pauses all channel-related operations. Only callable by a pauser.
     Pause {},
 
@@ -107,6 +211,13 @@ pub enum ExecuteMsg {
         is_visible: bool,
         /// The metadata of the asset.
         metadata: AssetMetadata,
+        /// (Optional) Who can access the asset. Defaults to public.
+        access_policy: Option<AccessPolicy>,
+        /// (Optional) The price of the asset, making it pay-per-view.
+        price: Option<Coin>,
+        /// (Optional) The time the asset goes live. The asset is not visible
+        /// and can not be added to playlists before this time.
+        publish_at: Option<Timestamp>,
     },
 
     /// Unpublishes an asset from a channel. The publish ID and related asset details will
@@ -135,7 +246,36 @@ pub enum ExecuteMsg {
         media_uri: Option<String>,
         /// The new thumbnail URI of the asset.
         thumbnail_uri: Option<String>,
+        /// The new access policy of the asset.
+        access_policy: Option<AccessPolicy>,
+        /// The new scheduled publish time. A time that has passed publishes the asset right away.
+        publish_at: Option<Timestamp>,
+        /// (Optional) Removes the scheduled publish time. Can not be combined with `publish_at`.
+        #[serde(default)]
+        clear_publish_at: Option<bool>,
+    },
+
+    /// Sets or removes the pay-per-view price of an asset. The price must be in an
+    /// accepted tip denom. Only callable by the channel owner or a collaborator.
+    AssetSetPrice {
+        /// The ID of the channel where the asset is published.
+        channel_id: String,
+        /// The ID of the publish to be priced.
+        publish_id: String,
+        /// The new price, or `None` to stop selling the asset.
+        price: Option<Coin>,
     },
+
+    /// Purchases a pay-per-view asset. The funds must match the asset price.
+    /// The protocol fee is deducted and the rest is split between the channel
+    /// collaborators and the payment address, like a tip.
+    AssetPurchase {
+        /// The ID of the channel where the asset is published.
+        channel_id: String,
+        /// The ID of the publish to be purchased.
+        publish_id: String,
+    },
+    /// Flags an asset. Each account can raise each kind of flag once per asset.
     AssetFlag {
         /// The ID of the channel where the asset is published.
         channel_id: String,
@@ -143,9 +283,18 @@ pub enum ExecuteMsg {
         publish_id: String,
         /// The flag value.
         flag: Flag,
-        /// Interactive video id. Only for indexing purposes. Not used for anything else.
+        /// (Optional) The interactive video the flag is raised from. Stored with the flag report.
         interactive_video_id: Option<String>,
     },
+    /// Retracts a flag the sender raised on an asset.
+    AssetRetractFlag {
+        /// The ID of the channel where the asset is published.
+        channel_id: String,
+        /// The ID of the flagged publish.
+        publish_id: String,
+        /// The flag to retract.
+        flag: Flag,
+    },
     /// Creates a new playlist in the specified channel.
     /// Only callable by the channel owner or a collaborator.
     PlaylistCreate {
@@ -221,10 +370,30 @@ pub enum ExecuteMsg {
 
     /// Deletes an existing channel. The channel ID and related details will be removed
     /// from the contract state. Only callable by the channel owner.
+    /// If the channel has more state than can be removed in one transaction,
+    /// the rest is removed with `ChannelCleanup`.
     ChannelDelete {
         /// The ID of the channel to be deleted.
         channel_id: String,
     },
+    /// Continues removing the state of a deleted channel. Callable by anyone.
+    ChannelCleanup {
+        /// The ID of the deleted channel.
+        channel_id: String,
+        /// (Optional) The maximum number of entries to remove.
+        limit: Option<u32>,
+    },
+    /// Changes the username of a channel. The previous username is held for the channel
+    /// for the configured hold period before anyone else can claim it.
+    /// Requires the username change fee. Only callable by the channel owner.
+    /// The channel ONFT keeps the username it was minted with, the current username is
+    /// returned by the `Channel` and `ChannelDetails` queries.
+    ChannelChangeUsername {
+        /// The ID of the channel.
+        channel_id: String,
+        /// The new username of the channel.
+        new_user_name: String,
+    },
     /// Updates the details of an existing channel. Only callable by the channel owner.
     ChannelUpdateDetails {
         /// The ID of the channel to be updated.
@@ -241,26 +410,64 @@ pub enum ExecuteMsg {
         /// (Optional) The new payment address of the channel.
         payment_address: Option<String>,
     },
+    /// Sets how tips to a channel are paid out. In `PayoutMode::Pull` the shares are
+    /// credited in the contract and claimed with `WithdrawEarnings`.
+    /// Only callable by the channel owner or an admin collaborator.
+    ChannelSetPayoutMode {
+        /// The ID of the channel.
+        channel_id: String,
+        /// The new payout mode.
+        payout_mode: PayoutMode,
+    },
+    /// Sends all tip shares credited to the sender.
+    WithdrawEarnings {},
     /// Tipping a channel
     ChannelTip {
         /// The ID of the channel to be tipped.
         channel_id: String,
-        /// The amount of tokens to be tipped.
-        amount: Coin,
-        /// The asset id to be tipped. Only for indexing purposes. Not used for anything else.
+        /// The tokens to be tipped. Must match the funds sent with the message.
+        /// Each denom is split among the collaborators separately.
+        amount: Vec<Coin>,
+        /// (Optional) The publish ID of the tipped asset in this channel.
+        /// Only used to record the tip in the asset statistics.
         asset_id: Option<String>,
     },
-    /// Adds a collaborator to a channel.
+    /// Invites a collaborator to a channel. The collaborator is added, and its share
+    /// counted, once the invite is accepted. Replaces any existing invite for the address.
     /// Only callable by the channel owner.
-    ChannelAddCollaborator {
-        /// The ID of the channel to add the collaborator to.
+    ChannelInviteCollaborator {
+        /// The ID of the channel to invite the collaborator to.
         channel_id: String,
-        /// The address of the collaborator to be added.
+        /// The address of the collaborator to be invited.
         collaborator_address: String,
         /// Collaborator details
         collaborator_details: ChannelCollaborator,
+        /// (Optional) The expiration of the invite. Never expires if not set.
+        expiry: Option<Expiration>,
+    },
+    /// Accepts a pending collaborator invite. Only callable by the invited address.
+    ChannelAcceptCollaboratorInvite {
+        /// The ID of the channel the sender was invited to.
+        channel_id: String,
+    },
+    /// Declines a pending collaborator invite. Only callable by the invited address.
+    ChannelDeclineCollaboratorInvite {
+        /// The ID of the channel the sender was invited to.
+        channel_id: String,
     },
-    /// Removes a collaborator from a channel.
+    /// Changes the role and/or share of a collaborator.
+    /// Only callable by the channel owner.
+    ChannelUpdateCollaborator {
+        /// The ID of the channel.
+        channel_id: String,
+        /// The address of the collaborator to be updated.
+        collaborator_address: String,
+        /// (Optional) The new role of the collaborator.
+        role: Option<Role>,
+        /// (Optional) The new share of the collaborator.
+        share: Option<Decimal>,
+    },
+    /// Removes a collaborator from a channel, or cancels a pending invite.
     /// Only callable by the channel owner.
     ChannelRemoveCollaborator {
         /// The ID of the channel to remove the collaborator from.
@@ -268,6 +475,23 @@ pub enum ExecuteMsg {
         /// The address of the collaborator to be removed.
         collaborator_address: String,
     },
+    /// Removes the sender from the collaborators of a channel.
+    ChannelLeaveCollaboration {
+        /// The ID of the channel to leave.
+        channel_id: String,
+    },
+    /// Removes the expired collaborators of a channel and recalculates the total collaborator shares.
+    /// Callable by anyone.
+    ChannelPruneCollaborators {
+        /// The ID of the channel to prune.
+        channel_id: String,
+    },
+    /// Records the current owner of the channel ONFT, for channels whose ONFT was transferred.
+    /// Callable by anyone.
+    ChannelSyncOwner {
+        /// The ID of the channel to sync.
+        channel_id: String,
+    },
 
     /// Follow a channel
     ChannelFollow {
@@ -280,6 +504,25 @@ pub enum ExecuteMsg {
         /// The ID of the channel to unfollow.
         channel_id: String,
     },
+
+    /// Sets or removes the paid subscription plan of a channel.
+    /// Existing subscriptions stay active until they expire.
+    /// Only callable by the channel owner.
+    ChannelSetSubscriptionPlan {
+        /// The ID of the channel.
+        channel_id: String,
+        /// The new plan. Removes the plan if not set.
+        plan: Option<SubscriptionPlan>,
+    },
+
+    /// Prepays one or more periods of a channel subscription.
+    /// The funds must match the plan price times the number of periods.
+    ChannelSubscribe {
+        /// The ID of the channel to subscribe to.
+        channel_id: String,
+        /// The number of periods to pay for.
+        periods: u32,
+    },
 }
 
 #[cw_serde]
@@ -332,6 +575,14 @@ pub enum QueryMsg {
     #[returns(ChannelConractConfig)]
     Config {},
 
+    /// Query the denoms accepted for tipping with their minimum amounts.
+    #[returns(Vec<TipDenom>)]
+    AcceptedTipDenoms {},
+
+    /// Query the pending protocol admin proposal, if any.
+    #[returns(Option<PendingProtocolAdmin>)]
+    PendingProtocolAdmin {},
+
     #[returns(Vec<AssetResponse>)]
     Assets {
         channel_id: String,
@@ -345,6 +596,82 @@ pub enum QueryMsg {
         publish_id: String,
     },
 
+    /// Query if an address can access the media of an asset under its access policy.
+    /// Hidden assets are only accessible to the channel owner and collaborators.
+    #[returns(bool)]
+    CanAccessAsset {
+        address: String,
+        channel_id: String,
+        publish_id: String,
+    },
+
+    /// Query the visible assets of a channel that are scheduled to go live, ordered by
+    /// their publish time.
+    #[returns(Vec<AssetResponse>)]
+    ScheduledAssets {
+        channel_id: String,
+        /// Optional publish ID to start pagination after.
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Query the flag thresholds set by the protocol admin.
+    #[returns(Vec<FlagThreshold>)]
+    FlagThresholds {},
+
+    /// Query the assets under review, ordered by asset key.
+    #[returns(Vec<AssetResponse>)]
+    ReviewQueue {
+        /// Optional asset key to start pagination after.
+        start_after: Option<AssetKey>,
+        limit: Option<u32>,
+    },
+
+    /// Query the accounts that flagged an asset with their flags, ordered by address.
+    #[returns(Vec<FlaggerInfo>)]
+    AssetFlaggers {
+        channel_id: String,
+        publish_id: String,
+        /// Optional flagger address to start pagination after.
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Query the flag reports of an asset, ordered by reporter address and flag.
+    #[returns(Vec<FlagReport>)]
+    AssetFlagReports {
+        channel_id: String,
+        publish_id: String,
+        /// Optional reporter address and flag to start pagination after.
+        start_after: Option<(String, Flag)>,
+        limit: Option<u32>,
+    },
+
+    /// Query if an address has purchased an asset.
+    #[returns(bool)]
+    HasPurchased {
+        channel_id: String,
+        publish_id: String,
+        address: String,
+    },
+
+    /// Query the purchase receipts of an asset, ordered by buyer address.
+    #[returns(Vec<PurchaseReceipt>)]
+    AssetBuyers {
+        channel_id: String,
+        publish_id: String,
+        /// Optional buyer address to start pagination after.
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Query the protocol moderators, ordered by address.
+    #[returns(Vec<Addr>)]
+    Moderators {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
     #[returns(Vec<ReservedUsername>)]
     ReservedUsernames {
         start_after: Option<String>,
@@ -364,6 +691,15 @@ pub enum QueryMsg {
         limit: Option<u32>,
     },
 
+    /// Query the pending, unexpired collaborator invites of an address.
+    #[returns(Vec<CollaboratorInviteInfo>)]
+    CollaboratorInvites {
+        address: String,
+        /// Optional channel ID to start pagination after.
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
     #[returns(u64)]
     FollowersCount { channel_id: String },
 
@@ -373,6 +709,80 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+
+    /// Query the paid subscription plan of a channel, if any.
+    #[returns(Option<SubscriptionPlan>)]
+    SubscriptionPlan { channel_id: String },
+
+    /// Query whether an address has an active subscription to a channel.
+    #[returns(bool)]
+    IsSubscribed { channel_id: String, address: String },
+
+    /// Query the subscribers of a channel, ordered by address.
+    /// Subscribers whose subscription expired are not returned.
+    #[returns(Vec<SubscriberInfo>)]
+    Subscribers {
+        channel_id: String,
+        /// Optional address to start pagination after.
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Query the IDs of the channels followed by an address, ordered by channel ID.
+    #[returns(Vec<String>)]
+    Following {
+        address: String,
+        /// Optional channel ID to start pagination after.
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    #[returns(bool)]
+    IsFollowing { channel_id: String, address: String },
+
+    /// Query the channels an address owns or collaborates on, ordered by channel ID.
+    /// Ownership is verified against the ONFT module.
+    #[returns(Vec<AddressChannelInfo>)]
+    ChannelsByOwner {
+        address: String,
+        /// Optional channel ID to start pagination after.
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Query the cleanup progress of a deleted channel.
+    /// Returns `None` if there is no state left to be removed.
+    #[returns(Option<ChannelCleanupProgress>)]
+    ChannelCleanupProgress { channel_id: String },
+
+    /// Query the tip shares credited to an address and not yet withdrawn.
+    #[returns(Vec<Coin>)]
+    PendingEarnings { address: String },
+
+    /// Query the lifetime tips received by a channel.
+    #[returns(TipStats)]
+    ChannelTipStats { channel_id: String },
+
+    /// Query the lifetime tips received by an asset.
+    #[returns(TipStats)]
+    AssetTipStats {
+        channel_id: String,
+        publish_id: String,
+    },
+
+    /// Query the lifetime tips sent by an address to a channel.
+    #[returns(Vec<Coin>)]
+    TipperTotals { channel_id: String, tipper: String },
+
+    /// Query the tippers of a channel for a denom, highest total first.
+    #[returns(Vec<TipperInfo>)]
+    TopTippers {
+        channel_id: String,
+        denom: String,
+        /// Optional address of the last tipper of the previous page.
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 // Response for Channel query
 #[cw_serde]
@@ -387,6 +797,9 @@ pub struct ChannelResponse {
     pub banner_picture: Option<String>,
     pub collaborators: Vec<CollaboratorInfo>,
     pub follower_count: u64,
+    pub payout_mode: PayoutMode,
+    /// The active suspension of the channel, if any
+    pub suspension: Option<ChannelSuspension>,
 }
 
 #[cw_serde]
@@ -395,11 +808,59 @@ pub struct AssetResponse {
     pub flags: Vec<FlagInfo>,
     pub metadata: AssetMetadata,
 }
+#[cw_serde]
+pub enum ChannelRelation {
+    /// The address owns the channel ONFT
+    Owner,
+    Collaborator {
+        role: String,
+        share: Decimal,
+        expires_at: Option<Timestamp>,
+    },
+}
+
+#[cw_serde]
+pub struct SubscriberInfo {
+    pub address: String,
+    /// The time the subscription is paid until
+    pub expires_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct AddressChannelInfo {
+    pub channel_id: String,
+    pub user_name: String,
+    pub relation: ChannelRelation,
+}
+
+#[cw_serde]
+pub struct CollaboratorInviteInfo {
+    pub channel_id: String,
+    pub role: String,
+    pub share: Decimal,
+    pub expiry: Option<Expiration>,
+}
+
+#[cw_serde]
+pub struct TipStats {
+    /// Total tipped amount per denom, before the protocol fee
+    pub total: Vec<Coin>,
+    /// Number of tips received
+    pub tip_count: u64,
+}
+
+#[cw_serde]
+pub struct TipperInfo {
+    pub address: String,
+    pub amount: Coin,
+}
+
 #[cw_serde]
 pub struct CollaboratorInfo {
     pub address: String,
     pub role: String,
     pub share: Decimal,
+    pub expires_at: Option<Timestamp>,
 }
 
 // Create this new type to avoid tuples
@@ -408,3 +869,16 @@ pub struct FlagInfo {
     pub flag: Flag,
     pub count: u64,
 }
+
+/// The number of accounts that must raise a flag before the asset is put under review
+#[cw_serde]
+pub struct FlagThreshold {
+    pub flag: Flag,
+    pub threshold: u64,
+}
+
+#[cw_serde]
+pub struct FlaggerInfo {
+    pub flagger: Addr,
+    pub flags: Vec<Flag>,
+}
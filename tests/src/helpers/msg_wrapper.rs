@@ -1,6 +1,6 @@
-use cosmwasm_std::{Addr, Binary};
+use cosmwasm_std::{Addr, Binary, Coin, Timestamp};
 use omniflix_channel_types::{
-    asset::{AssetMetadata, AssetSource},
+    asset::{AccessPolicy, AssetMetadata, AssetSource},
     msg::{
         ChannelTokenDetails, ChannelsCollectionDetails, ExecuteMsg, InstantiateMsg,
         ReservedUsername,
@@ -112,6 +112,9 @@ pub struct AssetPublishMsgBuilder {
     media_uri: String,
     thumbnail_uri: Option<String>,
     playlist_id: Option<String>,
+    access_policy: Option<AccessPolicy>,
+    price: Option<Coin>,
+    publish_at: Option<Timestamp>,
 }
 
 impl AssetPublishMsgBuilder {
@@ -126,6 +129,9 @@ impl AssetPublishMsgBuilder {
             media_uri: "https://example.com/media.png".to_string(),
             thumbnail_uri: None,
             playlist_id: None,
+            access_policy: None,
+            price: None,
+            publish_at: None,
         }
     }
 
@@ -164,6 +170,21 @@ impl AssetPublishMsgBuilder {
         self
     }
 
+    pub fn access_policy(mut self, access_policy: AccessPolicy) -> Self {
+        self.access_policy = Some(access_policy);
+        self
+    }
+
+    pub fn price(mut self, price: Coin) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn publish_at(mut self, publish_at: Timestamp) -> Self {
+        self.publish_at = Some(publish_at);
+        self
+    }
+
     pub fn build(self) -> ExecuteMsg {
         ExecuteMsg::AssetPublish {
             asset_source: self.asset_source,
@@ -177,6 +198,9 @@ impl AssetPublishMsgBuilder {
                 media_uri: self.media_uri,
                 thumbnail_uri: None,
             },
+            access_policy: self.access_policy,
+            price: self.price,
+            publish_at: self.publish_at,
         }
     }
 }
@@ -63,11 +63,14 @@ pub fn setup() -> SetupResponse {
         vec![coin(1_000_000_000, "uflix")],
     );
 
-    let channel_contract = Box::new(ContractWrapper::new(
-        omniflix_channel::contract::execute,
-        omniflix_channel::contract::instantiate,
-        omniflix_channel::contract::query,
-    ));
+    let channel_contract = Box::new(
+        ContractWrapper::new(
+            omniflix_channel::contract::execute,
+            omniflix_channel::contract::instantiate,
+            omniflix_channel::contract::query,
+        )
+        .with_migrate(omniflix_channel::contract::migrate),
+    );
 
     let channel_contract_code_id = app.store_code(channel_contract);
 
@@ -1,6 +1,8 @@
 use cosmwasm_std::{from_json, Addr, Coin, CosmosMsg, MemoryStorage, Storage};
 use cw_multi_test::AppResponse;
-use omniflix_std::types::omniflix::onft::v1beta1::{Collection, MsgCreateDenom, MsgMintOnft};
+use omniflix_std::types::omniflix::onft::v1beta1::{
+    Collection, MsgCreateDenom, MsgMintOnft, MsgTransferOnft,
+};
 use testing::app::OmniflixApp;
 
 pub fn get_contract_address_from_res(res: AppResponse) -> String {
@@ -84,3 +86,20 @@ pub fn mint_onft_msg(
 
     mint_onft_msg.into() // Convert to CosmosMsg
 }
+
+/// Generates a CosmosMsg to transfer an ONFT
+pub fn transfer_onft_msg(
+    denom_id: String,
+    onft_id: String,
+    sender: String,
+    recipient: String,
+) -> CosmosMsg {
+    let transfer_onft_msg = MsgTransferOnft {
+        id: onft_id,
+        denom_id,
+        sender,
+        recipient,
+    };
+
+    transfer_onft_msg.into()
+}
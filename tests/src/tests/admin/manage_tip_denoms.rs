@@ -0,0 +1,220 @@
+use cosmwasm_std::{coin, Uint128};
+use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::msg::{ExecuteMsg, QueryMsg, TipDenom};
+
+use crate::helpers::{
+    msg_wrapper::{get_channel_instantiate_msg, CreateChannelMsgBuilder},
+    setup::setup,
+    utils::get_event_attribute,
+};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Accept a new denom with a minimum amount
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminManageTipDenoms {
+                add_tip_denoms: Some(vec![TipDenom {
+                    denom: "different_denom".to_string(),
+                    min_amount: Some(Uint128::new(1000)),
+                }]),
+                remove_tip_denoms: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let tip_denoms: Vec<TipDenom> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AcceptedTipDenoms {},
+        )
+        .unwrap();
+    assert_eq!(
+        tip_denoms,
+        vec![
+            TipDenom {
+                denom: "uflix".to_string(),
+                min_amount: None,
+            },
+            TipDenom {
+                denom: "different_denom".to_string(),
+                min_amount: Some(Uint128::new(1000)),
+            },
+        ]
+    );
+
+    // Tip below the minimum amount
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(999, "different_denom")],
+                asset_id: None,
+            },
+            &[coin(999, "different_denom")],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::TipAmountBelowMinimum {
+            min_amount: coin(1000, "different_denom")
+        }
+    );
+
+    // Tip with the minimum amount
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(1000, "different_denom")],
+                asset_id: None,
+            },
+            &[coin(1000, "different_denom")],
+        )
+        .unwrap();
+
+    // Remove the denom
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminManageTipDenoms {
+                add_tip_denoms: None,
+                remove_tip_denoms: Some(vec!["different_denom".to_string()]),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(1000, "different_denom")],
+                asset_id: None,
+            },
+            &[coin(1000, "different_denom")],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidTipDenom {});
+
+    let tip_denoms: Vec<TipDenom> = app
+        .wrap()
+        .query_wasm_smart(channel_contract_addr, &QueryMsg::AcceptedTipDenoms {})
+        .unwrap();
+    assert_eq!(
+        tip_denoms,
+        vec![TipDenom {
+            denom: "uflix".to_string(),
+            min_amount: None,
+        }]
+    );
+}
+
+#[test]
+fn unauthorized_and_invalid() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Non admin can not manage tip denoms
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminManageTipDenoms {
+                add_tip_denoms: Some(vec![TipDenom {
+                    denom: "different_denom".to_string(),
+                    min_amount: None,
+                }]),
+                remove_tip_denoms: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    // Removing a denom that is not accepted
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminManageTipDenoms {
+                add_tip_denoms: None,
+                remove_tip_denoms: Some(vec!["different_denom".to_string()]),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::TipDenomNotFound {
+            denom: "different_denom".to_string()
+        }
+    );
+}
@@ -1,2 +1,7 @@
+pub mod manage_tip_denoms;
+pub mod moderators;
 pub mod remove_assets;
+pub mod reset_flags;
 pub mod set_config;
+pub mod suspend_channel;
+pub mod transfer_admin;
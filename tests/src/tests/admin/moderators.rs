@@ -0,0 +1,268 @@
+use cosmwasm_std::{coin, Addr};
+use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::asset::Flag;
+use omniflix_channel_types::msg::{AssetResponse, ExecuteMsg, QueryMsg};
+
+use crate::helpers::msg_wrapper::{AssetPublishMsgBuilder, CreateChannelMsgBuilder};
+use crate::helpers::utils::get_event_attribute;
+use crate::helpers::{msg_wrapper::get_channel_instantiate_msg, setup::setup};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let creator2 = setup_response.test_accounts.creator2.clone();
+    let moderator = setup_response.test_accounts.collector.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Only the protocol admin can manage moderators
+    let add_moderator_msg = ExecuteMsg::AdminManageModerators {
+        add_moderators: Some(vec![moderator.to_string()]),
+        remove_moderators: None,
+    };
+    let res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &add_moderator_msg,
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &add_moderator_msg,
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        get_event_attribute(res, "wasm", "add_moderator"),
+        moderator.to_string()
+    );
+
+    let moderators: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Moderators {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(moderators, vec![moderator.clone()]);
+
+    // Create a channel and publish an asset
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res, "wasm", "publish_id");
+    let asset_key = (channel_id.clone(), publish_id.clone());
+
+    // Moderators can hide and restore assets
+    let _res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminHideAssets {
+                asset_keys: vec![asset_key.clone()],
+            },
+            &[],
+        )
+        .unwrap();
+    let review_queue: Vec<AssetResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ReviewQueue {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(review_queue.len(), 1);
+
+    let _res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminRestoreAssets {
+                asset_keys: vec![asset_key.clone()],
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Moderators can reset the flags of an asset
+    let _res = app
+        .execute_contract(
+            creator2.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetFlag {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                flag: Flag::Spam,
+                interactive_video_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminResetFlags {
+                asset_keys: vec![asset_key.clone()],
+                flags: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let asset: AssetResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Asset {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+        )
+        .unwrap();
+    assert!(asset.flags.iter().all(|flag| flag.count == 0));
+
+    // Moderators can not change the config or remove the flags of all assets
+    let res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminSetConfig {
+                channel_creation_fee: Some(vec![]),
+                fee_collector: None,
+                tip_fee_rate: None,
+                username_change_fee: None,
+                username_hold_period: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    let res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminRemoveAssets {
+                asset_keys: vec![asset_key.clone()],
+                refresh_flags: Some(true),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    // Moderators can remove assets
+    let _res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminRemoveAssets {
+                asset_keys: vec![asset_key.clone()],
+                refresh_flags: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let assets: Vec<AssetResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Assets {
+                channel_id: channel_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(assets.is_empty());
+
+    // Removed moderators lose their permissions
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminManageModerators {
+                add_moderators: None,
+                remove_moderators: Some(vec![moderator.to_string()]),
+            },
+            &[],
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminHideAssets { asset_keys: vec![] },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminManageModerators {
+                add_moderators: None,
+                remove_moderators: Some(vec![moderator.to_string()]),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::ModeratorNotFound {
+            address: moderator.to_string()
+        }
+    );
+}
@@ -0,0 +1,287 @@
+use cosmwasm_std::coin;
+use cw_multi_test::{Executor, MockApiBech32};
+use omniflix_channel::ContractError;
+use omniflix_channel_types::asset::Flag;
+use omniflix_channel_types::msg::{AssetResponse, ExecuteMsg, QueryMsg};
+use testing::app::OmniflixApp;
+
+use crate::helpers::msg_wrapper::{AssetPublishMsgBuilder, CreateChannelMsgBuilder};
+use crate::helpers::utils::get_event_attribute;
+use crate::helpers::{msg_wrapper::get_channel_instantiate_msg, setup::setup};
+
+fn flag_count(asset: &AssetResponse, flag: Flag) -> u64 {
+    asset
+        .flags
+        .iter()
+        .find(|flag_info| flag_info.flag == flag)
+        .map(|flag_info| flag_info.count)
+        .unwrap_or_default()
+}
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel and publish two assets
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    let mut publish_ids = vec![];
+    for _ in 0..2 {
+        let res = app
+            .execute_contract(
+                creator.clone(),
+                channel_contract_addr.clone(),
+                &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+                &[],
+            )
+            .unwrap();
+        publish_ids.push(get_event_attribute(res, "wasm", "publish_id"));
+        app.update_block(|block| {
+            block.time = block.time.plus_nanos(1);
+        });
+    }
+
+    // Flag both assets as spam and explicit
+    for publish_id in publish_ids.iter() {
+        for flag in [Flag::Spam, Flag::Explicit] {
+            let _res = app
+                .execute_contract(
+                    collector.clone(),
+                    channel_contract_addr.clone(),
+                    &ExecuteMsg::AssetFlag {
+                        channel_id: channel_id.clone(),
+                        publish_id: publish_id.clone(),
+                        flag,
+                        interactive_video_id: None,
+                    },
+                    &[],
+                )
+                .unwrap();
+        }
+    }
+
+    // Reset only the spam flags of the first asset
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminResetFlags {
+                asset_keys: vec![(channel_id.clone(), publish_ids[0].clone())],
+                flags: Some(vec![Flag::Spam]),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let query_asset = |app: &OmniflixApp, publish_id: &String| -> AssetResponse {
+        app.wrap()
+            .query_wasm_smart(
+                channel_contract_addr.clone(),
+                &QueryMsg::Asset {
+                    channel_id: channel_id.clone(),
+                    publish_id: publish_id.clone(),
+                },
+            )
+            .unwrap()
+    };
+    let first_asset = query_asset(&app, &publish_ids[0]);
+    assert_eq!(flag_count(&first_asset, Flag::Spam), 0);
+    assert_eq!(flag_count(&first_asset, Flag::Explicit), 1);
+    let second_asset = query_asset(&app, &publish_ids[1]);
+    assert_eq!(flag_count(&second_asset, Flag::Spam), 1);
+    assert_eq!(flag_count(&second_asset, Flag::Explicit), 1);
+
+    // The flagger can raise a reset flag again
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetFlag {
+                channel_id: channel_id.clone(),
+                publish_id: publish_ids[0].clone(),
+                flag: Flag::Spam,
+                interactive_video_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let first_asset = query_asset(&app, &publish_ids[0]);
+    assert_eq!(flag_count(&first_asset, Flag::Spam), 1);
+
+    // Only the protocol admin can clear the flags of all assets
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminClearAllFlags { limit: None },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    // Four flag counts, two flagger records and four flag reports are removed in batches
+    let mut batches = 0;
+    loop {
+        batches += 1;
+        let res = app
+            .execute_contract(
+                admin.clone(),
+                channel_contract_addr.clone(),
+                &ExecuteMsg::AdminClearAllFlags { limit: Some(2) },
+                &[],
+            )
+            .unwrap();
+        if get_event_attribute(res, "wasm", "flags_cleared") == "true" {
+            break;
+        }
+    }
+    assert_eq!(batches, 5);
+
+    for publish_id in publish_ids.iter() {
+        let asset = query_asset(&app, publish_id);
+        assert!(asset.flags.iter().all(|flag_info| flag_info.count == 0));
+    }
+}
+
+#[test]
+fn clear_more_than_the_default_limit() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+    let api = MockApiBech32::new("cosmwasm");
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel and publish two assets
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    let mut publish_ids = vec![];
+    for _ in 0..2 {
+        let res = app
+            .execute_contract(
+                creator.clone(),
+                channel_contract_addr.clone(),
+                &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+                &[],
+            )
+            .unwrap();
+        publish_ids.push(get_event_attribute(res, "wasm", "publish_id"));
+        app.update_block(|block| {
+            block.time = block.time.plus_nanos(1);
+        });
+    }
+
+    // 100 accounts flag the first asset, leaving one flag count, 100 flagger records
+    // and 100 flag reports
+    for i in 0..100 {
+        let _res = app
+            .execute_contract(
+                api.addr_make(&format!("flagger{}", i)),
+                channel_contract_addr.clone(),
+                &ExecuteMsg::AssetFlag {
+                    channel_id: channel_id.clone(),
+                    publish_id: publish_ids[0].clone(),
+                    flag: Flag::Spam,
+                    interactive_video_id: None,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    // Removing an asset with refreshed flags clears only the first batch
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminRemoveAssets {
+                asset_keys: vec![(channel_id.clone(), publish_ids[1].clone())],
+                refresh_flags: Some(true),
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(get_event_attribute(res, "wasm", "flags_cleared"), "false");
+
+    // The rest is cleared by repeating the message with the default limit
+    let mut batches = 0;
+    loop {
+        batches += 1;
+        let res = app
+            .execute_contract(
+                admin.clone(),
+                channel_contract_addr.clone(),
+                &ExecuteMsg::AdminClearAllFlags { limit: None },
+                &[],
+            )
+            .unwrap();
+        if get_event_attribute(res, "wasm", "flags_cleared") == "true" {
+            break;
+        }
+    }
+    assert_eq!(batches, 2);
+
+    let asset: AssetResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Asset {
+                channel_id: channel_id.clone(),
+                publish_id: publish_ids[0].clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(flag_count(&asset, Flag::Spam), 0);
+}
@@ -1,7 +1,7 @@
-use cosmwasm_std::{coin, StdError};
+use cosmwasm_std::{coin, Decimal, StdError};
 use cw_multi_test::Executor;
 use omniflix_channel::ContractError;
-use omniflix_channel_types::config::ChannelConractConfig;
+use omniflix_channel_types::config::{ChannelConractConfig, MAX_TIP_FEE_RATE};
 use omniflix_channel_types::msg::{ExecuteMsg, QueryMsg};
 
 use crate::helpers::{msg_wrapper::get_channel_instantiate_msg, setup::setup};
@@ -35,9 +35,11 @@ fn happy_path() {
             admin.clone(),
             channel_contract_addr.clone(),
             &ExecuteMsg::AdminSetConfig {
-                protocol_admin: Some(creator.to_string()),
                 channel_creation_fee: Some(vec![]),
                 fee_collector: Some(creator.to_string()),
+                tip_fee_rate: None,
+                username_change_fee: None,
+                username_hold_period: None,
             },
             &[],
         )
@@ -48,7 +50,9 @@ fn happy_path() {
         .wrap()
         .query_wasm_smart(channel_contract_addr, &QueryMsg::Config {})
         .unwrap();
-    assert_eq!(config.auth_details.protocol_admin, creator);
+    assert_eq!(config.auth_details.fee_collector, creator);
+    assert_eq!(config.auth_details.protocol_admin, admin);
+    assert_eq!(config.tip_fee_rate, Decimal::zero());
 }
 
 #[test]
@@ -80,9 +84,11 @@ fn unauthorized() {
             creator.clone(),
             channel_contract_addr.clone(),
             &ExecuteMsg::AdminSetConfig {
-                protocol_admin: Some(creator.to_string()),
                 channel_creation_fee: Some(vec![]),
                 fee_collector: Some(creator.to_string()),
+                tip_fee_rate: None,
+                username_change_fee: None,
+                username_hold_period: None,
             },
             &[],
         )
@@ -101,7 +107,6 @@ fn invalid() {
     // Actors
 
     let admin = setup_response.test_accounts.admin.clone();
-    let creator = setup_response.test_accounts.creator.clone();
 
     // Instantiate the contract
     let instantiate_msg = get_channel_instantiate_msg(admin.clone());
@@ -122,10 +127,12 @@ fn invalid() {
             admin.clone(),
             channel_contract_addr.clone(),
             &ExecuteMsg::AdminSetConfig {
-                // Invalid address
-                protocol_admin: Some("creator".to_string()),
                 channel_creation_fee: Some(vec![]),
-                fee_collector: Some(creator.to_string()),
+                // Invalid address
+                fee_collector: Some("creator".to_string()),
+                tip_fee_rate: None,
+                username_change_fee: None,
+                username_hold_period: None,
             },
             &[],
         )
@@ -137,3 +144,72 @@ fn invalid() {
         &ContractError::Std(StdError::generic_err("Error decoding bech32"))
     );
 }
+
+#[test]
+fn tip_fee_rate_too_high() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Rate above the maximum
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminSetConfig {
+                channel_creation_fee: None,
+                fee_collector: None,
+                tip_fee_rate: Some(MAX_TIP_FEE_RATE + Decimal::percent(1)),
+                username_change_fee: None,
+                username_hold_period: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::TipFeeRateTooHigh {
+            max: MAX_TIP_FEE_RATE
+        }
+    );
+
+    // Rate at the maximum is accepted
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminSetConfig {
+                channel_creation_fee: None,
+                fee_collector: None,
+                tip_fee_rate: Some(MAX_TIP_FEE_RATE),
+                username_change_fee: None,
+                username_hold_period: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let config: ChannelConractConfig = app
+        .wrap()
+        .query_wasm_smart(channel_contract_addr, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.tip_fee_rate, MAX_TIP_FEE_RATE);
+}
@@ -0,0 +1,404 @@
+use channel_manager::error::ChannelError;
+use cosmwasm_std::{coin, Binary};
+use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::channel::{ChannelSuspension, SubscriptionPlan};
+use omniflix_channel_types::msg::{ChannelResponse, ExecuteMsg, QueryMsg};
+
+use crate::helpers::msg_wrapper::{AssetPublishMsgBuilder, CreateChannelMsgBuilder};
+use crate::helpers::utils::get_event_attribute;
+use crate::helpers::{msg_wrapper::get_channel_instantiate_msg, setup::setup};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let moderator = setup_response.test_accounts.collaborator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminManageModerators {
+                add_moderators: Some(vec![moderator.to_string()]),
+                remove_moderators: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Set a subscription plan, publish a priced asset and create a playlist
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSetSubscriptionPlan {
+                channel_id: channel_id.clone(),
+                plan: Some(SubscriptionPlan {
+                    price: coin(1000, "uflix"),
+                    period: 100,
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone())
+                .price(coin(1000, "uflix"))
+                .build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res, "wasm", "publish_id");
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::PlaylistCreate {
+                playlist_name: "Before".to_string(),
+                channel_id: channel_id.clone(),
+                salt: Binary::from(b"salt0"),
+            },
+            &[],
+        )
+        .unwrap();
+    let playlist_id = get_event_attribute(res, "wasm", "playlist_id");
+    app.update_block(|block| block.time = block.time.plus_nanos(1));
+
+    // Only the protocol admin or a moderator can suspend a channel
+    let reason = "Repeated spam".to_string();
+    let until = app.block_info().time.plus_seconds(100);
+    let suspend_msg = ExecuteMsg::AdminSuspendChannel {
+        channel_id: channel_id.clone(),
+        reason: reason.clone(),
+        until: Some(until),
+    };
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &suspend_msg,
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    // The reason is required and the end must be in the future
+    let res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminSuspendChannel {
+                channel_id: channel_id.clone(),
+                reason: "".to_string(),
+                until: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidChannelSuspension {});
+    let now = app.block_info().time;
+    let res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminSuspendChannel {
+                channel_id: channel_id.clone(),
+                reason: reason.clone(),
+                until: Some(now),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidChannelSuspension {});
+
+    let _res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &suspend_msg,
+            &[],
+        )
+        .unwrap();
+
+    let channel: ChannelResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Channel {
+                channel_id: channel_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        channel.suspension,
+        Some(ChannelSuspension {
+            reason: reason.clone(),
+            suspended_at: app.block_info().time,
+            until: Some(until),
+        })
+    );
+
+    // Suspended channels can not publish, create or update playlists, update details,
+    // reprice or sell assets, receive tips or take subscriptions
+    let suspended_err = ContractError::Channel(ChannelError::ChannelSuspended {
+        reason: reason.clone(),
+    });
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(res.downcast_ref::<ContractError>().unwrap(), &suspended_err);
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::PlaylistCreate {
+                playlist_name: "Suspended".to_string(),
+                channel_id: channel_id.clone(),
+                salt: Binary::from(b"salt1"),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(res.downcast_ref::<ContractError>().unwrap(), &suspended_err);
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelUpdateDetails {
+                channel_id: channel_id.clone(),
+                description: Some("New description".to_string()),
+                channel_name: None,
+                profile_picture: None,
+                banner_picture: None,
+                payment_address: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(res.downcast_ref::<ContractError>().unwrap(), &suspended_err);
+
+    let tip_msg = ExecuteMsg::ChannelTip {
+        channel_id: channel_id.clone(),
+        amount: vec![coin(1000, "uflix")],
+        asset_id: None,
+    };
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &tip_msg,
+            &[coin(1000, "uflix")],
+        )
+        .unwrap_err();
+    assert_eq!(res.downcast_ref::<ContractError>().unwrap(), &suspended_err);
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::PlaylistAddAsset {
+                publish_id: publish_id.clone(),
+                asset_channel_id: channel_id.clone(),
+                channel_id: channel_id.clone(),
+                playlist_id: playlist_id.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(res.downcast_ref::<ContractError>().unwrap(), &suspended_err);
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetUpdateDetails {
+                publish_id: publish_id.clone(),
+                channel_id: channel_id.clone(),
+                is_visible: None,
+                name: Some("New name".to_string()),
+                description: None,
+                media_uri: None,
+                thumbnail_uri: None,
+                access_policy: None,
+                publish_at: None,
+                clear_publish_at: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(res.downcast_ref::<ContractError>().unwrap(), &suspended_err);
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetSetPrice {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                price: Some(coin(2000, "uflix")),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(res.downcast_ref::<ContractError>().unwrap(), &suspended_err);
+
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetPurchase {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap_err();
+    assert_eq!(res.downcast_ref::<ContractError>().unwrap(), &suspended_err);
+
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSubscribe {
+                channel_id: channel_id.clone(),
+                periods: 1,
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap_err();
+    assert_eq!(res.downcast_ref::<ContractError>().unwrap(), &suspended_err);
+
+    // The suspension ends at its end time
+    app.update_block(|block| block.time = until);
+    let channel: ChannelResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Channel {
+                channel_id: channel_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(channel.suspension, None);
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &tip_msg,
+            &[coin(1000, "uflix")],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetPurchase {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap();
+
+    // Suspensions without an end last until the channel is unsuspended
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminSuspendChannel {
+                channel_id: channel_id.clone(),
+                reason: reason.clone(),
+                until: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let unsuspend_msg = ExecuteMsg::AdminUnsuspendChannel {
+        channel_id: channel_id.clone(),
+    };
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &unsuspend_msg,
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    let _res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &unsuspend_msg,
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+            &[],
+        )
+        .unwrap();
+
+    let res = app
+        .execute_contract(
+            moderator.clone(),
+            channel_contract_addr.clone(),
+            &unsuspend_msg,
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::ChannelNotSuspended {})
+    );
+}
@@ -0,0 +1,323 @@
+use cosmwasm_std::coin;
+use cw_multi_test::Executor;
+use cw_utils::Expiration;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::config::{ChannelConractConfig, PendingProtocolAdmin};
+use omniflix_channel_types::msg::{ExecuteMsg, QueryMsg};
+
+use crate::helpers::{msg_wrapper::get_channel_instantiate_msg, setup::setup};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Propose the new admin
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminProposeProtocolAdmin {
+                new_admin: creator.to_string(),
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Query the pending admin
+    let pending_admin: Option<PendingProtocolAdmin> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::PendingProtocolAdmin {},
+        )
+        .unwrap();
+    assert_eq!(
+        pending_admin,
+        Some(PendingProtocolAdmin {
+            address: creator.clone(),
+            expiry: None,
+        })
+    );
+
+    // The admin is not changed until the proposal is accepted
+    let config: ChannelConractConfig = app
+        .wrap()
+        .query_wasm_smart(channel_contract_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.auth_details.protocol_admin, admin);
+
+    // Accept the proposal
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminAcceptProtocolAdmin {},
+            &[],
+        )
+        .unwrap();
+
+    let config: ChannelConractConfig = app
+        .wrap()
+        .query_wasm_smart(channel_contract_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.auth_details.protocol_admin, creator);
+
+    // Pending admin is cleared
+    let pending_admin: Option<PendingProtocolAdmin> = app
+        .wrap()
+        .query_wasm_smart(channel_contract_addr, &QueryMsg::PendingProtocolAdmin {})
+        .unwrap();
+    assert_eq!(pending_admin, None);
+}
+
+#[test]
+fn unauthorized() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Non admin can not propose
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminProposeProtocolAdmin {
+                new_admin: creator.to_string(),
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    // Nothing to accept
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminAcceptProtocolAdmin {},
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::NoPendingProtocolAdmin {});
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminProposeProtocolAdmin {
+                new_admin: creator.to_string(),
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Only the proposed address can accept
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminAcceptProtocolAdmin {},
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    // Only the admin can cancel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminCancelProtocolAdmin {},
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+}
+
+#[test]
+fn cancel() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminProposeProtocolAdmin {
+                new_admin: creator.to_string(),
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Cancel the proposal
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminCancelProtocolAdmin {},
+            &[],
+        )
+        .unwrap();
+
+    // Proposed admin can no longer accept
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminAcceptProtocolAdmin {},
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::NoPendingProtocolAdmin {});
+
+    // Nothing left to cancel
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminCancelProtocolAdmin {},
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::NoPendingProtocolAdmin {});
+}
+
+#[test]
+fn expired_proposal() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Expiry in the past is rejected
+    let current_height = app.block_info().height;
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminProposeProtocolAdmin {
+                new_admin: creator.to_string(),
+                expiry: Some(Expiration::AtHeight(current_height)),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidExpiration {});
+
+    let expiry_height = app.block_info().height + 10;
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminProposeProtocolAdmin {
+                new_admin: creator.to_string(),
+                expiry: Some(Expiration::AtHeight(expiry_height)),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Move past the expiry
+    app.update_block(|block| block.height = expiry_height);
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminAcceptProtocolAdmin {},
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::PendingProtocolAdminExpired {});
+
+    // Admin is unchanged
+    let config: ChannelConractConfig = app
+        .wrap()
+        .query_wasm_smart(channel_contract_addr, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.auth_details.protocol_admin, admin);
+}
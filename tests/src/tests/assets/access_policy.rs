@@ -0,0 +1,362 @@
+use cosmwasm_std::{coin, Addr};
+use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::asset::AccessPolicy;
+use omniflix_channel_types::channel::SubscriptionPlan;
+use omniflix_channel_types::msg::{AssetResponse, ExecuteMsg, QueryMsg};
+use testing::app::OmniflixApp;
+
+use crate::helpers::{
+    msg_wrapper::{get_channel_instantiate_msg, AssetPublishMsgBuilder, CreateChannelMsgBuilder},
+    setup::setup,
+    utils::{create_denom_msg, get_event_attribute, mint_onft_msg},
+};
+
+fn can_access(
+    app: &OmniflixApp,
+    channel_contract_addr: &Addr,
+    address: &Addr,
+    channel_id: &str,
+    publish_id: &str,
+) -> bool {
+    app.wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::CanAccessAsset {
+                address: address.to_string(),
+                channel_id: channel_id.to_string(),
+                publish_id: publish_id.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+fn update_asset_msg(
+    channel_id: &str,
+    publish_id: &str,
+    is_visible: Option<bool>,
+    access_policy: Option<AccessPolicy>,
+) -> ExecuteMsg {
+    ExecuteMsg::AssetUpdateDetails {
+        publish_id: publish_id.to_string(),
+        channel_id: channel_id.to_string(),
+        is_visible,
+        name: None,
+        description: None,
+        media_uri: None,
+        thumbnail_uri: None,
+        access_policy,
+        publish_at: None,
+        clear_publish_at: None,
+    }
+}
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+    let creator2 = setup_response.test_accounts.creator2.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Publish a followers only asset
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone())
+                .access_policy(AccessPolicy::Followers)
+                .build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res.clone(), "wasm", "publish_id");
+    let access_policy = get_event_attribute(res, "wasm", "access_policy");
+    assert_eq!(access_policy, "followers");
+
+    let asset: AssetResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Asset {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(asset.asset.access_policy, AccessPolicy::Followers);
+
+    // The channel owner can always access the asset
+    assert!(can_access(
+        &app,
+        &channel_contract_addr,
+        &creator,
+        &channel_id,
+        &publish_id
+    ));
+    assert!(!can_access(
+        &app,
+        &channel_contract_addr,
+        &collector,
+        &channel_id,
+        &publish_id
+    ));
+
+    // Following grants access
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelFollow {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(can_access(
+        &app,
+        &channel_contract_addr,
+        &collector,
+        &channel_id,
+        &publish_id
+    ));
+
+    // Restrict the asset to subscribers
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &update_asset_msg(
+                &channel_id,
+                &publish_id,
+                None,
+                Some(AccessPolicy::Subscribers),
+            ),
+            &[],
+        )
+        .unwrap();
+    let access_policy = get_event_attribute(res, "wasm", "access_policy");
+    assert_eq!(access_policy, "subscribers");
+    assert!(!can_access(
+        &app,
+        &channel_contract_addr,
+        &collector,
+        &channel_id,
+        &publish_id
+    ));
+
+    // Subscribing grants access until the subscription expires
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSetSubscriptionPlan {
+                channel_id: channel_id.clone(),
+                plan: Some(SubscriptionPlan {
+                    price: coin(1000, "uflix"),
+                    period: 100,
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSubscribe {
+                channel_id: channel_id.clone(),
+                periods: 1,
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap();
+    assert!(can_access(
+        &app,
+        &channel_contract_addr,
+        &collector,
+        &channel_id,
+        &publish_id
+    ));
+    app.update_block(|block| block.time = block.time.plus_seconds(100));
+    assert!(!can_access(
+        &app,
+        &channel_contract_addr,
+        &collector,
+        &channel_id,
+        &publish_id
+    ));
+
+    // Restrict the asset to the holders of a collection
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &update_asset_msg(
+                &channel_id,
+                &publish_id,
+                None,
+                Some(AccessPolicy::CollectionHolders {
+                    collection_id: "passes".to_string(),
+                }),
+            ),
+            &[],
+        )
+        .unwrap();
+    assert!(!can_access(
+        &app,
+        &channel_contract_addr,
+        &collector,
+        &channel_id,
+        &publish_id
+    ));
+
+    let _res = app
+        .execute(
+            creator.clone(),
+            create_denom_msg(creator.to_string(), "passes".to_string(), None),
+        )
+        .unwrap();
+    let _res = app
+        .execute(
+            creator.clone(),
+            mint_onft_msg(
+                "passes".to_string(),
+                "pass1".to_string(),
+                collector.to_string(),
+            ),
+        )
+        .unwrap();
+    assert!(can_access(
+        &app,
+        &channel_contract_addr,
+        &collector,
+        &channel_id,
+        &publish_id
+    ));
+
+    // Hidden assets are only accessible to the channel owner and collaborators
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &update_asset_msg(&channel_id, &publish_id, Some(false), None),
+            &[],
+        )
+        .unwrap();
+    assert!(!can_access(
+        &app,
+        &channel_contract_addr,
+        &collector,
+        &channel_id,
+        &publish_id
+    ));
+    assert!(can_access(
+        &app,
+        &channel_contract_addr,
+        &creator,
+        &channel_id,
+        &publish_id
+    ));
+
+    // Public assets are accessible to anyone
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &update_asset_msg(
+                &channel_id,
+                &publish_id,
+                Some(true),
+                Some(AccessPolicy::Public),
+            ),
+            &[],
+        )
+        .unwrap();
+    assert!(can_access(
+        &app,
+        &channel_contract_addr,
+        &creator2,
+        &channel_id,
+        &publish_id
+    ));
+}
+
+#[test]
+fn invalid_access_policy() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Collection holder policies need a collection
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone())
+                .access_policy(AccessPolicy::CollectionHolders {
+                    collection_id: "".to_string(),
+                })
+                .build(),
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidAccessPolicy {});
+}
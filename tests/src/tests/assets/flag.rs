@@ -1,8 +1,10 @@
+use asset_manager::error::AssetError;
 use cosmwasm_std::coin;
 use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
 use omniflix_channel_types::{
-    asset::{AssetSource, Flag},
-    msg::ExecuteMsg,
+    asset::{AssetSource, Flag, FlagReport},
+    msg::{AssetResponse, ExecuteMsg, FlaggerInfo, QueryMsg},
 };
 
 use crate::helpers::msg_wrapper::AssetPublishMsgBuilder;
@@ -116,3 +118,449 @@ fn flag_asset_happy_path() {
     );
     assert_eq!(get_event_attribute(res.clone(), "wasm", "flag"), "NSFW");
 }
+
+#[test]
+fn flag_once_per_account() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+    let creator2 = setup_response.test_accounts.creator2.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel and publish an asset
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res, "wasm", "publish_id");
+
+    let flag_msg = ExecuteMsg::AssetFlag {
+        channel_id: channel_id.clone(),
+        publish_id: publish_id.clone(),
+        flag: Flag::NSFW,
+        interactive_video_id: None,
+    };
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &flag_msg,
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        get_event_attribute(res, "wasm", "flagger"),
+        collector.to_string()
+    );
+
+    // The same account can not raise the same flag twice
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &flag_msg,
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Asset(AssetError::AlreadyFlagged {})
+    );
+
+    // Other flags and other accounts are counted
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetFlag {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                flag: Flag::Spam,
+                interactive_video_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            creator2.clone(),
+            channel_contract_addr.clone(),
+            &flag_msg,
+            &[],
+        )
+        .unwrap();
+
+    let asset: AssetResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Asset {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+        )
+        .unwrap();
+    let nsfw = asset.flags.iter().find(|f| f.flag == Flag::NSFW).unwrap();
+    assert_eq!(nsfw.count, 2);
+    let spam = asset.flags.iter().find(|f| f.flag == Flag::Spam).unwrap();
+    assert_eq!(spam.count, 1);
+
+    let flaggers: Vec<FlaggerInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AssetFlaggers {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let mut expected = vec![
+        FlaggerInfo {
+            flagger: collector.clone(),
+            flags: vec![Flag::NSFW, Flag::Spam],
+        },
+        FlaggerInfo {
+            flagger: creator2.clone(),
+            flags: vec![Flag::NSFW],
+        },
+    ];
+    expected.sort_by(|a, b| a.flagger.cmp(&b.flagger));
+    assert_eq!(flaggers, expected);
+}
+
+#[test]
+fn retract_flag() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel and publish an asset
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res, "wasm", "publish_id");
+
+    let retract_msg = ExecuteMsg::AssetRetractFlag {
+        channel_id: channel_id.clone(),
+        publish_id: publish_id.clone(),
+        flag: Flag::Hateful,
+    };
+
+    // Only raised flags can be retracted
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &retract_msg,
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Asset(AssetError::FlagNotFound {})
+    );
+
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetFlag {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                flag: Flag::Hateful,
+                interactive_video_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &retract_msg,
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        get_event_attribute(res, "wasm", "action"),
+        "asset_retract_flag"
+    );
+
+    let asset: AssetResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Asset {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+        )
+        .unwrap();
+    assert!(asset.flags.iter().all(|f| f.count == 0));
+
+    let flaggers: Vec<FlaggerInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AssetFlaggers {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(flaggers.is_empty());
+}
+
+#[test]
+fn flag_reports() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel and publish an asset
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res, "wasm", "publish_id");
+
+    // Interactive video IDs can not be empty
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetFlag {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                flag: Flag::Spam,
+                interactive_video_id: Some("".to_string()),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidInteractiveVideoId {});
+
+    // Flag the asset with a reason from an interactive video
+    let reason = "Misleading medical advice".to_string();
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetFlag {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                flag: Flag::Other(reason.clone()),
+                interactive_video_id: Some("iv1".to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        get_event_attribute(res, "wasm", "interactive_video_id"),
+        "iv1"
+    );
+    let reported_at = app.block_info().time;
+
+    app.update_block(|block| {
+        block.time = block.time.plus_seconds(1);
+    });
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetFlag {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                flag: Flag::Spam,
+                interactive_video_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let reports: Vec<FlagReport> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AssetFlagReports {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(reports.len(), 2);
+    let collector_report = reports
+        .iter()
+        .find(|report| report.reporter == collector)
+        .unwrap();
+    assert_eq!(
+        collector_report,
+        &FlagReport {
+            reporter: collector.clone(),
+            flag: Flag::Other(reason),
+            interactive_video_id: Some("iv1".to_string()),
+            reported_at,
+        }
+    );
+
+    // Reports are paginated by reporter and flag
+    let first_page: Vec<FlagReport> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AssetFlagReports {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    let second_page: Vec<FlagReport> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AssetFlagReports {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                start_after: Some((
+                    first_page[0].reporter.to_string(),
+                    first_page[0].flag.clone(),
+                )),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(vec![first_page[0].clone(), second_page[0].clone()], reports);
+
+    // Retracting a flag removes its report
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetRetractFlag {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                flag: Flag::Spam,
+            },
+            &[],
+        )
+        .unwrap();
+    let reports: Vec<FlagReport> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AssetFlagReports {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].reporter, collector);
+}
@@ -1,4 +1,8 @@
+pub mod access_policy;
 pub mod flag;
 pub mod publish;
+pub mod purchase;
+pub mod review;
+pub mod schedule;
 pub mod unpublish;
 pub mod update_details;
@@ -0,0 +1,420 @@
+use asset_manager::error::AssetError;
+use cosmwasm_std::{coin, Addr, Decimal, Uint128};
+use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::asset::{AccessPolicy, PurchaseReceipt};
+use omniflix_channel_types::channel::{ChannelCollaborator, Role};
+use omniflix_channel_types::msg::{ExecuteMsg, QueryMsg};
+
+use crate::helpers::{
+    msg_wrapper::{get_channel_instantiate_msg, AssetPublishMsgBuilder, CreateChannelMsgBuilder},
+    setup::setup,
+    utils::get_event_attribute,
+};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+    let buyer = setup_response.test_accounts.collector.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Set a 10% protocol fee
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminSetConfig {
+                channel_creation_fee: None,
+                fee_collector: None,
+                tip_fee_rate: Some(Decimal::percent(10)),
+                username_change_fee: None,
+                username_hold_period: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Add a collaborator with a 10% share
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelInviteCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collaborator.to_string(),
+                collaborator_details: ChannelCollaborator {
+                    role: Role::Publisher,
+                    share: Decimal::percent(10),
+                    expires_at: None,
+                },
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Publish a subscribers only asset sold for 1000uflix
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone())
+                .access_policy(AccessPolicy::Subscribers)
+                .price(coin(1000, "uflix"))
+                .build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res.clone(), "wasm", "publish_id");
+    assert_eq!(get_event_attribute(res, "wasm", "price"), "1000uflix");
+
+    let fee_collector_balance_before = app.wrap().query_balance(&admin, "uflix").unwrap();
+
+    let res = app
+        .execute_contract(
+            buyer.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetPurchase {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap();
+
+    // The protocol fee goes to the fee collector and the rest is split through the shares
+    assert_eq!(
+        get_event_attribute(res.clone(), "wasm", "protocol_fee"),
+        "100uflix"
+    );
+    assert_eq!(
+        get_event_attribute(res.clone(), "wasm", collaborator.as_str()),
+        "90uflix"
+    );
+    assert_eq!(
+        get_event_attribute(res, "wasm", creator.as_str()),
+        "810uflix"
+    );
+    let fee_collector_balance_after = app.wrap().query_balance(&admin, "uflix").unwrap();
+    assert_eq!(
+        fee_collector_balance_after.amount - fee_collector_balance_before.amount,
+        Uint128::new(100)
+    );
+
+    let has_purchased: bool = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::HasPurchased {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                address: buyer.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(has_purchased);
+
+    let buyers: Vec<PurchaseReceipt> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AssetBuyers {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        buyers,
+        vec![PurchaseReceipt {
+            buyer: buyer.clone(),
+            price: coin(1000, "uflix"),
+            purchased_at: app.block_info().time,
+        }]
+    );
+
+    // Buyers can access the asset regardless of its access policy
+    let can_access: bool = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::CanAccessAsset {
+                address: buyer.to_string(),
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+        )
+        .unwrap();
+    assert!(can_access);
+
+    // Priced public assets are not accessible without a purchase
+    app.update_block(|block| {
+        block.time = block.time.plus_nanos(1);
+    });
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone())
+                .price(coin(1000, "uflix"))
+                .build(),
+            &[],
+        )
+        .unwrap();
+    let public_publish_id = get_event_attribute(res, "wasm", "publish_id");
+    let can_access_public = |address: &Addr| -> bool {
+        app.wrap()
+            .query_wasm_smart(
+                channel_contract_addr.clone(),
+                &QueryMsg::CanAccessAsset {
+                    address: address.to_string(),
+                    channel_id: channel_id.clone(),
+                    publish_id: public_publish_id.clone(),
+                },
+            )
+            .unwrap()
+    };
+    assert!(!can_access_public(&buyer));
+    assert!(can_access_public(&collaborator));
+
+    // An asset can only be purchased once
+    let res = app
+        .execute_contract(
+            buyer.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetPurchase {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Asset(AssetError::AssetAlreadyPurchased {})
+    );
+}
+
+#[test]
+fn invalid_purchases() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let buyer = setup_response.test_accounts.collector.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Publish an asset without a price
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res, "wasm", "publish_id");
+
+    let res = app
+        .execute_contract(
+            buyer.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetPurchase {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Asset(AssetError::AssetNotForSale {})
+    );
+
+    // Zero price
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetSetPrice {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                price: Some(coin(0, "uflix")),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidAssetPrice {});
+
+    // Price in a denom that is not accepted
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetSetPrice {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                price: Some(coin(1000, "different_denom")),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidTipDenom {});
+
+    // Only the channel owner or a collaborator can set the price
+    let res = app
+        .execute_contract(
+            buyer.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetSetPrice {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                price: Some(coin(1000, "uflix")),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetSetPrice {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                price: Some(coin(1000, "uflix")),
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(get_event_attribute(res, "wasm", "price"), "1000uflix");
+
+    // Payment must match the price
+    let res = app
+        .execute_contract(
+            buyer.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetPurchase {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+            &[coin(500, "uflix")],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::PaymentError {
+            expected: vec![coin(1000, "uflix")],
+            received: vec![coin(500, "uflix")],
+        }
+    );
+
+    // Hidden assets can not be purchased
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetUpdateDetails {
+                publish_id: publish_id.clone(),
+                channel_id: channel_id.clone(),
+                is_visible: Some(false),
+                name: None,
+                description: None,
+                media_uri: None,
+                thumbnail_uri: None,
+                access_policy: None,
+                publish_at: None,
+                clear_publish_at: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            buyer.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetPurchase {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::AssetNotVisible {});
+}
@@ -0,0 +1,391 @@
+use asset_manager::error::AssetError;
+use cosmwasm_std::{coin, Binary};
+use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::asset::Flag;
+use omniflix_channel_types::msg::{AssetResponse, ExecuteMsg, FlagThreshold, QueryMsg};
+
+use crate::helpers::{
+    msg_wrapper::{get_channel_instantiate_msg, AssetPublishMsgBuilder, CreateChannelMsgBuilder},
+    setup::setup,
+    utils::get_event_attribute,
+};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let creator2 = setup_response.test_accounts.creator2.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Only the protocol admin can set flag thresholds
+    let thresholds_msg = ExecuteMsg::AdminSetFlagThresholds {
+        thresholds: vec![FlagThreshold {
+            flag: Flag::Hateful,
+            threshold: 2,
+        }],
+    };
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &thresholds_msg,
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &thresholds_msg,
+            &[],
+        )
+        .unwrap();
+    let thresholds: Vec<FlagThreshold> = app
+        .wrap()
+        .query_wasm_smart(channel_contract_addr.clone(), &QueryMsg::FlagThresholds {})
+        .unwrap();
+    assert_eq!(
+        thresholds,
+        vec![FlagThreshold {
+            flag: Flag::Hateful,
+            threshold: 2,
+        }]
+    );
+
+    // Create a channel, a playlist and an asset
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::PlaylistCreate {
+                playlist_name: "Reviewed".to_string(),
+                channel_id: channel_id.clone(),
+                salt: Binary::from(b"salt1"),
+            },
+            &[],
+        )
+        .unwrap();
+    let playlist_id = get_event_attribute(res, "wasm", "playlist_id");
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res, "wasm", "publish_id");
+
+    let flag_msg = ExecuteMsg::AssetFlag {
+        channel_id: channel_id.clone(),
+        publish_id: publish_id.clone(),
+        flag: Flag::Hateful,
+        interactive_video_id: None,
+    };
+
+    // The asset stays visible below the threshold
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &flag_msg,
+            &[],
+        )
+        .unwrap();
+    assert!(!res.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "under_review")));
+
+    // Reaching the threshold puts the asset under review
+    let res = app
+        .execute_contract(
+            creator2.clone(),
+            channel_contract_addr.clone(),
+            &flag_msg,
+            &[],
+        )
+        .unwrap();
+    assert_eq!(get_event_attribute(res, "wasm", "under_review"), "true");
+
+    let assets: Vec<AssetResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Assets {
+                channel_id: channel_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(assets.is_empty());
+
+    let review_queue: Vec<AssetResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ReviewQueue {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(review_queue.len(), 1);
+    assert_eq!(review_queue[0].asset.publish_id, publish_id);
+    assert!(review_queue[0].asset.under_review);
+    assert!(!review_queue[0].asset.is_visible);
+
+    // Assets under review can not be added to playlists
+    let add_asset_msg = ExecuteMsg::PlaylistAddAsset {
+        publish_id: publish_id.clone(),
+        asset_channel_id: channel_id.clone(),
+        channel_id: channel_id.clone(),
+        playlist_id: playlist_id.clone(),
+    };
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &add_asset_msg,
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::AssetNotVisible {});
+
+    // Only the protocol admin can restore assets
+    let restore_msg = ExecuteMsg::AdminRestoreAssets {
+        asset_keys: vec![(channel_id.clone(), publish_id.clone())],
+    };
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &restore_msg,
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &restore_msg,
+            &[],
+        )
+        .unwrap();
+    let review_queue: Vec<AssetResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ReviewQueue {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(review_queue.is_empty());
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &add_asset_msg,
+            &[],
+        )
+        .unwrap();
+
+    // Restored assets are not put under review again by further flags
+    let res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &flag_msg,
+            &[],
+        )
+        .unwrap();
+    assert!(!res.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "under_review")));
+    let asset: AssetResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Asset {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+        )
+        .unwrap();
+    assert!(asset.asset.is_visible);
+
+    // Assets not under review can not be restored
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &restore_msg,
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Asset(AssetError::AssetNotUnderReview {})
+    );
+
+    // Dropping below the threshold and reaching it again puts the asset under review again
+    let retract_msg = ExecuteMsg::AssetRetractFlag {
+        channel_id: channel_id.clone(),
+        publish_id: publish_id.clone(),
+        flag: Flag::Hateful,
+    };
+    for flagger in [collaborator.clone(), creator2.clone()] {
+        let _res = app
+            .execute_contract(flagger, channel_contract_addr.clone(), &retract_msg, &[])
+            .unwrap();
+    }
+    let res = app
+        .execute_contract(
+            creator2.clone(),
+            channel_contract_addr.clone(),
+            &flag_msg,
+            &[],
+        )
+        .unwrap();
+    assert_eq!(get_event_attribute(res, "wasm", "under_review"), "true");
+}
+
+#[test]
+fn lowered_threshold() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let creator2 = setup_response.test_accounts.creator2.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res, "wasm", "publish_id");
+
+    // Flag the asset twice before any threshold is set
+    let flag_msg = ExecuteMsg::AssetFlag {
+        channel_id: channel_id.clone(),
+        publish_id: publish_id.clone(),
+        flag: Flag::Hateful,
+        interactive_video_id: None,
+    };
+    for flagger in [collector.clone(), creator2.clone()] {
+        let _res = app
+            .execute_contract(flagger, channel_contract_addr.clone(), &flag_msg, &[])
+            .unwrap();
+    }
+
+    // Setting a threshold below the existing count does not re-check the asset
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminSetFlagThresholds {
+                thresholds: vec![FlagThreshold {
+                    flag: Flag::Hateful,
+                    threshold: 1,
+                }],
+            },
+            &[],
+        )
+        .unwrap();
+    let asset: AssetResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Asset {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+        )
+        .unwrap();
+    assert!(!asset.asset.under_review);
+
+    // The next flag puts the asset above the lowered threshold under review
+    let res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &flag_msg,
+            &[],
+        )
+        .unwrap();
+    assert_eq!(get_event_attribute(res, "wasm", "under_review"), "true");
+}
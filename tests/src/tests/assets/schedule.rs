@@ -0,0 +1,326 @@
+use cosmwasm_std::{coin, Binary, Timestamp};
+use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::msg::{AssetResponse, ExecuteMsg, QueryMsg};
+
+use crate::helpers::{
+    msg_wrapper::{get_channel_instantiate_msg, AssetPublishMsgBuilder, CreateChannelMsgBuilder},
+    setup::setup,
+    utils::get_event_attribute,
+};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel and a playlist
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::PlaylistCreate {
+                playlist_name: "Premieres".to_string(),
+                channel_id: channel_id.clone(),
+                salt: Binary::from(b"salt1"),
+            },
+            &[],
+        )
+        .unwrap();
+    let playlist_id = get_event_attribute(res, "wasm", "playlist_id");
+
+    // Schedule two premieres
+    let premiere_time = app.block_info().time.plus_seconds(100);
+    let late_premiere_time = app.block_info().time.plus_seconds(200);
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone())
+                .publish_at(late_premiere_time)
+                .build(),
+            &[],
+        )
+        .unwrap();
+    let late_publish_id = get_event_attribute(res.clone(), "wasm", "publish_id");
+    assert_eq!(
+        get_event_attribute(res, "wasm", "publish_at"),
+        late_premiere_time.to_string()
+    );
+
+    app.update_block(|block| {
+        block.time = block.time.plus_nanos(1);
+    });
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone())
+                .publish_at(premiere_time)
+                .build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res, "wasm", "publish_id");
+
+    // Scheduled assets are not visible yet
+    let asset: AssetResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Asset {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+        )
+        .unwrap();
+    assert!(!asset.asset.is_visible);
+    assert_eq!(asset.asset.publish_at, Some(premiere_time));
+
+    let assets: Vec<AssetResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Assets {
+                channel_id: channel_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(assets.len(), 2);
+    assert!(assets.iter().all(|asset| !asset.asset.is_visible));
+
+    // Upcoming assets are ordered by their publish time
+    let scheduled: Vec<AssetResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ScheduledAssets {
+                channel_id: channel_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let scheduled_ids: Vec<String> = scheduled
+        .into_iter()
+        .map(|asset| asset.asset.publish_id)
+        .collect();
+    assert_eq!(
+        scheduled_ids,
+        vec![publish_id.clone(), late_publish_id.clone()]
+    );
+
+    let scheduled: Vec<AssetResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ScheduledAssets {
+                channel_id: channel_id.clone(),
+                start_after: Some(publish_id.clone()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(scheduled.len(), 1);
+    assert_eq!(scheduled[0].asset.publish_id, late_publish_id);
+
+    // Scheduled assets can not be added to playlists
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::PlaylistAddAsset {
+                publish_id: publish_id.clone(),
+                asset_channel_id: channel_id.clone(),
+                channel_id: channel_id.clone(),
+                playlist_id: playlist_id.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::AssetNotVisible {});
+
+    // The asset goes live at its publish time
+    app.update_block(|block| block.time = premiere_time);
+    let asset: AssetResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Asset {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+        )
+        .unwrap();
+    assert!(asset.asset.is_visible);
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::PlaylistAddAsset {
+                publish_id: publish_id.clone(),
+                asset_channel_id: channel_id.clone(),
+                channel_id: channel_id.clone(),
+                playlist_id: playlist_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let scheduled: Vec<AssetResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ScheduledAssets {
+                channel_id: channel_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(scheduled.len(), 1);
+    assert_eq!(scheduled[0].asset.publish_id, late_publish_id);
+
+    // Moving the publish time to now publishes the asset right away
+    let now = app.block_info().time;
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetUpdateDetails {
+                publish_id: late_publish_id.clone(),
+                channel_id: channel_id.clone(),
+                is_visible: None,
+                name: None,
+                description: None,
+                media_uri: None,
+                thumbnail_uri: None,
+                access_policy: None,
+                publish_at: Some(now),
+                clear_publish_at: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let scheduled: Vec<AssetResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ScheduledAssets {
+                channel_id: channel_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(scheduled.is_empty());
+    let asset: AssetResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Asset {
+                channel_id: channel_id.clone(),
+                publish_id: late_publish_id.clone(),
+            },
+        )
+        .unwrap();
+    assert!(asset.asset.is_visible);
+
+    // Scheduled assets published with a playlist are not added to it
+    app.update_block(|block| {
+        block.time = block.time.plus_nanos(1);
+    });
+    let mistaken_premiere_time = app.block_info().time.plus_seconds(100);
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone())
+                .playlist_id(playlist_id.clone())
+                .publish_at(mistaken_premiere_time)
+                .build(),
+            &[],
+        )
+        .unwrap();
+    let mistaken_publish_id = get_event_attribute(res.clone(), "wasm", "publish_id");
+    assert!(!res.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "playlist_id")));
+
+    // A publish time set by mistake can be cleared, but not together with a new time
+    let clear_msg = |publish_at: Option<Timestamp>| ExecuteMsg::AssetUpdateDetails {
+        publish_id: mistaken_publish_id.clone(),
+        channel_id: channel_id.clone(),
+        is_visible: None,
+        name: None,
+        description: None,
+        media_uri: None,
+        thumbnail_uri: None,
+        access_policy: None,
+        publish_at,
+        clear_publish_at: Some(true),
+    };
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &clear_msg(Some(now)),
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidPublishAt {});
+
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &clear_msg(None),
+            &[],
+        )
+        .unwrap();
+    let asset: AssetResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Asset {
+                channel_id: channel_id.clone(),
+                publish_id: mistaken_publish_id.clone(),
+            },
+        )
+        .unwrap();
+    assert!(asset.asset.is_visible);
+    assert_eq!(asset.asset.publish_at, None);
+}
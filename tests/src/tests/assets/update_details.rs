@@ -60,6 +60,9 @@ fn asset_does_not_exist() {
         description: Some("Updated Description".to_string()),
         media_uri: Some("https://updated-media-uri.com".to_string()),
         thumbnail_uri: None,
+        access_policy: None,
+        publish_at: None,
+        clear_publish_at: None,
     };
 
     let res = app
@@ -141,6 +144,9 @@ fn channel_not_owned() {
         description: Some("Updated Description".to_string()),
         media_uri: Some("https://updated-media-uri.com".to_string()),
         thumbnail_uri: None,
+        access_policy: None,
+        publish_at: None,
+        clear_publish_at: None,
     };
 
     let err = app
@@ -228,6 +234,9 @@ fn partial_update() {
         description: None,
         media_uri: None,
         thumbnail_uri: None,
+        access_policy: None,
+        publish_at: None,
+        clear_publish_at: None,
     };
 
     app.execute_contract(
@@ -333,6 +342,9 @@ fn full_update() {
         description: Some(new_description.to_string()),
         media_uri: Some(new_media_uri.to_string()),
         thumbnail_uri: Some(new_thumbnail_uri.to_string()),
+        access_policy: None,
+        publish_at: None,
+        clear_publish_at: None,
     };
 
     app.execute_contract(
@@ -406,13 +418,15 @@ fn collaborator_update() {
     let channel_id = get_event_attribute(res, "wasm", "channel_id");
 
     // Add collaborator to the channel
-    let add_collaborator_msg = ExecuteMsg::ChannelAddCollaborator {
+    let add_collaborator_msg = ExecuteMsg::ChannelInviteCollaborator {
         channel_id: channel_id.clone(),
         collaborator_address: collaborator.to_string(),
         collaborator_details: omniflix_channel_types::channel::ChannelCollaborator {
             role: Role::Publisher,
             share: cosmwasm_std::Decimal::percent(10),
+            expires_at: None,
         },
+        expiry: None,
     };
 
     app.execute_contract(
@@ -422,6 +436,16 @@ fn collaborator_update() {
         &[],
     )
     .unwrap();
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
 
     // Publish an asset
     let original_name = "Original Asset Name";
@@ -456,6 +480,9 @@ fn collaborator_update() {
         description: None,
         media_uri: None,
         thumbnail_uri: None,
+        access_policy: None,
+        publish_at: None,
+        clear_publish_at: None,
     };
 
     // This should succeed since collaborators should be able to update assets
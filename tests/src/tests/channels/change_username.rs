@@ -0,0 +1,326 @@
+use channel_manager::error::ChannelError;
+use cosmwasm_std::{coin, Binary};
+use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::channel::ChannelDetails;
+use omniflix_channel_types::config::ChannelConractConfig;
+use omniflix_channel_types::msg::{ExecuteMsg, QueryMsg, ReservedUsername};
+
+use crate::helpers::{
+    msg_wrapper::{get_channel_instantiate_msg, CreateChannelMsgBuilder},
+    setup::setup,
+    utils::get_event_attribute,
+};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let creator2 = setup_response.test_accounts.creator2.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Set a username change fee and a hold period of 100 seconds
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminSetConfig {
+                channel_creation_fee: None,
+                fee_collector: None,
+                tip_fee_rate: None,
+                username_change_fee: Some(vec![coin(1000, "uflix")]),
+                username_hold_period: Some(100),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Create two channels
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+    let _res = app
+        .execute_contract(
+            creator2.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator2", creator2.clone())
+                .salt(Binary::from("salt2".as_bytes()))
+                .build(),
+            &[],
+        )
+        .unwrap();
+
+    // Change the username
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelChangeUsername {
+                channel_id: channel_id.clone(),
+                new_user_name: "rebranded".to_string(),
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap();
+    assert_eq!(
+        get_event_attribute(res.clone(), "wasm", "old_user_name"),
+        "creator"
+    );
+    assert_eq!(
+        get_event_attribute(res.clone(), "wasm", "new_user_name"),
+        "rebranded"
+    );
+
+    // The fee is sent to the fee collector
+    let config: ChannelConractConfig = app
+        .wrap()
+        .query_wasm_smart(channel_contract_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    let recipient = get_event_attribute(res.clone(), "transfer", "recipient");
+    assert_eq!(recipient, config.auth_details.fee_collector.to_string());
+    let amount = get_event_attribute(res, "transfer", "amount");
+    assert_eq!(amount, "1000uflix");
+
+    // Both username mappings are updated
+    let details: ChannelDetails = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ChannelDetails {
+                channel_id: channel_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(details.user_name, "rebranded");
+    let queried_channel_id: String = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ChannelId {
+                user_name: "rebranded".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(queried_channel_id, channel_id);
+    let res = app.wrap().query_wasm_smart::<String>(
+        channel_contract_addr.clone(),
+        &QueryMsg::ChannelId {
+            user_name: "creator".to_string(),
+        },
+    );
+    assert!(res.is_err());
+
+    // The old username is held for the channel
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", collector.clone())
+                .salt(Binary::from("salt3".as_bytes()))
+                .build(),
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::UserNameOnHold {})
+    );
+
+    // The hold is released after the hold period
+    app.update_block(|block| block.time = block.time.plus_seconds(100));
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", collector.clone())
+                .salt(Binary::from("salt3".as_bytes()))
+                .build(),
+            &[],
+        )
+        .unwrap();
+}
+
+#[test]
+fn invalid_changes() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let creator2 = setup_response.test_accounts.creator2.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create two channels
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+    let _res = app
+        .execute_contract(
+            creator2.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator2", creator2.clone())
+                .salt(Binary::from("salt2".as_bytes()))
+                .build(),
+            &[],
+        )
+        .unwrap();
+
+    // Reserve a username
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminManageReservedUsernames {
+                add_usernames: Some(vec![ReservedUsername {
+                    username: "reserved".to_string(),
+                    address: None,
+                }]),
+                remove_usernames: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Only the channel owner can change the username
+    let res = app
+        .execute_contract(
+            creator2.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelChangeUsername {
+                channel_id: channel_id.clone(),
+                new_user_name: "newname".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    // Username taken by another channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelChangeUsername {
+                channel_id: channel_id.clone(),
+                new_user_name: "creator2".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::UserNameAlreadyTaken {})
+    );
+
+    // Reserved username
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelChangeUsername {
+                channel_id: channel_id.clone(),
+                new_user_name: "reserved".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::UserNameReserved {});
+
+    // Invalid username
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelChangeUsername {
+                channel_id: channel_id.clone(),
+                new_user_name: "a".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert!(matches!(typed_err, ContractError::StringValidationError(_)));
+
+    // The fee must be paid when one is configured
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminSetConfig {
+                channel_creation_fee: None,
+                fee_collector: None,
+                tip_fee_rate: None,
+                username_change_fee: Some(vec![coin(1000, "uflix")]),
+                username_hold_period: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelChangeUsername {
+                channel_id: channel_id.clone(),
+                new_user_name: "newname".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::PaymentError {
+            expected: vec![coin(1000, "uflix")],
+            received: vec![],
+        }
+    );
+}
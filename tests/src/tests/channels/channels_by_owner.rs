@@ -0,0 +1,207 @@
+use cosmwasm_std::{coin, Binary, Decimal};
+use cw_multi_test::Executor;
+use omniflix_channel_types::channel::{ChannelCollaborator, Role};
+use omniflix_channel_types::msg::{AddressChannelInfo, ChannelRelation, ExecuteMsg, QueryMsg};
+
+use crate::helpers::{
+    msg_wrapper::{get_channel_instantiate_msg, CreateChannelMsgBuilder},
+    setup::setup,
+    utils::{get_event_attribute, transfer_onft_msg},
+};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let creator2 = setup_response.test_accounts.creator2.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel for each creator
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res.clone(), "wasm", "channel_id");
+    let onft_id = get_event_attribute(res, "wasm", "onft_id");
+
+    let res = app
+        .execute_contract(
+            creator2.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator2", creator2.clone())
+                .salt(Binary::from("salt2".as_bytes()))
+                .build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id2 = get_event_attribute(res, "wasm", "channel_id");
+
+    // Creator collaborates on the second channel
+    let _res = app
+        .execute_contract(
+            creator2.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelInviteCollaborator {
+                channel_id: channel_id2.clone(),
+                collaborator_address: creator.to_string(),
+                collaborator_details: ChannelCollaborator {
+                    role: Role::Publisher,
+                    share: Decimal::percent(10),
+                    expires_at: None,
+                },
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id2.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let channels: Vec<AddressChannelInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ChannelsByOwner {
+                address: creator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let mut expected = vec![
+        AddressChannelInfo {
+            channel_id: channel_id.clone(),
+            user_name: "creator".to_string(),
+            relation: ChannelRelation::Owner,
+        },
+        AddressChannelInfo {
+            channel_id: channel_id2.clone(),
+            user_name: "creator2".to_string(),
+            relation: ChannelRelation::Collaborator {
+                role: "publisher".to_string(),
+                share: Decimal::percent(10),
+                expires_at: None,
+            },
+        },
+    ];
+    expected.sort_by(|a, b| a.channel_id.cmp(&b.channel_id));
+    assert_eq!(channels, expected);
+
+    // Pagination
+    let channels: Vec<AddressChannelInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ChannelsByOwner {
+                address: creator.to_string(),
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(channels, expected[..1].to_vec());
+    let channels: Vec<AddressChannelInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ChannelsByOwner {
+                address: creator.to_string(),
+                start_after: Some(expected[0].channel_id.clone()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(channels, expected[1..].to_vec());
+
+    // Creator transfers the channel ONFT
+    let _res = app
+        .execute(
+            creator.clone(),
+            transfer_onft_msg(
+                "Channels".to_string(),
+                onft_id,
+                creator.to_string(),
+                collaborator.to_string(),
+            ),
+        )
+        .unwrap();
+
+    // The transferred channel is no longer listed for the previous owner
+    let channels: Vec<AddressChannelInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ChannelsByOwner {
+                address: creator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(channels.len(), 1);
+    assert_eq!(channels[0].channel_id, channel_id2);
+
+    // Anyone can record the new owner
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSyncOwner {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+    let owner = get_event_attribute(res, "wasm", "owner");
+    assert_eq!(owner, collaborator.to_string());
+
+    let channels: Vec<AddressChannelInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ChannelsByOwner {
+                address: collaborator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        channels,
+        vec![AddressChannelInfo {
+            channel_id: channel_id.clone(),
+            user_name: "creator".to_string(),
+            relation: ChannelRelation::Owner,
+        }]
+    );
+}
@@ -0,0 +1,228 @@
+use channel_manager::error::ChannelError;
+use cosmwasm_std::{coin, Decimal};
+use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::channel::{ChannelCollaborator, Role};
+use omniflix_channel_types::msg::{CollaboratorInfo, ExecuteMsg, QueryMsg};
+
+use crate::helpers::{
+    msg_wrapper::{get_channel_instantiate_msg, CreateChannelMsgBuilder},
+    setup::setup,
+    utils::get_event_attribute,
+};
+
+#[test]
+fn expired_collaborator() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Expiration in the past is rejected
+    let now = app.block_info().time;
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelInviteCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collaborator.to_string(),
+                collaborator_details: ChannelCollaborator {
+                    role: Role::Admin,
+                    share: Decimal::percent(50),
+                    expires_at: Some(now),
+                },
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidExpiration {});
+
+    // Add an admin collaborator with a 50% share for 100 seconds
+    let expires_at = now.plus_seconds(100);
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelInviteCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collaborator.to_string(),
+                collaborator_details: ChannelCollaborator {
+                    role: Role::Admin,
+                    share: Decimal::percent(50),
+                    expires_at: Some(expires_at),
+                },
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Tip while the collaborator is active
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(1000, "uflix")],
+                asset_id: None,
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap();
+    let collaborator_share = get_event_attribute(res, "wasm", collaborator.as_str());
+    assert_eq!(collaborator_share, "500uflix");
+
+    // Move past the expiration
+    app.update_block(|block| block.time = expires_at);
+
+    // Expired collaborator can not act on the channel
+    let res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelInviteCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collector.to_string(),
+                collaborator_details: ChannelCollaborator {
+                    role: Role::Publisher,
+                    share: Decimal::percent(10),
+                    expires_at: None,
+                },
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::CollaboratorExpired {})
+    );
+
+    // Expired collaborators can not be updated back into a share
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelUpdateCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collaborator.to_string(),
+                role: None,
+                share: Some(Decimal::percent(40)),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::CollaboratorExpired {})
+    );
+
+    // The whole tip goes to the payment address
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(1000, "uflix")],
+                asset_id: None,
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap();
+    let creator_share = get_event_attribute(res.clone(), "wasm", creator.as_str());
+    assert_eq!(creator_share, "1000uflix");
+    let recipient = get_event_attribute(res, "transfer", "recipient");
+    assert_eq!(recipient, creator.to_string());
+
+    // Anyone can prune the expired collaborators
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelPruneCollaborators {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+    let pruned = get_event_attribute(res, "wasm", "pruned_collaborators");
+    assert_eq!(pruned, collaborator.to_string());
+
+    let collaborators: Vec<CollaboratorInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::GetChannelCollaborators {
+                channel_id: channel_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(collaborators.is_empty());
+
+    // The freed share can be given to a new collaborator
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelInviteCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collector.to_string(),
+                collaborator_details: ChannelCollaborator {
+                    role: Role::Publisher,
+                    share: Decimal::percent(100),
+                    expires_at: None,
+                },
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap();
+}
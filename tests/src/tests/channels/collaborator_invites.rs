@@ -0,0 +1,477 @@
+use channel_manager::error::ChannelError;
+use cosmwasm_std::{coin, Decimal};
+use cw_multi_test::Executor;
+use cw_utils::Expiration;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::channel::{ChannelCollaborator, Role};
+use omniflix_channel_types::msg::{CollaboratorInfo, CollaboratorInviteInfo, ExecuteMsg, QueryMsg};
+
+use crate::helpers::{
+    msg_wrapper::{get_channel_instantiate_msg, CreateChannelMsgBuilder},
+    setup::setup,
+    utils::get_event_attribute,
+};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Invite the collaborator
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelInviteCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collaborator.to_string(),
+                collaborator_details: ChannelCollaborator {
+                    role: Role::Publisher,
+                    share: Decimal::percent(20),
+                    expires_at: None,
+                },
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // The invite is pending, the collaborator is not added yet
+    let invites: Vec<CollaboratorInviteInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::CollaboratorInvites {
+                address: collaborator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        invites,
+        vec![CollaboratorInviteInfo {
+            channel_id: channel_id.clone(),
+            role: Role::Publisher.to_string(),
+            share: Decimal::percent(20),
+            expiry: None,
+        }]
+    );
+
+    let collaborators: Vec<CollaboratorInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::GetChannelCollaborators {
+                channel_id: channel_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(collaborators.is_empty());
+
+    // Accept the invite
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let collaborator_info: CollaboratorInfo = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::GetChannelCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collaborator.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(collaborator_info.share, Decimal::percent(20));
+
+    let invites: Vec<CollaboratorInviteInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::CollaboratorInvites {
+                address: collaborator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(invites.is_empty());
+
+    // The collaborator leaves the channel
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelLeaveCollaboration {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let collaborators: Vec<CollaboratorInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::GetChannelCollaborators {
+                channel_id: channel_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(collaborators.is_empty());
+
+    // Can not leave twice
+    let res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelLeaveCollaboration {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::CollaboratorNotFound {})
+    );
+}
+
+#[test]
+fn decline_and_cancel() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    let invite_msg = ExecuteMsg::ChannelInviteCollaborator {
+        channel_id: channel_id.clone(),
+        collaborator_address: collaborator.to_string(),
+        collaborator_details: ChannelCollaborator {
+            role: Role::Publisher,
+            share: Decimal::percent(20),
+            expires_at: None,
+        },
+        expiry: None,
+    };
+
+    // Invite and decline
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &invite_msg,
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelDeclineCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::CollaboratorInviteNotFound {})
+    );
+
+    // Invite again and cancel as the owner
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &invite_msg,
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelRemoveCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collaborator.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::CollaboratorInviteNotFound {})
+    );
+}
+
+#[test]
+fn expired_invite() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    let expiry_height = app.block_info().height + 10;
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelInviteCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collaborator.to_string(),
+                collaborator_details: ChannelCollaborator {
+                    role: Role::Publisher,
+                    share: Decimal::percent(20),
+                    expires_at: None,
+                },
+                expiry: Some(Expiration::AtHeight(expiry_height)),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Move past the expiry
+    app.update_block(|block| block.height = expiry_height);
+
+    // Expired invites are not listed
+    let invites: Vec<CollaboratorInviteInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::CollaboratorInvites {
+                address: collaborator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(invites.is_empty());
+
+    let res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::CollaboratorInviteExpired {})
+    );
+}
+
+#[test]
+fn shares_counted_after_acceptance() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Two pending invites that together exceed 100%
+    for invitee in [collaborator.clone(), collector.clone()] {
+        let _res = app
+            .execute_contract(
+                creator.clone(),
+                channel_contract_addr.clone(),
+                &ExecuteMsg::ChannelInviteCollaborator {
+                    channel_id: channel_id.clone(),
+                    collaborator_address: invitee.to_string(),
+                    collaborator_details: ChannelCollaborator {
+                        role: Role::Publisher,
+                        share: Decimal::percent(60),
+                        expires_at: None,
+                    },
+                    expiry: None,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    // First acceptance succeeds
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Second acceptance would exceed the total share
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::InvalidSharePercentage {})
+    );
+}
@@ -51,13 +51,15 @@ fn add_collaborator_unauthorized() {
     let channel_id = get_event_attribute(res, "wasm", "channel_id");
 
     // Try to add collaborator as unauthorized user
-    let msg = ExecuteMsg::ChannelAddCollaborator {
+    let msg = ExecuteMsg::ChannelInviteCollaborator {
         channel_id: channel_id.clone(),
         collaborator_address: creator.clone().into_string(),
         collaborator_details: ChannelCollaborator {
             role: Role::Moderator,
             share: Decimal::from_ratio(Uint128::one(), Uint128::from(3u128)),
+            expires_at: None,
         },
+        expiry: None,
     };
 
     let res = app
@@ -108,13 +110,15 @@ fn add_collaborator_invalid_share() {
     let channel_id = get_event_attribute(res, "wasm", "channel_id");
 
     // Try to add collaborator with share > 100%
-    let msg = ExecuteMsg::ChannelAddCollaborator {
+    let msg = ExecuteMsg::ChannelInviteCollaborator {
         channel_id: channel_id.clone(),
         collaborator_address: collector.clone().into_string(),
         collaborator_details: ChannelCollaborator {
             role: Role::Moderator,
             share: Decimal::percent(101),
+            expires_at: None,
         },
+        expiry: None,
     };
 
     let res = app
@@ -171,28 +175,42 @@ fn add_collaborator_total_unique_limit_exceeded() {
         // Generate a valid cosmos collaborator address
         let collaborator_address = app.api().addr_make(format!("collaborator_{}", i).as_str());
 
-        let msg = ExecuteMsg::ChannelAddCollaborator {
+        let msg = ExecuteMsg::ChannelInviteCollaborator {
             channel_id: channel_id.clone(),
             collaborator_address: collaborator_address.clone().into_string(),
             collaborator_details: ChannelCollaborator {
                 role: Role::Moderator,
                 share: Decimal::percent(9),
+                expires_at: None,
             },
+            expiry: None,
         };
 
         let _res = app
             .execute_contract(creator.clone(), channel_contract_addr.clone(), &msg, &[])
             .unwrap();
+        let _res = app
+            .execute_contract(
+                collaborator_address.clone(),
+                channel_contract_addr.clone(),
+                &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                    channel_id: channel_id.clone(),
+                },
+                &[],
+            )
+            .unwrap();
     }
 
     // Try to add another collaborator should fail
-    let msg = ExecuteMsg::ChannelAddCollaborator {
+    let msg = ExecuteMsg::ChannelInviteCollaborator {
         channel_id: channel_id.clone(),
         collaborator_address: app.api().addr_make("new_collaborator").into_string(),
         collaborator_details: ChannelCollaborator {
             role: Role::Moderator,
             share: Decimal::percent(9),
+            expires_at: None,
         },
+        expiry: None,
     };
 
     let res = app
@@ -246,18 +264,30 @@ fn happy_path() {
     let channel_id = get_event_attribute(res, "wasm", "channel_id");
 
     // Add collaborator successfully
-    let msg = ExecuteMsg::ChannelAddCollaborator {
+    let msg = ExecuteMsg::ChannelInviteCollaborator {
         channel_id: channel_id.clone(),
         collaborator_address: collector.clone().into_string(),
         collaborator_details: ChannelCollaborator {
             role: Role::Moderator,
             share: Decimal::percent(30),
+            expires_at: None,
         },
+        expiry: None,
     };
 
     let _res = app
         .execute_contract(creator.clone(), channel_contract_addr.clone(), &msg, &[])
         .unwrap();
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
 
     // Verify collaborator was added
     let query_msg = QueryMsg::GetChannelCollaborator {
@@ -315,18 +345,30 @@ fn remove_collaborator_unauthorized() {
     let channel_id = get_event_attribute(res, "wasm", "channel_id");
 
     // Add collaborator successfully
-    let msg = ExecuteMsg::ChannelAddCollaborator {
+    let msg = ExecuteMsg::ChannelInviteCollaborator {
         channel_id: channel_id.clone(),
         collaborator_address: creator_2.clone().into_string(),
         collaborator_details: ChannelCollaborator {
             role: Role::Moderator,
             share: Decimal::percent(30),
+            expires_at: None,
         },
+        expiry: None,
     };
 
     let _res = app
         .execute_contract(creator.clone(), channel_contract_addr.clone(), &msg, &[])
         .unwrap();
+    let _res = app
+        .execute_contract(
+            creator_2.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
 
     // Try to remove collaborator as unauthorized user
     let msg = ExecuteMsg::ChannelRemoveCollaborator {
@@ -382,18 +424,30 @@ fn remove_collaborator_happy_path() {
     let channel_id = get_event_attribute(res, "wasm", "channel_id");
 
     // Add collaborator successfully
-    let msg = ExecuteMsg::ChannelAddCollaborator {
+    let msg = ExecuteMsg::ChannelInviteCollaborator {
         channel_id: channel_id.clone(),
         collaborator_address: collector.clone().into_string(),
         collaborator_details: ChannelCollaborator {
             role: Role::Moderator,
             share: Decimal::percent(30),
+            expires_at: None,
         },
+        expiry: None,
     };
 
     let _res = app
         .execute_contract(creator.clone(), channel_contract_addr.clone(), &msg, &[])
         .unwrap();
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
 
     // Remove collaborator successfully
     let msg = ExecuteMsg::ChannelRemoveCollaborator {
@@ -419,3 +473,194 @@ fn remove_collaborator_happy_path() {
 
     assert_eq!(collaborators.len(), 0);
 }
+
+#[test]
+fn update_collaborator() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let create_channel_msg = CreateChannelMsgBuilder::new("creator", creator.clone()).build();
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &create_channel_msg,
+            &[],
+        )
+        .unwrap();
+
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Add two collaborators with 30% each
+    for address in [collector.clone(), collaborator.clone()] {
+        let msg = ExecuteMsg::ChannelInviteCollaborator {
+            channel_id: channel_id.clone(),
+            collaborator_address: address.clone().into_string(),
+            collaborator_details: ChannelCollaborator {
+                role: Role::Publisher,
+                share: Decimal::percent(30),
+                expires_at: None,
+            },
+            expiry: None,
+        };
+        let _res = app
+            .execute_contract(creator.clone(), channel_contract_addr.clone(), &msg, &[])
+            .unwrap();
+        let _res = app
+            .execute_contract(
+                address.clone(),
+                channel_contract_addr.clone(),
+                &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                    channel_id: channel_id.clone(),
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    // Only the channel owner can update a collaborator
+    let res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelUpdateCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collector.clone().into_string(),
+                role: Some(Role::Moderator),
+                share: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    // Total share can not exceed 100%
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelUpdateCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collector.clone().into_string(),
+                role: None,
+                share: Some(Decimal::percent(71)),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::InvalidSharePercentage {})
+    );
+
+    // Update the role and share
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelUpdateCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collector.clone().into_string(),
+                role: Some(Role::Moderator),
+                share: Some(Decimal::percent(70)),
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        get_event_attribute(res.clone(), "wasm", "old_role"),
+        "publisher"
+    );
+    assert_eq!(
+        get_event_attribute(res.clone(), "wasm", "new_role"),
+        "moderator"
+    );
+    assert_eq!(get_event_attribute(res.clone(), "wasm", "old_share"), "0.3");
+    assert_eq!(get_event_attribute(res, "wasm", "new_share"), "0.7");
+
+    let collaborator_info: CollaboratorInfo = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::GetChannelCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collector.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(collaborator_info.role, "moderator");
+    assert_eq!(collaborator_info.share, Decimal::percent(70));
+
+    // Lowering the share frees room for the other collaborator
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelUpdateCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collector.clone().into_string(),
+                role: None,
+                share: Some(Decimal::percent(10)),
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelUpdateCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collaborator.clone().into_string(),
+                role: None,
+                share: Some(Decimal::percent(90)),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Unknown collaborator
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelUpdateCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: admin.clone().into_string(),
+                role: Some(Role::Admin),
+                share: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::CollaboratorNotFound {})
+    );
+}
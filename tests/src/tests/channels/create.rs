@@ -239,6 +239,86 @@ fn username_already_exists() {
     );
 }
 
+#[test]
+fn normalized_username_already_exists() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let creator2 = setup_response.test_accounts.creator2.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    let channel_create_msg = CreateChannelMsgBuilder::new("Creator", creator.clone()).build();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &channel_create_msg,
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Usernames that only differ in casing, separators or look-alike characters are taken
+    for user_name in ["creator", "creator_", "CRE.AT0R"] {
+        let channel_create_msg = CreateChannelMsgBuilder::new(user_name, creator2.clone())
+            .salt(Binary::from("salt2".as_bytes()))
+            .build();
+        let res = app
+            .execute_contract(
+                creator2.clone(),
+                channel_contract_addr.clone(),
+                &channel_create_msg,
+                &[],
+            )
+            .unwrap_err();
+        let typed_err = res.downcast_ref::<ContractError>().unwrap();
+        assert_eq!(
+            typed_err,
+            &ContractError::Channel(ChannelError::UserNameAlreadyTaken {})
+        );
+    }
+
+    // The channel can be found by any form of its username
+    let queried_channel_id: String = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ChannelId {
+                user_name: "CREATOR".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(queried_channel_id, channel_id);
+
+    // The display casing is kept
+    let channel: ChannelResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Channel {
+                channel_id: channel_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(channel.user_name, "Creator");
+}
+
 #[test]
 fn happy_path() {
     // Setup testing environment
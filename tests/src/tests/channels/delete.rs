@@ -1,12 +1,13 @@
-use crate::helpers::msg_wrapper::CreateChannelMsgBuilder;
+use crate::helpers::msg_wrapper::{AssetPublishMsgBuilder, CreateChannelMsgBuilder};
 use crate::helpers::setup::setup;
 use crate::helpers::utils::get_event_attribute;
-use cosmwasm_std::coin;
+use cosmwasm_std::{coin, Addr, Binary, Decimal};
 use cw_multi_test::Executor;
 use omniflix_channel::ContractError;
 use omniflix_channel_types::{
-    channel::ChannelDetails,
-    msg::{ExecuteMsg, QueryMsg},
+    asset::{Flag, FlagReport, PurchaseReceipt},
+    channel::{ChannelCleanupProgress, ChannelCollaborator, ChannelDetails, CleanupStage, Role},
+    msg::{ExecuteMsg, FlaggerInfo, QueryMsg},
 };
 
 #[test]
@@ -279,3 +280,438 @@ fn delete_nonexistent_channel() {
         &ContractError::Channel(channel_manager::error::ChannelError::ChannelIdNotFound {})
     );
 }
+
+#[test]
+fn delete_channel_in_batches() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate the contract
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &crate::helpers::msg_wrapper::get_channel_instantiate_msg(admin.clone()),
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Rename the channel so that its previous username is held for it
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminSetConfig {
+                channel_creation_fee: None,
+                fee_collector: None,
+                tip_fee_rate: None,
+                username_change_fee: None,
+                username_hold_period: Some(1000),
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelChangeUsername {
+                channel_id: channel_id.clone(),
+                new_user_name: "renamed".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Add a collaborator
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelInviteCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collaborator.to_string(),
+                collaborator_details: ChannelCollaborator {
+                    role: Role::Publisher,
+                    share: Decimal::percent(10),
+                    expires_at: None,
+                },
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Publish, tip and purchase an asset
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone())
+                .price(coin(1000, "uflix"))
+                .build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res, "wasm", "publish_id");
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AssetPurchase {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(1000, "uflix")],
+                asset_id: Some(publish_id.clone()),
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap();
+
+    // More followers than can be removed with the channel
+    let followers: Vec<Addr> = (0..120)
+        .map(|i| app.api().addr_make(&format!("follower{}", i)))
+        .collect();
+    for follower in followers.iter() {
+        let _res = app
+            .execute_contract(
+                follower.clone(),
+                channel_contract_addr.clone(),
+                &ExecuteMsg::ChannelFollow {
+                    channel_id: channel_id.clone(),
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    // Delete the channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelDelete {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        get_event_attribute(res, "wasm", "cleanup_complete"),
+        "false"
+    );
+
+    // The collaborator and the first 99 followers are removed
+    let progress: Option<ChannelCleanupProgress> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ChannelCleanupProgress {
+                channel_id: channel_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        progress,
+        Some(ChannelCleanupProgress {
+            stage: CleanupStage::Followers,
+            removed_entries: 100,
+        })
+    );
+
+    // Anyone can continue the cleanup
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelCleanup {
+                channel_id: channel_id.clone(),
+                limit: Some(10),
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        get_event_attribute(res, "wasm", "cleanup_complete"),
+        "false"
+    );
+
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelCleanup {
+                channel_id: channel_id.clone(),
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(get_event_attribute(res, "wasm", "cleanup_complete"), "true");
+
+    let progress: Option<ChannelCleanupProgress> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ChannelCleanupProgress {
+                channel_id: channel_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(progress, None);
+
+    // No state is left behind
+    let remaining_followers: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Followers {
+                channel_id: channel_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(remaining_followers.is_empty());
+
+    let has_purchased: bool = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::HasPurchased {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                address: collector.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(!has_purchased);
+    let buyers: Vec<PurchaseReceipt> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AssetBuyers {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(buyers.is_empty());
+
+    // The previous username is no longer held for the deleted channel
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", collector.clone())
+                .salt(Binary::from("salt2".as_bytes()))
+                .build(),
+            &[],
+        )
+        .unwrap();
+
+    // Nothing left to clean up
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelCleanup {
+                channel_id: channel_id.clone(),
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::NoPendingChannelCleanup {});
+}
+
+#[test]
+fn delete_channel_with_flagged_asset() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate the contract
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &crate::helpers::msg_wrapper::get_channel_instantiate_msg(admin.clone()),
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res, "wasm", "publish_id");
+
+    // More flaggers and flag reports than can be removed with the channel
+    for i in 0..60 {
+        let flagger = app.api().addr_make(&format!("flagger{}", i));
+        let _res = app
+            .execute_contract(
+                flagger,
+                channel_contract_addr.clone(),
+                &ExecuteMsg::AssetFlag {
+                    channel_id: channel_id.clone(),
+                    publish_id: publish_id.clone(),
+                    flag: Flag::NSFW,
+                    interactive_video_id: None,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    // Delete the channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelDelete {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(
+        get_event_attribute(res, "wasm", "cleanup_complete"),
+        "false"
+    );
+
+    // The flaggers and the first 40 flag reports are removed, the asset is kept until all are gone
+    let progress: Option<ChannelCleanupProgress> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ChannelCleanupProgress {
+                channel_id: channel_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        progress,
+        Some(ChannelCleanupProgress {
+            stage: CleanupStage::Assets,
+            removed_entries: 100,
+        })
+    );
+    let flaggers: Vec<FlaggerInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AssetFlaggers {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(flaggers.is_empty());
+    let reports: Vec<FlagReport> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AssetFlagReports {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(reports.len(), 20);
+
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelCleanup {
+                channel_id: channel_id.clone(),
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(get_event_attribute(res, "wasm", "cleanup_complete"), "true");
+
+    let reports: Vec<FlagReport> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AssetFlagReports {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(reports.is_empty());
+}
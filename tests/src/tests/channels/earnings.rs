@@ -0,0 +1,257 @@
+use crate::helpers::msg_wrapper::{get_channel_instantiate_msg, CreateChannelMsgBuilder};
+use crate::helpers::setup::setup;
+use crate::helpers::utils::get_event_attribute;
+use cosmwasm_std::{coin, Coin, Decimal};
+use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::channel::{ChannelCollaborator, PayoutMode, Role};
+use omniflix_channel_types::msg::{ChannelResponse, ExecuteMsg, QueryMsg};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Add collaborator with a 25% share
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelInviteCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collaborator.to_string(),
+                collaborator_details: ChannelCollaborator {
+                    role: Role::Publisher,
+                    share: Decimal::percent(25),
+                    expires_at: None,
+                },
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Switch the channel to pull payouts
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSetPayoutMode {
+                channel_id: channel_id.clone(),
+                payout_mode: PayoutMode::Pull,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let channel: ChannelResponse = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Channel {
+                channel_id: channel_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(channel.payout_mode, PayoutMode::Pull);
+
+    let collaborator_balance_before = app.wrap().query_balance(&collaborator, "uflix").unwrap();
+
+    // Tip twice, no bank sends are made
+    for _ in 0..2 {
+        let res = app
+            .execute_contract(
+                collector.clone(),
+                channel_contract_addr.clone(),
+                &ExecuteMsg::ChannelTip {
+                    channel_id: channel_id.clone(),
+                    amount: vec![coin(1000, "uflix")],
+                    asset_id: None,
+                },
+                &[coin(1000, "uflix")],
+            )
+            .unwrap();
+        assert!(!res.events.iter().any(|event| event.ty == "transfer"));
+    }
+
+    let earnings: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::PendingEarnings {
+                address: collaborator.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(earnings, vec![coin(500, "uflix")]);
+
+    let earnings: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::PendingEarnings {
+                address: creator.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(earnings, vec![coin(1500, "uflix")]);
+
+    // Withdraw the collaborator earnings
+    let res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::WithdrawEarnings {},
+            &[],
+        )
+        .unwrap();
+    let amount = get_event_attribute(res, "wasm", "amount");
+    assert_eq!(amount, "500uflix");
+
+    let collaborator_balance_after = app.wrap().query_balance(&collaborator, "uflix").unwrap();
+    assert_eq!(
+        collaborator_balance_after.amount - collaborator_balance_before.amount,
+        cosmwasm_std::Uint128::new(500)
+    );
+
+    // Nothing left to withdraw
+    let res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::WithdrawEarnings {},
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::NoPendingEarnings {});
+
+    // Back to push payouts, tips are sent right away
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSetPayoutMode {
+                channel_id: channel_id.clone(),
+                payout_mode: PayoutMode::Push,
+            },
+            &[],
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(1000, "uflix")],
+                asset_id: None,
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap();
+    assert!(res.events.iter().any(|event| event.ty == "transfer"));
+
+    // Earnings credited before the switch are still withdrawable
+    let earnings: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::PendingEarnings {
+                address: creator.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(earnings, vec![coin(1500, "uflix")]);
+}
+
+#[test]
+fn unauthorized_set_payout_mode() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSetPayoutMode {
+                channel_id: channel_id.clone(),
+                payout_mode: PayoutMode::Pull,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+}
@@ -2,7 +2,7 @@ use crate::helpers::msg_wrapper::{get_channel_instantiate_msg, CreateChannelMsgB
 use crate::helpers::setup::setup;
 use crate::helpers::utils::get_event_attribute;
 use channel_manager::error::ChannelError;
-use cosmwasm_std::{coin, Addr};
+use cosmwasm_std::{coin, Addr, Binary};
 use cw_multi_test::Executor;
 use omniflix_channel::ContractError;
 use omniflix_channel_types::msg::{ExecuteMsg, QueryMsg};
@@ -192,3 +192,141 @@ fn happy_path() {
     assert_eq!(followers.len(), 1);
     assert_eq!(followers[0], follower);
 }
+
+#[test]
+fn following() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let creator2 = setup_response.test_accounts.creator2.clone();
+    let follower = setup_response.test_accounts.collector.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create two channels
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    let res = app
+        .execute_contract(
+            creator2.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator2", creator2.clone())
+                .salt(Binary::from("salt2".as_bytes()))
+                .build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id2 = get_event_attribute(res, "wasm", "channel_id");
+
+    // Follow both channels
+    for channel_id in [channel_id.clone(), channel_id2.clone()] {
+        let _res = app
+            .execute_contract(
+                follower.clone(),
+                channel_contract_addr.clone(),
+                &ExecuteMsg::ChannelFollow { channel_id },
+                &[],
+            )
+            .unwrap();
+    }
+
+    let following: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Following {
+                address: follower.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let mut expected = vec![channel_id.clone(), channel_id2.clone()];
+    expected.sort();
+    assert_eq!(following, expected);
+
+    let is_following: bool = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::IsFollowing {
+                channel_id: channel_id.clone(),
+                address: follower.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(is_following);
+
+    // Unfollow the first channel
+    let _res = app
+        .execute_contract(
+            follower.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelUnfollow {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let is_following: bool = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::IsFollowing {
+                channel_id: channel_id.clone(),
+                address: follower.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(!is_following);
+
+    // Deleting the second channel removes it from the followed channels
+    let _res = app
+        .execute_contract(
+            creator2.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelDelete {
+                channel_id: channel_id2.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let following: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Following {
+                address: follower.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(following.is_empty());
+}
@@ -1,6 +1,13 @@
+pub mod change_username;
+pub mod channels_by_owner;
+pub mod collaborator_expiry;
+pub mod collaborator_invites;
 pub mod collaborators;
 pub mod create;
+pub mod earnings;
 pub mod manage_reserved_usernames;
+pub mod subscriptions;
+pub mod tip_stats;
 pub mod tipping;
 pub mod update_channel_details;
 
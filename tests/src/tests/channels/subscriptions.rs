@@ -0,0 +1,343 @@
+use channel_manager::error::ChannelError;
+use cosmwasm_std::{coin, Decimal};
+use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::channel::{ChannelCollaborator, Role, SubscriptionPlan};
+use omniflix_channel_types::msg::{ExecuteMsg, QueryMsg, SubscriberInfo};
+
+use crate::helpers::{
+    msg_wrapper::{get_channel_instantiate_msg, CreateChannelMsgBuilder},
+    setup::setup,
+    utils::get_event_attribute,
+};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+    let subscriber = setup_response.test_accounts.collector.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Add a collaborator with a 10% share
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelInviteCollaborator {
+                channel_id: channel_id.clone(),
+                collaborator_address: collaborator.to_string(),
+                collaborator_details: ChannelCollaborator {
+                    role: Role::Publisher,
+                    share: Decimal::percent(10),
+                    expires_at: None,
+                },
+                expiry: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Set a plan of 1000uflix per 100 seconds
+    let plan = SubscriptionPlan {
+        price: coin(1000, "uflix"),
+        period: 100,
+    };
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSetSubscriptionPlan {
+                channel_id: channel_id.clone(),
+                plan: Some(plan.clone()),
+            },
+            &[],
+        )
+        .unwrap();
+    let queried_plan: Option<SubscriptionPlan> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::SubscriptionPlan {
+                channel_id: channel_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(queried_plan, Some(plan));
+
+    // Subscribe for two periods
+    let res = app
+        .execute_contract(
+            subscriber.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSubscribe {
+                channel_id: channel_id.clone(),
+                periods: 2,
+            },
+            &[coin(2000, "uflix")],
+        )
+        .unwrap();
+
+    // Revenue is split through the collaborator shares
+    let collaborator_share = get_event_attribute(res.clone(), "wasm", collaborator.as_str());
+    assert_eq!(collaborator_share, "200uflix");
+    let creator_share = get_event_attribute(res.clone(), "wasm", creator.as_str());
+    assert_eq!(creator_share, "1800uflix");
+    let expires_at = app.block_info().time.plus_seconds(200);
+    assert_eq!(
+        get_event_attribute(res, "wasm", "expires_at"),
+        expires_at.to_string()
+    );
+
+    let is_subscribed: bool = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::IsSubscribed {
+                channel_id: channel_id.clone(),
+                address: subscriber.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(is_subscribed);
+
+    let subscribers: Vec<SubscriberInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Subscribers {
+                channel_id: channel_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        subscribers,
+        vec![SubscriberInfo {
+            address: subscriber.to_string(),
+            expires_at,
+        }]
+    );
+
+    // The subscription ends after the prepaid periods
+    app.update_block(|block| block.time = expires_at);
+    let is_subscribed: bool = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::IsSubscribed {
+                channel_id: channel_id.clone(),
+                address: subscriber.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(!is_subscribed);
+    let subscribers: Vec<SubscriberInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::Subscribers {
+                channel_id: channel_id.clone(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(subscribers.is_empty());
+}
+
+#[test]
+fn invalid_subscriptions() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let subscriber = setup_response.test_accounts.collector.clone();
+
+    // Instantiate Channel Contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // No plan set
+    let res = app
+        .execute_contract(
+            subscriber.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSubscribe {
+                channel_id: channel_id.clone(),
+                periods: 1,
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Channel(ChannelError::SubscriptionPlanNotFound {})
+    );
+
+    // Only the channel admin can set the plan
+    let plan = SubscriptionPlan {
+        price: coin(1000, "uflix"),
+        period: 100,
+    };
+    let res = app
+        .execute_contract(
+            subscriber.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSetSubscriptionPlan {
+                channel_id: channel_id.clone(),
+                plan: Some(plan.clone()),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::Unauthorized {});
+
+    // Zero period
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSetSubscriptionPlan {
+                channel_id: channel_id.clone(),
+                plan: Some(SubscriptionPlan {
+                    price: coin(1000, "uflix"),
+                    period: 0,
+                }),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidSubscriptionPlan {});
+
+    // Price in a denom that is not accepted
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSetSubscriptionPlan {
+                channel_id: channel_id.clone(),
+                plan: Some(SubscriptionPlan {
+                    price: coin(1000, "different_denom"),
+                    period: 100,
+                }),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidTipDenom {});
+
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSetSubscriptionPlan {
+                channel_id: channel_id.clone(),
+                plan: Some(plan),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Zero periods
+    let res = app
+        .execute_contract(
+            subscriber.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSubscribe {
+                channel_id: channel_id.clone(),
+                periods: 0,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidSubscriptionPeriods {});
+
+    // Payment must cover every period
+    let res = app
+        .execute_contract(
+            subscriber.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelSubscribe {
+                channel_id: channel_id.clone(),
+                periods: 3,
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::PaymentError {
+            expected: vec![coin(3000, "uflix")],
+            received: vec![coin(1000, "uflix")],
+        }
+    );
+}
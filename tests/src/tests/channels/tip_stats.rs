@@ -0,0 +1,256 @@
+use crate::helpers::msg_wrapper::{
+    get_channel_instantiate_msg, AssetPublishMsgBuilder, CreateChannelMsgBuilder,
+};
+use crate::helpers::setup::setup;
+use crate::helpers::utils::get_event_attribute;
+use asset_manager::error::AssetError;
+use cosmwasm_std::coin;
+use cw_multi_test::Executor;
+use omniflix_channel::ContractError;
+use omniflix_channel_types::msg::{ExecuteMsg, QueryMsg, TipStats, TipperInfo};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+
+    let mut instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    instantiate_msg.accepted_tip_denoms = vec!["uflix".to_string(), "different_denom".to_string()];
+
+    // Instantiate the contract
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Publish an asset
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &AssetPublishMsgBuilder::new(channel_id.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let publish_id = get_event_attribute(res, "wasm", "publish_id");
+
+    // Tip the asset
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(1000, "uflix"), coin(50, "different_denom")],
+                asset_id: Some(publish_id.clone()),
+            },
+            &[coin(1000, "uflix"), coin(50, "different_denom")],
+        )
+        .unwrap();
+
+    // Tip the channel
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(3000, "uflix")],
+                asset_id: None,
+            },
+            &[coin(3000, "uflix")],
+        )
+        .unwrap();
+
+    let stats: TipStats = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::ChannelTipStats {
+                channel_id: channel_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        stats,
+        TipStats {
+            total: vec![coin(50, "different_denom"), coin(4000, "uflix")],
+            tip_count: 2,
+        }
+    );
+
+    let stats: TipStats = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::AssetTipStats {
+                channel_id: channel_id.clone(),
+                publish_id: publish_id.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        stats,
+        TipStats {
+            total: vec![coin(50, "different_denom"), coin(1000, "uflix")],
+            tip_count: 1,
+        }
+    );
+
+    let totals: Vec<cosmwasm_std::Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::TipperTotals {
+                channel_id: channel_id.clone(),
+                tipper: collector.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        totals,
+        vec![coin(50, "different_denom"), coin(1000, "uflix")]
+    );
+
+    let top_tippers: Vec<TipperInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::TopTippers {
+                channel_id: channel_id.clone(),
+                denom: "uflix".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        top_tippers,
+        vec![
+            TipperInfo {
+                address: collaborator.to_string(),
+                amount: coin(3000, "uflix"),
+            },
+            TipperInfo {
+                address: collector.to_string(),
+                amount: coin(1000, "uflix"),
+            },
+        ]
+    );
+
+    // Paginate after the first tipper
+    let top_tippers: Vec<TipperInfo> = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr.clone(),
+            &QueryMsg::TopTippers {
+                channel_id: channel_id.clone(),
+                denom: "uflix".to_string(),
+                start_after: Some(collaborator.to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        top_tippers,
+        vec![TipperInfo {
+            address: collector.to_string(),
+            amount: coin(1000, "uflix"),
+        }]
+    );
+}
+
+#[test]
+fn tip_unknown_asset() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+
+    // Instantiate the contract
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &CreateChannelMsgBuilder::new("creator", creator.clone()).build(),
+            &[],
+        )
+        .unwrap();
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Tip an asset that does not exist in the channel
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(1000, "uflix")],
+                asset_id: Some("unknown".to_string()),
+            },
+            &[coin(1000, "uflix")],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::Asset(AssetError::AssetNotFound {})
+    );
+
+    // Nothing is recorded
+    let stats: TipStats = app
+        .wrap()
+        .query_wasm_smart(
+            channel_contract_addr,
+            &QueryMsg::ChannelTipStats { channel_id },
+        )
+        .unwrap();
+    assert_eq!(
+        stats,
+        TipStats {
+            total: vec![],
+            tip_count: 0,
+        }
+    );
+}
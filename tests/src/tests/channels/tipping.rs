@@ -91,10 +91,10 @@ fn invalid_tipping_denom() {
             channel_contract_addr.clone(),
             &ExecuteMsg::ChannelTip {
                 channel_id: channel_id.clone(),
-                amount: coin(100000, "different_denom"),
+                amount: vec![coin(100000, "different_denom")],
                 asset_id: None,
             },
-            &[coin(1000000, "different_denom")],
+            &[coin(100000, "different_denom")],
         )
         .unwrap_err();
 
@@ -148,7 +148,7 @@ fn invalid_tip_amount() {
             channel_contract_addr.clone(),
             &ExecuteMsg::ChannelTip {
                 channel_id: channel_id.clone(),
-                amount: coin(1000, "uflix"),
+                amount: vec![coin(1000, "uflix")],
                 asset_id: None,
             },
             &[coin(100, "uflix")],
@@ -156,7 +156,13 @@ fn invalid_tip_amount() {
         .unwrap_err();
 
     let typed_err = res.downcast_ref::<ContractError>().unwrap();
-    assert_eq!(typed_err, &ContractError::InvalidTipAmount {});
+    assert_eq!(
+        typed_err,
+        &ContractError::PaymentError {
+            expected: vec![coin(1000, "uflix")],
+            received: vec![coin(100, "uflix")],
+        }
+    );
     // Denom in the message is different from the denom in the coins
     let res = app
         .execute_contract(
@@ -164,7 +170,7 @@ fn invalid_tip_amount() {
             channel_contract_addr.clone(),
             &ExecuteMsg::ChannelTip {
                 channel_id: channel_id.clone(),
-                amount: coin(1000, "uflix"),
+                amount: vec![coin(1000, "uflix")],
                 asset_id: None,
             },
             &[coin(1000, "different_denom")],
@@ -174,8 +180,28 @@ fn invalid_tip_amount() {
     let typed_err = res.downcast_ref::<ContractError>().unwrap();
     assert_eq!(
         typed_err,
-        &ContractError::Payment(cw_utils::PaymentError::MissingDenom("uflix".to_string()))
+        &ContractError::PaymentError {
+            expected: vec![coin(1000, "uflix")],
+            received: vec![coin(1000, "different_denom")],
+        }
     );
+
+    // Empty tip
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![],
+                asset_id: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidTipAmount {});
 }
 
 #[test]
@@ -224,7 +250,7 @@ fn happy_path() {
             channel_contract_addr.clone(),
             &ExecuteMsg::ChannelTip {
                 channel_id: channel_id.clone(),
-                amount: coin(100000, "uflix"),
+                amount: vec![coin(100000, "uflix")],
                 asset_id: None,
             },
             &[coin(100000, "uflix")],
@@ -238,18 +264,30 @@ fn happy_path() {
     assert_eq!(recipient, creator.to_string());
 
     // Add collaborator
-    let msg = ExecuteMsg::ChannelAddCollaborator {
+    let msg = ExecuteMsg::ChannelInviteCollaborator {
         channel_id: channel_id.clone(),
         collaborator_address: collector.clone().into_string(),
         collaborator_details: omniflix_channel_types::channel::ChannelCollaborator {
             role: omniflix_channel_types::channel::Role::Moderator,
             share: Decimal::from_ratio(Uint128::one(), Uint128::from(3u128)),
+            expires_at: None,
         },
+        expiry: None,
     };
 
     let _res = app
         .execute_contract(creator.clone(), channel_contract_addr.clone(), &msg, &[])
         .unwrap();
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
 
     // Execute a tip
     let res = app
@@ -258,7 +296,7 @@ fn happy_path() {
             channel_contract_addr.clone(),
             &ExecuteMsg::ChannelTip {
                 channel_id: channel_id.clone(),
-                amount: coin(100000, "uflix"),
+                amount: vec![coin(100000, "uflix")],
                 asset_id: None,
             },
             &[coin(100000, "uflix")],
@@ -272,3 +310,249 @@ fn happy_path() {
     let amount = get_event_attribute(res.clone(), "wasm", &collector.into_string());
     assert_eq!(amount, "33333uflix");
 }
+
+#[test]
+fn protocol_fee() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+
+    let mut instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    instantiate_msg.channel_creation_fee = vec![coin(1000000, "uflix")];
+
+    // Instantiate the contract
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+    let channel_create_msg = CreateChannelMsgBuilder::new("creator", creator.clone()).build();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &channel_create_msg.clone(),
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Set a 10% tip fee
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::AdminSetConfig {
+                channel_creation_fee: None,
+                fee_collector: None,
+                tip_fee_rate: Some(Decimal::percent(10)),
+                username_change_fee: None,
+                username_hold_period: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Add collaborator with a 50% share
+    let msg = ExecuteMsg::ChannelInviteCollaborator {
+        channel_id: channel_id.clone(),
+        collaborator_address: collaborator.clone().into_string(),
+        collaborator_details: omniflix_channel_types::channel::ChannelCollaborator {
+            role: omniflix_channel_types::channel::Role::Moderator,
+            share: Decimal::percent(50),
+            expires_at: None,
+        },
+        expiry: None,
+    };
+    let _res = app
+        .execute_contract(creator.clone(), channel_contract_addr.clone(), &msg, &[])
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let fee_collector_balance_before = app.wrap().query_balance(&admin, "uflix").unwrap();
+
+    // Execute a tip
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(100000, "uflix")],
+                asset_id: None,
+            },
+            &[coin(100000, "uflix")],
+        )
+        .unwrap();
+
+    // The fee is taken before the collaborator split
+    let protocol_fee = get_event_attribute(res.clone(), "wasm", "protocol_fee");
+    assert_eq!(protocol_fee, "10000uflix");
+    let fee_collector = get_event_attribute(res.clone(), "wasm", "fee_collector");
+    assert_eq!(fee_collector, admin.to_string());
+    let creator_amount = get_event_attribute(res.clone(), "wasm", "creator_amount");
+    assert_eq!(creator_amount, "90000uflix");
+
+    let amount = get_event_attribute(res.clone(), "wasm", &collaborator.into_string());
+    assert_eq!(amount, "45000uflix");
+    let amount = get_event_attribute(res.clone(), "wasm", &creator.into_string());
+    assert_eq!(amount, "45000uflix");
+
+    let fee_collector_balance_after = app.wrap().query_balance(&admin, "uflix").unwrap();
+    assert_eq!(
+        fee_collector_balance_after.amount - fee_collector_balance_before.amount,
+        Uint128::new(10000)
+    );
+}
+
+#[test]
+fn multi_denom_tip() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+    let creator = setup_response.test_accounts.creator.clone();
+    let collector = setup_response.test_accounts.collector.clone();
+    let collaborator = setup_response.test_accounts.collaborator.clone();
+
+    let mut instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    instantiate_msg.channel_creation_fee = vec![coin(1000000, "uflix")];
+    instantiate_msg.accepted_tip_denoms = vec!["uflix".to_string(), "different_denom".to_string()];
+
+    // Instantiate the contract
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            None,
+        )
+        .unwrap();
+    let channel_create_msg = CreateChannelMsgBuilder::new("creator", creator.clone()).build();
+
+    // Create a channel
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            channel_contract_addr.clone(),
+            &channel_create_msg.clone(),
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+
+    let channel_id = get_event_attribute(res, "wasm", "channel_id");
+
+    // Add collaborator with a 50% share
+    let msg = ExecuteMsg::ChannelInviteCollaborator {
+        channel_id: channel_id.clone(),
+        collaborator_address: collaborator.clone().into_string(),
+        collaborator_details: omniflix_channel_types::channel::ChannelCollaborator {
+            role: omniflix_channel_types::channel::Role::Moderator,
+            share: Decimal::percent(50),
+            expires_at: None,
+        },
+        expiry: None,
+    };
+    let _res = app
+        .execute_contract(creator.clone(), channel_contract_addr.clone(), &msg, &[])
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collaborator.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelAcceptCollaboratorInvite {
+                channel_id: channel_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Tip with two denoms in a single message
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(1000, "uflix"), coin(500, "different_denom")],
+                asset_id: None,
+            },
+            &[coin(1000, "uflix"), coin(500, "different_denom")],
+        )
+        .unwrap();
+
+    // Each recipient gets a single transfer with both denoms
+    let transfers: Vec<(String, String)> = res
+        .events
+        .iter()
+        .filter(|event| event.ty == "transfer")
+        .map(|event| {
+            let attr = |key: &str| {
+                event
+                    .attributes
+                    .iter()
+                    .find(|attr| attr.key == key)
+                    .unwrap()
+                    .value
+                    .clone()
+            };
+            (attr("recipient"), attr("amount"))
+        })
+        .collect();
+    assert_eq!(
+        transfers,
+        vec![
+            (
+                collaborator.to_string(),
+                "250different_denom,500uflix".to_string()
+            ),
+            (
+                creator.to_string(),
+                "250different_denom,500uflix".to_string()
+            ),
+        ]
+    );
+
+    // Denom that is not accepted in a multi denom tip
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            channel_contract_addr.clone(),
+            &ExecuteMsg::ChannelTip {
+                channel_id: channel_id.clone(),
+                amount: vec![coin(1000, "uflix"), coin(500, "incorrect_denom")],
+                asset_id: None,
+            },
+            &[coin(1000, "uflix"), coin(500, "incorrect_denom")],
+        )
+        .unwrap_err();
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(typed_err, &ContractError::InvalidTipDenom {});
+}
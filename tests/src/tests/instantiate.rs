@@ -112,9 +112,11 @@ fn set_config() {
             creator.clone(),
             channel_contract_addr.clone(),
             &ExecuteMsg::AdminSetConfig {
-                protocol_admin: Some(creator.to_string()),
                 channel_creation_fee: Some(vec![]),
                 fee_collector: Some(creator.to_string()),
+                tip_fee_rate: None,
+                username_change_fee: None,
+                username_hold_period: None,
             },
             &[],
         )
@@ -129,9 +131,11 @@ fn set_config() {
             admin.clone(),
             channel_contract_addr.clone(),
             &ExecuteMsg::AdminSetConfig {
-                protocol_admin: Some(creator.to_string()),
                 channel_creation_fee: Some(vec![]),
                 fee_collector: Some(creator.to_string()),
+                tip_fee_rate: None,
+                username_change_fee: None,
+                username_hold_period: None,
             },
             &[],
         )
@@ -142,5 +146,5 @@ fn set_config() {
         .wrap()
         .query_wasm_smart(channel_contract_addr, &QueryMsg::Config {})
         .unwrap();
-    assert_eq!(config.auth_details.protocol_admin, creator);
+    assert_eq!(config.auth_details.fee_collector, creator);
 }
@@ -0,0 +1,154 @@
+use cosmwasm_std::coin;
+use cw2::{get_contract_version, set_contract_version};
+use cw_multi_test::Executor;
+use omniflix_channel::contract::{CONTRACT_NAME, CONTRACT_VERSION};
+use omniflix_channel::ContractError;
+use omniflix_channel_types::msg::MigrateMsg;
+
+use crate::helpers::{msg_wrapper::get_channel_instantiate_msg, setup::setup};
+
+#[test]
+fn happy_path() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+
+    // Instantiate the contract with a migration admin
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            Some(admin.to_string()),
+        )
+        .unwrap();
+
+    // Version is recorded at instantiate
+    let version = get_contract_version(&*app.contract_storage(&channel_contract_addr)).unwrap();
+    assert_eq!(version.contract, CONTRACT_NAME);
+    assert_eq!(version.version, CONTRACT_VERSION);
+
+    // Simulate an older deployment
+    set_contract_version(
+        &mut *app.contract_storage_mut(&channel_contract_addr),
+        CONTRACT_NAME,
+        "0.0.1",
+    )
+    .unwrap();
+
+    let _res = app
+        .migrate_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &MigrateMsg {},
+            setup_response.channel_contract_code_id,
+        )
+        .unwrap();
+
+    let version = get_contract_version(&*app.contract_storage(&channel_contract_addr)).unwrap();
+    assert_eq!(version.version, CONTRACT_VERSION);
+}
+
+#[test]
+fn refuse_downgrade() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+
+    // Instantiate the contract with a migration admin
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            Some(admin.to_string()),
+        )
+        .unwrap();
+
+    // Simulate a deployment running a newer version
+    set_contract_version(
+        &mut *app.contract_storage_mut(&channel_contract_addr),
+        CONTRACT_NAME,
+        "99.0.0",
+    )
+    .unwrap();
+
+    let res = app
+        .migrate_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &MigrateMsg {},
+            setup_response.channel_contract_code_id,
+        )
+        .unwrap_err();
+
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::CannotMigrateToOlderVersion {
+            stored: "99.0.0".to_string(),
+            new: CONTRACT_VERSION.to_string(),
+        }
+    );
+}
+
+#[test]
+fn wrong_contract_name() {
+    // Setup testing environment
+    let setup_response = setup();
+    let mut app = setup_response.app;
+
+    // Actors
+    let admin = setup_response.test_accounts.admin.clone();
+
+    // Instantiate the contract with a migration admin
+    let instantiate_msg = get_channel_instantiate_msg(admin.clone());
+    let channel_contract_addr = app
+        .instantiate_contract(
+            setup_response.channel_contract_code_id,
+            admin.clone(),
+            &instantiate_msg,
+            &[coin(1000000, "uflix")],
+            "Instantiate Channel Contract",
+            Some(admin.to_string()),
+        )
+        .unwrap();
+
+    // Simulate a different contract stored at this address
+    set_contract_version(
+        &mut *app.contract_storage_mut(&channel_contract_addr),
+        "other-contract",
+        "0.0.1",
+    )
+    .unwrap();
+
+    let res = app
+        .migrate_contract(
+            admin.clone(),
+            channel_contract_addr.clone(),
+            &MigrateMsg {},
+            setup_response.channel_contract_code_id,
+        )
+        .unwrap_err();
+
+    let typed_err = res.downcast_ref::<ContractError>().unwrap();
+    assert_eq!(
+        typed_err,
+        &ContractError::InvalidContractName {
+            expected: CONTRACT_NAME.to_string(),
+            found: "other-contract".to_string(),
+        }
+    );
+}
@@ -1,6 +1,9 @@
 #[cfg(test)]
 pub mod instantiate;
 
+#[cfg(test)]
+pub mod migrate;
+
 #[cfg(test)]
 pub mod channels;
 